@@ -0,0 +1,39 @@
+from scipy.special import airy, airye
+
+# ┌──────────────────────────────────────────────────────────┐
+#  airy
+# └──────────────────────────────────────────────────────────┘
+# x values to test
+x_values = [
+    -500.0, -60.0, -15.0,  # Asymptotic expansion (oscillating)
+    -9.0, -5.0, -2.0, -1.5,  # Bessel functions J and Y
+    -1.0, -0.5, -1e-10, 0.0, 1e-10, 0.3, 1.0,  # Maclaurin series
+    1.5, 2.0, 5.0, 7.0,  # Bessel functions I and K
+    8.0, 15.0, 50.0, 100.0  # Asymptotic expansion
+]
+
+airy_table = []
+for x in x_values:
+    ai, aip, bi, bip = airy(x)
+    airy_table.append((x, ai, bi, aip, bip))
+
+print("const AIRY_TABLE: [(f64, f64, f64, f64, f64); {}] = [".format(len(airy_table)))
+for x, ai, bi, aip, bip in airy_table:
+    print(f"    ({x:.16e}, {ai:.16e}, {bi:.16e}, {aip:.16e}, {bip:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  airy_scaled
+# └──────────────────────────────────────────────────────────┘
+# x values to test
+x_scaled_values = [-20.0, -1.0, 0.5, 3.0, 10.0, 120.0, 1e3, 1e8]
+
+airy_scaled_table = []
+for x in x_scaled_values:
+    ai, aip, bi, bip = airye(x)
+    airy_scaled_table.append((x, ai, bi, aip, bip))
+
+print("\nconst AIRY_SCALED_TABLE: [(f64, f64, f64, f64, f64); {}] = [".format(len(airy_scaled_table)))
+for x, ai, bi, aip, bip in airy_scaled_table:
+    print(f"    ({x:.16e}, {ai:.16e}, {bi:.16e}, {aip:.16e}, {bip:.16e}),")
+print("];")
@@ -1,4 +1,6 @@
-from scipy.special import yn, kn, jv, iv, yv, kv
+from scipy.special import yn, kn, jv, iv, yv, kv, spherical_jn, spherical_yn, spherical_in, spherical_kn
+from scipy.special import hankel1, hankel2, jve, yve, ive, kve, hankel1e, hankel2e
+from scipy.special import jn_zeros, yn_zeros, jnp_zeros, ynp_zeros
 
 # x values to test
 x_values = [
@@ -124,3 +126,142 @@ for nu in nu_values:
         kp = -kv(nu-1, x) - nu/x * kv(nu, x)  # Derivative of K_nu(x)
         print(f"    ({nu:.1f}, {x:.1f}, {i:.14e}, {k:.14e}, {ip:.14e}, {kp:.14e}),")
 print("];")
+
+# Negative orders
+neg_nu_values = [-0.3, -1.0, -1.5, -2.7, -6.0]
+neg_x_values = [0.5, 1.5, 4.0, 12.0]
+
+print("\nconst NEG_BESSELJY_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(neg_nu_values) * len(neg_x_values)))
+for nu in neg_nu_values:
+    for x in neg_x_values:
+        j = jv(nu, x)
+        y = yv(nu, x)
+        jp = jv(nu-1, x) - nu/x * jv(nu, x)  # Derivative of J_nu(x)
+        yp = yv(nu-1, x) - nu/x * yv(nu, x)  # Derivative of Y_nu(x)
+        print(f"    ({nu:.1f}, {x:.1f}, {j:.14e}, {y:.14e}, {jp:.14e}, {yp:.14e}),")
+print("];")
+
+print("\nconst NEG_BESSELIK_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(neg_nu_values) * len(neg_x_values)))
+for nu in neg_nu_values:
+    for x in neg_x_values:
+        i = iv(nu, x)
+        k = kv(nu, x)
+        ip = iv(nu-1, x) - nu/x * iv(nu, x)  # Derivative of I_nu(x)
+        kp = -kv(nu-1, x) - nu/x * kv(nu, x)  # Derivative of K_nu(x)
+        print(f"    ({nu:.1f}, {x:.1f}, {i:.14e}, {k:.14e}, {ip:.14e}, {kp:.14e}),")
+print("];")
+
+# Exponentially scaled modified Bessel functions
+# The large x values would overflow I and underflow K without the scaling.
+scaled_n_values = [0, 1, 2, 5, 30]
+scaled_x_values = [0.01, 1.0, 14.0, 16.0, 50.0, 800.0, 1e4, 1e6]
+
+for name, f in [("IN_SCALED", ive), ("KN_SCALED", kve)]:
+    print(f"\nconst {name}_TABLE: [(u32, f64, f64); {len(scaled_n_values) * len(scaled_x_values)}] = [")
+    for n in scaled_n_values:
+        for x in scaled_x_values:
+            print(f"    ({n}, {x:.14e}, {f(n, x):.14e}),")
+    print("];")
+
+scaled_nu_values = [-1.5, 0.3, 2.5, 10.5]
+
+print("\nconst BESSELIK_SCALED_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(scaled_nu_values) * len(scaled_x_values)))
+for nu in scaled_nu_values:
+    for x in scaled_x_values:
+        i = ive(nu, x)
+        k = kve(nu, x)
+        ip = ive(nu-1, x) - nu/x * ive(nu, x)  # Scaled derivative of I_nu(x)
+        kp = -kve(nu-1, x) - nu/x * kve(nu, x)  # Scaled derivative of K_nu(x)
+        print(f"    ({nu:.1f}, {x:.14e}, {i:.14e}, {k:.14e}, {ip:.14e}, {kp:.14e}),")
+print("];")
+
+# Spherical Bessel functions
+# (n, x) pairs cover the power series (x^2 < 2n + 3), upward recurrence (x > n)
+# and downward recurrence (n > x) for j_n and i_n.
+spherical_values = [
+    (0, 0.1), (0, 1.0), (0, 10.0), (1, 0.01), (1, 2.0), (1, 30.0),
+    (2, 0.5), (2, 5.0), (5, 1.0), (5, 7.5), (10, 3.0), (10, 8.0),
+    (10, 50.0), (30, 10.0), (30, 40.0), (100, 20.0), (100, 150.0)
+]
+
+for name, f in [("SPHERICAL_JN", spherical_jn), ("SPHERICAL_YN", spherical_yn),
+                ("SPHERICAL_IN", spherical_in), ("SPHERICAL_KN", spherical_kn)]:
+    print(f"\nconst {name}_TABLE: [(u32, f64, f64, f64); {len(spherical_values)}] = [")
+    for n, x in spherical_values:
+        y = f(n, x)
+        yp = f(n, x, derivative=True)
+        print(f"    ({n}, {x:.14e}, {y:.14e}, {yp:.14e}),")
+    print("];")
+
+# Complex Bessel and Hankel functions
+# (nu, z) pairs cover all quadrants, the positive and negative real axis, the imaginary axis,
+# small |z| (series), moderate |z| (continued fractions) and large |z| (asymptotic expansions).
+complex_values = [
+    (0.0, 0.5 + 0.3j), (0.0, -3.0 + 2.0j), (0.3, 2.0 - 1.5j), (0.3, -1.0 - 4.0j),
+    (1.0, 0.001 + 0.001j), (1.0, 7.5 + 5.0j), (1.0, -20.0 + 1.0j), (2.5, 15.0j),
+    (2.5, -6.0 + 0.0j), (2.5, 40.0 - 30.0j), (4.0, -0.8 + 1.2j), (7.0, 3.0 + 0.0j),
+    (7.0, -12.0 - 9.0j), (10.5, 1.0 - 2.0j), (20.0, 60.0 + 45.0j), (20.0, -300.0 + 0.0j)
+]
+
+for name, f, fe in [("COMPLEX_BESSELJ", jv, jve), ("COMPLEX_BESSELY", yv, yve),
+                    ("COMPLEX_BESSELI", iv, ive), ("COMPLEX_BESSELK", kv, kve),
+                    ("COMPLEX_HANKEL1", hankel1, hankel1e), ("COMPLEX_HANKEL2", hankel2, hankel2e)]:
+    print(f"\nconst {name}_TABLE: [(f64, f64, f64, f64, f64, f64, f64); {len(complex_values)}] = [")
+    for nu, z in complex_values:
+        y = complex(f(nu, z))
+        ye = complex(fe(nu, z))
+        print(f"    ({nu:.1f}, {z.real:.14e}, {z.imag:.14e}, {y.real:.14e}, {y.imag:.14e}, {ye.real:.14e}, {ye.imag:.14e}),")
+    print("];")
+
+# Large arguments and orders
+# (nu, x) pairs cover the Hankel expansion for large x and the Debye expansions for large nu on both
+# sides of the turning point x = nu. The modified functions are scaled as I and K over- and underflow.
+large_values = [
+    (0.5, 40.0), (0.5, 1e4), (0.5, 1e6), (3.3, 3e5), (25.0, 23.0), (25.0, 140.0),
+    (25.0, 1e6), (150.5, 140.0), (150.5, 160.0), (150.5, 900.0), (150.5, 1e4), (1000.0, 900.0),
+    (1000.0, 990.0), (1000.0, 1010.0), (1000.0, 1200.0), (1000.0, 1e6), (2000.0, 1e5), (400.0, 5e3)
+]
+
+print("\nconst LARGE_BESSELJY_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(large_values)))
+for nu, x in large_values:
+    j = jv(nu, x)
+    y = yv(nu, x)
+    jp = jv(nu-1, x) - nu/x * jv(nu, x)  # Derivative of J_nu(x)
+    yp = yv(nu-1, x) - nu/x * yv(nu, x)  # Derivative of Y_nu(x)
+    print(f"    ({nu:.1f}, {x:.14e}, {j:.14e}, {y:.14e}, {jp:.14e}, {yp:.14e}),")
+print("];")
+
+print("\nconst LARGE_BESSELIK_SCALED_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(large_values)))
+for nu, x in large_values:
+    i = ive(nu, x)
+    k = kve(nu, x)
+    ip = ive(nu-1, x) - nu/x * ive(nu, x)  # Scaled derivative of I_nu(x)
+    kp = -kve(nu-1, x) - nu/x * kve(nu, x)  # Scaled derivative of K_nu(x)
+    print(f"    ({nu:.1f}, {x:.14e}, {i:.14e}, {k:.14e}, {ip:.14e}, {kp:.14e}),")
+print("];")
+
+# Zeros of Bessel functions and their derivatives
+# The orders range from the McMahon regime (many zeros above nu) to the Olver regime near the turning point.
+zeros_n_values = [0, 1, 2, 5, 12, 40]
+zeros_count = 8
+
+for name, f in [("JN_ZEROS", jn_zeros), ("YN_ZEROS", yn_zeros),
+                ("JNP_ZEROS", jnp_zeros), ("YNP_ZEROS", ynp_zeros)]:
+    print(f"\nconst {name}_TABLE: [(u32, usize, f64); {len(zeros_n_values) * zeros_count}] = [")
+    for n in zeros_n_values:
+        for k, zero in enumerate(f(n, zeros_count), 1):
+            print(f"    ({n}, {k}, {zero:.14e}),")
+    print("];")
+
+# Sequences of Bessel functions
+# (n, x) pairs cover orders far above x, where the backward recurrences take over, and large x,
+# where I and K are carried scaled through the recurrences.
+sequence_values = [
+    (0, 1e-3), (7, 1e-3), (30, 0.3), (1, 2.5), (60, 2.5), (120, 5.0), (0, 50.0), (49, 50.0),
+    (200, 50.0), (150, 150.0), (1, 700.0), (600, 800.0), (1000, 800.0)
+]
+
+print("\nconst SEQUENCE_TABLE: [(u32, f64, f64, f64, f64, f64); {}] = [".format(len(sequence_values)))
+for n, x in sequence_values:
+    print(f"    ({n}, {x:.14e}, {jv(n, x):.14e}, {yv(n, x):.14e}, {iv(n, x):.14e}, {kv(n, x):.14e}),")
+print("];")
@@ -1,4 +1,5 @@
 from scipy.special import beta, betainc, betaincinv
+import mpmath
 
 # a and b values to test for beta, betai, and invbetai
 ab_values = [
@@ -55,3 +56,98 @@ print("\nconst INVBETAI_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(invbet
 for a, b, p, x in invbetai_table:
     print("    ({:.14e}, {:.14e}, {:.14e}, {:.14e}),".format(a, b, p, x))
 print("];")
+
+# (a, b, x) values to test for betai_grad
+# scipy does not provide parameter derivatives, so they are computed with mpmath.
+mpmath.mp.dps = 50
+betai_grad_values = [(a, b, x) for a, b in ab_values for x in x_values] + [
+    (0.1, 8.0, 0.01), (50.0, 60.0, 0.45), (5.0, 2.0, 0.99)
+]
+
+def betai_mp(a, b, x):
+    return mpmath.betainc(a, b, 0, x, regularized=True)
+
+betai_grad_table = [
+    (a, b, x, float(betai_mp(a, b, x)),
+     float(mpmath.diff(lambda s: betai_mp(s, b, x), a)),
+     float(mpmath.diff(lambda s: betai_mp(a, s, x), b)))
+    for a, b, x in betai_grad_values
+]
+
+print("\nconst BETAI_GRAD_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(betai_grad_table)))
+for a, b, x, y, da, db in betai_grad_table:
+    print("    ({:.14e}, {:.14e}, {:.14e}, {:.14e}, {:.14e}, {:.14e}),".format(a, b, x, y, da, db))
+print("];")
+
+# (a, b) values to test for ln_beta, including one or both parameters large
+# scipy's betaln loses accuracy for these, so mpmath is used.
+ln_beta_values = ab_values + [
+    (0.01, 50.0), (3.0, 1e5), (1.0, 1e10), (1e-5, 1e20), (9.9, 10.0), (50.0, 60.0),
+    (1e3, 1e8), (1e150, 1e150)
+]
+ln_beta_table = [(a, b, float(mpmath.log(mpmath.beta(a, b)))) for a, b in ln_beta_values]
+
+print("\nconst LN_BETA_TABLE: [(f64, f64, f64); {}] = [".format(len(ln_beta_table)))
+for a, b, y in ln_beta_table:
+    print("    ({:.14e}, {:.14e}, {:.14e}),".format(a, b, y))
+print("];")
+
+# (a, b, x) values to test for ln_betai, including deep tails where betai underflows
+def ln_betai_mp(a, b, x):
+    # I_x(a,b) = x^a (1-x)^b / (a B(a,b)) 2F1(a+b, 1; a+1; x), summed on the side of the mean
+    def lower(a, b, x):
+        return (a * mpmath.log(x) + b * mpmath.log1p(-x) - mpmath.log(a) - mpmath.log(mpmath.beta(a, b))
+                + mpmath.log(mpmath.hyp2f1(a + b, 1, a + 1, x, maxterms=10**7)))
+    if x < a / (a + b):
+        return lower(a, b, x)
+    return mpmath.log1p(-mpmath.exp(lower(b, a, 1 - x)))
+
+ln_betai_values = [(a, b, x) for a, b in ab_values for x in x_values] + [
+    (2.0, 3.0, 1e-200), (0.5, 0.5, 1e-300), (50.0, 60.0, 0.1), (100.0, 1.0, 0.2), (2.0, 3.0, 0.999999),
+    (1e4, 1e4, 0.3), (1e4, 1e4, 0.49), (1e4, 1e4, 0.55), (5000.0, 6000.0, 0.1), (4000.0, 3500.0, 0.5)
+]
+ln_betai_table = [(a, b, x, float(ln_betai_mp(a, b, x))) for a, b, x in ln_betai_values]
+
+print("\nconst LN_BETAI_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(ln_betai_table)))
+for a, b, x, y in ln_betai_table:
+    print("    ({:.14e}, {:.14e}, {:.14e}, {:.14e}),".format(a, b, x, y))
+print("];")
+
+# (a, b, x) values to test for betaic, including upper tails where betai rounds to 1
+# scipy has no complementary function, so mpmath is used.
+betaic_values = [(a, b, x) for a, b in ab_values for x in x_values] + [
+    (2.0, 3.0, 0.999), (0.5, 0.5, 0.9999999), (10.0, 5.0, 0.99), (0.1, 10.0, 1e-8), (50.0, 60.0, 0.9),
+    (50.0, 60.0, 0.999)
+]
+betaic_table = [
+    (a, b, x, float(mpmath.betainc(a, b, x, 1, regularized=True))) for a, b, x in betaic_values
+]
+
+print("\nconst BETAIC_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(betaic_table)))
+for a, b, x, y in betaic_table:
+    print("    ({:.14e}, {:.14e}, {:.14e}, {:.14e}),".format(a, b, x, y))
+print("];")
+
+# q values to test for invbetaic, including small upper tail probabilities where 1 - q rounds to 1
+q_values = p_values + [1e-10, 1e-20]
+
+def invbetaic_mp(a, b, q):
+    # Solve for 1 - x = y with I_y(b, a) = q by bisection
+    lo, hi = mpmath.mpf(0), mpmath.mpf(1)
+    for _ in range(200):
+        mid = (lo + hi) / 2
+        if mpmath.betainc(b, a, 0, mid, regularized=True) < q:
+            lo = mid
+        else:
+            hi = mid
+    return 1 - (lo + hi) / 2
+
+invbetaic_table = [
+    (a, b, q, float(invbetaic_mp(a, b, q))) for a, b in ab_values for q in q_values
+    if invbetaic_mp(a, b, q) < 1 - 1e-14
+]
+
+print("\nconst INVBETAIC_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(invbetaic_table)))
+for a, b, q, x in invbetaic_table:
+    print("    ({:.14e}, {:.14e}, {:.14e}, {:.14e}),".format(a, b, q, x))
+print("];")
@@ -0,0 +1,11 @@
+from scipy.special import dawsn
+
+# x values covering the power series, Rybicki's method and the asymptotic range,
+# and both sides of the switches at |x| = 0.5 and |x| = 1e8
+x_values = [1e-10, 0.1, 0.2, 0.3, 0.4999, 0.5, 0.9241, 1.5, 2.5, 4.0, 10.0, 123.0, 1e5, 9.9e7, 1.01e8, 1e9,
+            -0.2, -0.7, -3.0, -1e5, -1e9]
+
+print("const DAWSON_TABLE: [(f64, f64); {}] = [".format(len(x_values)))
+for x in x_values:
+    print(f"    ({x:.16e}, {dawsn(x):.16e}),")
+print("];")
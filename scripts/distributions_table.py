@@ -0,0 +1,103 @@
+from scipy import stats
+import math
+
+# ┌──────────────────────────────────────────────────────────┐
+#  Helpers
+# └──────────────────────────────────────────────────────────┘
+def fmt(v):
+    if isinstance(v, int):
+        return str(v)
+    if math.isinf(v):
+        return "f64::INFINITY" if v > 0 else "f64::NEG_INFINITY"
+    return f"{v:.16e}"
+
+def print_table(name, columns, rows):
+    print("\nconst {}: [({}); {}] = [".format(name, ", ".join(columns), len(rows)))
+    for row in rows:
+        print("    ({}),".format(", ".join(fmt(v) for v in row)))
+    print("];")
+
+# Probabilities to test for the quantile functions, including both tails
+p_values = [1e-12, 1e-3, 0.05, 0.3, 0.5, 0.7, 0.95, 0.999, 1.0 - 1e-10]
+
+def continuous_tables(name, columns, params, x_values, make):
+    rows = []
+    for param in params:
+        dist = make(*param)
+        for x in x_values(*param):
+            rows.append(param + (x, dist.logpdf(x), dist.cdf(x), dist.sf(x)))
+    print_table(name + "_TABLE", columns + ["f64"] * 4, rows)
+    rows = [param + (p, make(*param).ppf(p)) for param in params for p in p_values]
+    print_table(name + "_QUANTILE_TABLE", columns + ["f64"] * 2, rows)
+
+def discrete_tables(name, columns, params, k_values, make):
+    rows = []
+    for param in params:
+        dist = make(*param)
+        for k in k_values(*param):
+            rows.append(param + (k, dist.logpmf(k), dist.cdf(k), dist.sf(k)))
+    print_table(name + "_TABLE", columns + ["u64"] + ["f64"] * 3, rows)
+    rows = [param + (p, make(*param).ppf(p)) for param in params for p in p_values]
+    print_table(name + "_QUANTILE_TABLE", columns + ["f64"] * 2, rows)
+
+# ┌──────────────────────────────────────────────────────────┐
+#  Continuous distributions
+# └──────────────────────────────────────────────────────────┘
+continuous_tables(
+    "NORMAL", ["f64", "f64"],
+    [(0.0, 1.0), (1.5, 0.3), (-20.0, 4.0)],
+    lambda m, s: [m + s * z for z in [-30.0, -5.0, -1.0, -1e-3, 0.0, 0.5, 2.0, 8.0]],
+    lambda m, s: stats.norm(loc=m, scale=s),
+)
+
+continuous_tables(
+    "CHI_SQUARED", ["f64"],
+    [(1.0,), (2.0,), (7.5,), (100.0,)],
+    lambda k: [1e-6, 0.1, 1.0, k, 3.0 * k + 10.0, 10.0 * k + 50.0, 20.0 * k + 60.0],
+    lambda k: stats.chi2(k),
+)
+
+continuous_tables(
+    "GAMMA", ["f64", "f64"],
+    [(0.3, 1.0), (2.0, 0.5), (25.0, 3.0)],
+    lambda k, th: [th * y for y in [1e-20, 1e-5, 0.2, 1.0, k, 2.0 * k + 20.0, 4.0 * k + 60.0]],
+    lambda k, th: stats.gamma(k, scale=th),
+)
+
+continuous_tables(
+    "BETA", ["f64", "f64"],
+    [(0.5, 0.5), (2.0, 5.0), (30.0, 4.0)],
+    lambda a, b: [1e-8, 0.1, 0.5, 0.9, 1.0 - 1e-8],
+    lambda a, b: stats.beta(a, b),
+)
+
+continuous_tables(
+    "STUDENT_T", ["f64"],
+    [(1.0,), (3.0,), (25.0,), (1e6,)],
+    lambda nu: [-1e4, -30.0, -2.0, -1e-4, 0.0, 0.5, 3.0, 60.0],
+    lambda nu: stats.t(nu),
+)
+
+continuous_tables(
+    "FISHER_F", ["f64", "f64"],
+    [(1.0, 1.0), (5.0, 2.0), (10.0, 40.0), (200.0, 3.0)],
+    lambda d1, d2: [1e-6, 0.1, 1.0, 2.5, 50.0, 1e4],
+    lambda d1, d2: stats.f(d1, d2),
+)
+
+# ┌──────────────────────────────────────────────────────────┐
+#  Discrete distributions
+# └──────────────────────────────────────────────────────────┘
+discrete_tables(
+    "POISSON", ["f64"],
+    [(0.01,), (3.5,), (250.0,)],
+    lambda l: sorted({0, 1, int(l / 10), int(l), int(l + 3 * math.sqrt(l)) + 2, int(3 * l) + 20}),
+    lambda l: stats.poisson(l),
+)
+
+discrete_tables(
+    "BINOMIAL", ["u64", "f64"],
+    [(1, 0.3), (20, 0.05), (100, 0.5), (1000, 0.9)],
+    lambda n, p: sorted({0, 1, int(n * p), max(n - 1, 0), n}),
+    lambda n, p: stats.binom(n, p),
+)
@@ -0,0 +1,83 @@
+from scipy.special import ellipk, ellipkm1, ellipe, ellipkinc, ellipeinc, elliprf, elliprd, elliprj, elliprc
+import mpmath
+
+# ┌──────────────────────────────────────────────────────────┐
+#  ellipk, ellipe, ellipkm1
+# └──────────────────────────────────────────────────────────┘
+# m values to test, including the logarithmic singularity of K(m) at m = 1
+complete_m_values = [-100.0, -3.0, -0.5, 0.0, 1e-10, 0.1, 0.3, 0.5, 0.7, 0.9, 0.99, 0.999999, 1.0 - 2.0**-40]
+
+print("const COMPLETE_TABLE: [(f64, f64, f64); {}] = [".format(len(complete_m_values)))
+for m in complete_m_values:
+    print(f"    ({m:.16e}, {ellipk(m):.16e}, {ellipe(m):.16e}),")
+print("];")
+
+# p = 1 - m values, where m itself would round to 1
+ellipkm1_p_values = [1e-300, 1e-100, 1e-30, 1e-17, 1e-8, 0.25, 1.0, 40.0]
+
+print("const ELLIPKM1_TABLE: [(f64, f64); {}] = [".format(len(ellipkm1_p_values)))
+for p in ellipkm1_p_values:
+    print(f"    ({p:.16e}, {ellipkm1(p):.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  ellipkinc, ellipeinc
+# └──────────────────────────────────────────────────────────┘
+# (phi, m) pairs, including amplitudes beyond pi/2 and m > 1 with m sin^2(phi) < 1
+incomplete_values = [
+    (0.1, 0.5), (0.7, -2.0), (1.0, 0.3), (1.2, 0.99), (1.5, 0.999999), (1.5707963267948966, 0.8),
+    (-0.9, 0.6), (2.4, 0.4), (7.0, 0.95), (40.0, -0.5), (0.5, 3.0), (0.3, 10.0), (1.0, 1.0)
+]
+
+print("const INCOMPLETE_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(incomplete_values)))
+for phi, m in incomplete_values:
+    print(f"    ({phi:.16e}, {m:.16e}, {ellipkinc(phi, m):.16e}, {ellipeinc(phi, m):.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  ellippi, ellippiinc
+# └──────────────────────────────────────────────────────────┘
+# scipy does not provide the integrals of the third kind, so mpmath is used here.
+# For n > 1 the real part of mpmath's result is the Cauchy principal value.
+mpmath.mp.dps = 50
+
+pi_values = [(-5.0, 0.3), (-0.5, 0.0), (0.2, -1.0), (0.5, 0.5), (0.9, 0.99), (0.999, 0.1), (1.5, 0.3), (4.0, 0.9)]
+
+print("const PI_TABLE: [(f64, f64, f64); {}] = [".format(len(pi_values)))
+for n, m in pi_values:
+    print(f"    ({n:.16e}, {m:.16e}, {float(mpmath.re(mpmath.ellippi(n, m))):.16e}),")
+print("];")
+
+pi_incomplete_values = [
+    (-2.0, 0.6, 0.5), (0.5, 1.0, 0.3), (0.9, 1.4, 0.9), (-0.3, 2.5, 0.2),
+    (0.4, -7.0, 0.7), (3.0, 0.4, 0.5), (3.0, 1.2, 0.5), (20.0, 1.0, 0.1)
+]
+
+print("const PI_INCOMPLETE_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(pi_incomplete_values)))
+for n, phi, m in pi_incomplete_values:
+    value = float(mpmath.re(mpmath.ellippi(n, phi, m)))
+    print(f"    ({n:.16e}, {phi:.16e}, {m:.16e}, {value:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  elliprf, elliprd, elliprj, elliprc
+# └──────────────────────────────────────────────────────────┘
+# (x, y, z, p) values, including a zero argument, tiny and huge scales and p < 0
+carlson_values = [
+    (1.0, 2.0, 3.0, 4.0), (0.0, 1.0, 2.0, 3.0), (2.0, 3.0, 4.0, 5.0), (0.5, 0.5, 0.5, 0.5),
+    (1e-5, 1e5, 3.0, 0.2), (0.0, 1e-12, 1.0, 1e-10), (3e-80, 2e-80, 1e-80, 4e-80),
+    (2.0, 3.0, 4.0, -0.5), (0.0, 1.0, 2.0, -3.0), (1e30, 3e30, 2e30, 1e30)
+]
+
+print("const CARLSON_TABLE: [(f64, f64, f64, f64, f64, f64, f64); {}] = [".format(len(carlson_values)))
+for x, y, z, p in carlson_values:
+    rf, rd, rj = elliprf(x, y, z), elliprd(x, y, z), elliprj(x, y, z, p)
+    print(f"    ({x:.16e}, {y:.16e}, {z:.16e}, {p:.16e}, {rf:.16e}, {rd:.16e}, {rj:.16e}),")
+print("];")
+
+rc_values = [(0.0, 0.25), (2.25, 2.0), (1.0, 1.0), (1.0, 1.0 + 1e-9), (1e-10, 5.0), (0.25, -2.0), (1e6, 1e-6)]
+
+print("const RC_TABLE: [(f64, f64, f64); {}] = [".format(len(rc_values)))
+for x, y in rc_values:
+    print(f"    ({x:.16e}, {y:.16e}, {elliprc(x, y):.16e}),")
+print("];")
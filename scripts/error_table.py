@@ -1,4 +1,4 @@
-from scipy.special import erf, erfc, erfinv, erfcinv
+from scipy.special import erf, erfc, erfi, erfinv, erfcinv
 
 # x values to test for erf and erfc
 x_values = [
@@ -55,3 +55,22 @@ print("\nconst INVERFC_TABLE: [(f64, f64); {}] = [".format(len(inverfc_table)))
 for p, x in inverfc_table:
     print("    ({:.14e}, {:.14e}),".format(p, x))
 print("];")
+
+# x values to test erf for small |x|, where it is compared with a relative tolerance,
+# up to both sides of the switch from the power series to 1 - erfc(x) at |x| = 0.5
+small_x_values = [1e-300, -1e-12, 1e-5, 0.01, -0.3, 0.49, 0.4999999, 0.5, -0.5, 0.5000001, 0.7]
+erf_small_table = [(x, erf(x)) for x in small_x_values]
+
+print("\nconst ERF_SMALL_TABLE: [(f64, f64); {}] = [".format(len(erf_small_table)))
+for x, y in erf_small_table:
+    print("    ({:.16e}, {:.16e}),".format(x, y))
+print("];")
+
+# x values to test for erfi, up to the overflow near x = 26.6
+erfi_x_values = [1e-10, -0.2, 0.5, 1.0, 2.5, -4.0, 10.0, 26.0]
+erfi_table = [(x, erfi(x)) for x in erfi_x_values]
+
+print("\nconst ERFI_TABLE: [(f64, f64); {}] = [".format(len(erfi_table)))
+for x, y in erfi_table:
+    print("    ({:.16e}, {:.16e}),".format(x, y))
+print("];")
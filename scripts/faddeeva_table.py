@@ -0,0 +1,41 @@
+from scipy.special import erf, erfc, erfcx, erfi, dawsn, wofz
+
+# ┌──────────────────────────────────────────────────────────┐
+#  complex_erf, complex_erfc, complex_erfcx, complex_erfi, complex_dawson
+# └──────────────────────────────────────────────────────────┘
+# z values covering both axes, the small |z| series, the regions near the axes and large |z|
+z_values = [
+    1e-10 + 2e-10j, 0.1 - 0.3j, 0.3 + 0.3j, -0.45 + 0.1j, 1.0 + 1.0j, -2.0 + 0.5j, 0.5 - 3.0j,
+    3.0 + 0.0j, -2.5 + 0.0j, 0.0 + 1.5j, 0.0 - 4.0j, 1e-3 + 2.0j, 5.0 + 2e-3j, -4.0 - 6.0j,
+    7.0 + 7.5j, 20.0 - 0.2j, 15.0 + 1e-4j, 0.2 + 20.0j, -12.0 + 1e-5j
+]
+
+functions = [("ERF", erf), ("ERFC", erfc), ("ERFCX", erfcx), ("ERFI", erfi), ("DAWSON", dawsn)]
+
+for name, f in functions:
+    print("const COMPLEX_{}_TABLE: [(f64, f64, f64, f64); {}] = [".format(name, len(z_values)))
+    for z in z_values:
+        w = f(z)
+        print(f"    ({z.real:.16e}, {z.imag:.16e}, {w.real:.16e}, {w.imag:.16e}),")
+    print("];")
+
+# Large |z| close to the axes, where erfcx and Dawson's integral stay finite
+large_z_values = [1e8 + 0.5j, 1e8 - 0.5j, 3e5 + 2e-4j, 3e5 - 0.9j]
+
+print("const COMPLEX_LARGE_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(large_z_values)))
+for z in large_z_values:
+    w, d = erfcx(z), dawsn(z)
+    print(f"    ({z.real:.16e}, {z.imag:.16e}, {w.real:.16e}, {w.imag:.16e}, {d.real:.16e}, {d.imag:.16e}),")
+print("];")
+
+# w(z) close to the axes for large |z|, where the small component of w is compared on its own
+near_axis_z_values = [
+    9.9 + 1e-8j, 10.1 + 1e-8j, -50.0 + 1e-7j, 2e3 + 1e-6j, -3e4 + 2e-5j, 1e5 + 1e-4j,
+    1e-8 + 9.9j, 1e-8 + 10.1j, 1e-7 + 50.0j, 1e-6 + 2e3j, -2e-5 + 3e4j, 1e-4 + 1e5j
+]
+
+print("const W_NEAR_AXIS_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(near_axis_z_values)))
+for z in near_axis_z_values:
+    w = wofz(z)
+    print(f"    ({z.real:.16e}, {z.imag:.16e}, {w.real:.16e}, {w.imag:.16e}),")
+print("];")
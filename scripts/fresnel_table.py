@@ -0,0 +1,46 @@
+from scipy.special import fresnel
+import mpmath
+
+# ┌──────────────────────────────────────────────────────────┐
+#  fresnel
+# └──────────────────────────────────────────────────────────┘
+# x values covering the power series, the Faddeeva and the asymptotic ranges
+fresnel_values = [
+    1e-10, 0.1, 0.5, 0.9, 1.0, 1.2, 2.0, 3.3, 5.0, 5.6, 8.0, 15.5, 100.3, 1e4, -0.7, -2.5, -7.0
+]
+
+print("const FRESNEL_TABLE: [(f64, f64, f64); {}] = [".format(len(fresnel_values)))
+for x in fresnel_values:
+    s, c = fresnel(x)
+    print(f"    ({x:.16e}, {s:.16e}, {c:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  fresnel_aux
+# └──────────────────────────────────────────────────────────┘
+# scipy does not provide f and g, so they are computed from their definitions with mpmath.
+mpmath.mp.dps = 60
+
+print("const FRESNEL_AUX_TABLE: [(f64, f64, f64); {}] = [".format(len(fresnel_values)))
+for x in fresnel_values:
+    x_mp = mpmath.mpf(x)
+    s, c = mpmath.fresnels(x_mp), mpmath.fresnelc(x_mp)
+    sin, cos = mpmath.sin(mpmath.pi * x_mp**2 / 2), mpmath.cos(mpmath.pi * x_mp**2 / 2)
+    f = (0.5 - s) * cos - (0.5 - c) * sin
+    g = (0.5 - c) * cos + (0.5 - s) * sin
+    print(f"    ({x:.16e}, {float(f):.16e}, {float(g):.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  complex_fresnel
+# └──────────────────────────────────────────────────────────┘
+complex_fresnel_values = [
+    0.5 + 0.5j, 1.2 + 0.3j, -0.8 + 0.9j, 2.0 + 1.0j, 3.0 - 0.5j, -4.0 - 2.0j, 0.1 + 3.0j,
+    5.0 + 0.1j, 1.5 - 1.5j, -2.0 + 2.0j, 0.0 + 2.0j, 3.0 + 0.0j
+]
+
+print("const COMPLEX_FRESNEL_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(complex_fresnel_values)))
+for z in complex_fresnel_values:
+    s, c = fresnel(z)
+    print(f"    ({z.real:.16e}, {z.imag:.16e}, {s.real:.16e}, {s.imag:.16e}, {c.real:.16e}, {c.imag:.16e}),")
+print("];")
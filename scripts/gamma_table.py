@@ -1,5 +1,6 @@
-from scipy.special import gammaln, gamma, gammainc, gammaincc, gammaincinv
+from scipy.special import gammaln, gammasgn, gamma, gammainc, gammaincc, gammaincinv, gammainccinv, psi, polygamma, loggamma
 import numpy as np
+import mpmath
 
 # ┌──────────────────────────────────────────────────────────┐
 #  ln_gamma
@@ -24,6 +25,26 @@ for x, y in table:
     print("    ({:.14e}, {:.14e}),".format(x, y))
 print("];")
 
+# ┌──────────────────────────────────────────────────────────┐
+#  ln_gamma_sign
+# └──────────────────────────────────────────────────────────┘
+# x values to test, including negative values where Γ(x) can be negative
+ln_gamma_sign_x_values = [
+    -1e10 - 0.5, -200.25, -100.5, -10.1,  # Large negative values (reflection formula)
+    -2.5, -1.5, -0.5, -0.25, -1e-10,  # Small negative values
+    0.9999, 1.0001, 1.9999, 2.0001,  # Close to the roots of ln Γ
+    1e-300, 3.0, 7.5, 171.7, 1e15, 1e300  # Positive values
+]
+
+# Generate table
+table = [(x, gammaln(x), gammasgn(x)) for x in ln_gamma_sign_x_values]
+
+# Print in Rust code format
+print("const LN_GAMMA_SIGN_TABLE: [(f64, f64, i8); {}] = [".format(len(table)))
+for x, y, s in table:
+    print(f"    ({x:.16e}, {y:.16e}, {int(s)}),")
+print("];")
+
 # ┌──────────────────────────────────────────────────────────┐
 #  gamma
 # └──────────────────────────────────────────────────────────┘
@@ -103,3 +124,159 @@ print("\nconst INVGAMMP_TABLE: [(f64, f64, f64); {}] = [".format(len(invgammp_ta
 for a, p, x in invgammp_table:
     print("    ({:.14e}, {:.14e}, {:.14e}),".format(a, p, x))
 print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  invgammq
+# └──────────────────────────────────────────────────────────┘
+# q values to test, including extreme upper tail probabilities where 1 - q rounds to 1
+q_values = [1e-300, 1e-100, 1e-20, 1e-5, 0.01, 0.3, 0.5, 0.9, 0.999]
+
+# Generate table for invgammq
+invgammq_table = [(a, q, gammainccinv(a, q)) for a in a_values + [150.0] for q in q_values]
+
+print("\nconst INVGAMMQ_TABLE: [(f64, f64, f64); {}] = [".format(len(invgammq_table)))
+for a, q, x in invgammq_table:
+    print(f"    ({a:.16e}, {q:.16e}, {x:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  upper & lower incomplete gamma
+# └──────────────────────────────────────────────────────────┘
+# scipy only provides the regularized functions for a > 0, so mpmath is used here.
+mpmath.mp.dps = 50
+
+# a values to test, including non-positive values for the upper function
+incomplete_a_values = [
+    -30.5, -5.0, -2.5, -1.0, -0.3, -1e-8, 0.0,  # Non-positive values (recurrence relation)
+    1e-8, 0.3, 1.0, 2.5, 10.0, 150.0  # Positive values
+]
+
+# x values to test
+incomplete_x_values = [1e-6, 0.01, 0.5, 1.0, 1.9, 3.0, 20.0, 160.0]
+
+upper_incomplete_gamma_table = [
+    (a, x, float(mpmath.gammainc(a, x, mpmath.inf)))
+    for a in incomplete_a_values for x in incomplete_x_values
+    if 1e-300 < mpmath.gammainc(a, x, mpmath.inf) < 1e300
+]
+lower_incomplete_gamma_table = [
+    (a, x, float(mpmath.gammainc(a, 0, x)))
+    for a in incomplete_a_values if a > 0 for x in incomplete_x_values
+    if 1e-300 < mpmath.gammainc(a, 0, x) < 1e300
+]
+
+print("const UPPER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); {}] = [".format(len(upper_incomplete_gamma_table)))
+for a, x, y in upper_incomplete_gamma_table:
+    print(f"    ({a:.16e}, {x:.16e}, {y:.16e}),")
+print("];")
+
+print("\nconst LOWER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); {}] = [".format(len(lower_incomplete_gamma_table)))
+for a, x, y in lower_incomplete_gamma_table:
+    print(f"    ({a:.16e}, {x:.16e}, {y:.16e}),")
+print("];")
+
+# (a, x) pairs where the functions overflow or underflow
+ln_incomplete_values = [
+    (-300.0, 1e-3), (-50.5, 1e-10), (0.5, 800.0), (10.0, 1e4),  # Upper function out of range
+    (1e-300, 0.1), (200.0, 1e-3), (1e4, 9e3), (1e4, 1.1e4), (300.0, 1e3)  # Both functions
+]
+
+ln_upper_incomplete_gamma_table = [
+    (a, x, float(mpmath.log(mpmath.gammainc(a, x, mpmath.inf)))) for a, x in ln_incomplete_values
+]
+ln_lower_incomplete_gamma_table = [
+    (a, x, float(mpmath.log(mpmath.gammainc(a, 0, x)))) for a, x in ln_incomplete_values if a > 0
+]
+
+print("\nconst LN_UPPER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); {}] = [".format(len(ln_upper_incomplete_gamma_table)))
+for a, x, y in ln_upper_incomplete_gamma_table:
+    print(f"    ({a:.16e}, {x:.16e}, {y:.16e}),")
+print("];")
+
+print("\nconst LN_LOWER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); {}] = [".format(len(ln_lower_incomplete_gamma_table)))
+for a, x, y in ln_lower_incomplete_gamma_table:
+    print(f"    ({a:.16e}, {x:.16e}, {y:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  gammp_grad
+# └──────────────────────────────────────────────────────────┘
+# scipy does not provide parameter derivatives, so they are computed with mpmath.
+mpmath.mp.dps = 50
+
+# (a, x) pairs covering both the series (x < a + 1) and the continued fraction, and tiny a
+gammp_grad_values = [
+    (1e-3, 1e-4), (0.01, 0.5), (0.5, 0.1), (0.5, 2.0), (1.0, 1.0), (2.5, 1.0), (2.5, 5.0),
+    (10.0, 9.0), (10.0, 12.0), (3.0, 40.0), (50.0, 45.0), (150.0, 170.0),
+    (1e-10, 1e-5), (1e-6, 0.3), (1e-8, 3.0)
+]
+
+gammp_grad_table = [
+    (a, x, float(mpmath.gammainc(a, 0, x, regularized=True)),
+     float(mpmath.diff(lambda s: mpmath.gammainc(s, 0, x, regularized=True), a)))
+    for a, x in gammp_grad_values
+]
+
+print("const GAMMP_GRAD_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(gammp_grad_table)))
+for a, x, y, dy in gammp_grad_table:
+    print(f"    ({a:.16e}, {x:.16e}, {y:.16e}, {dy:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  digamma & polygamma
+# └──────────────────────────────────────────────────────────┘
+# x values to test
+psi_x_values = [
+    -100.3, -2.5, -1.2, -0.5, -1e-5,  # Negative non-integer values (reflection formula)
+    1e-10, 1e-3, 0.1, 0.5,  # Values between 0 and 1
+    1.0, 1.25, 1.4616321449683622, 1.5, 2.0,  # Around the positive root of digamma
+    3.3, 10.0, 25.0,  # Medium values
+    1e3, 1e8, 1e15  # Large values
+]
+
+# Generate table for digamma
+digamma_table = [(x, psi(x)) for x in psi_x_values]
+
+print("const DIGAMMA_TABLE: [(f64, f64); {}] = [".format(len(digamma_table)))
+for x, y in digamma_table:
+    print(f"    ({x:.16e}, {y:.16e}),")
+print("];")
+
+# n values to test for polygamma
+n_values = [1, 2, 3, 10]
+
+# Generate table for polygamma (skipping values that are not representable in f64)
+polygamma_table = [
+    (n, x, polygamma(n, x)) for n in n_values for x in psi_x_values
+    if 1e-300 < abs(polygamma(n, x)) < 1e300
+]
+
+print("\nconst POLYGAMMA_TABLE: [(u32, f64, f64); {}] = [".format(len(polygamma_table)))
+for n, x, y in polygamma_table:
+    print(f"    ({n}, {x:.16e}, {y:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  complex gamma & log-gamma
+# └──────────────────────────────────────────────────────────┘
+# z values to test
+complex_z_values = [
+    0.5 + 0.5j, 1.0 + 1.0j, 2.0 - 3.0j, 0.1 + 10.0j,  # Small and moderate values
+    1.2 + 0.1j, 2.3 - 0.2j,  # Close to the roots of log Γ
+    -0.5 + 0.5j, -2.5 - 1.0j, -10.3 + 0.7j, -100.2 + 4.0j,  # Negative real parts (reflection formula)
+    5.0 - 25.0j, 30.0 + 40.0j, 150.0 + 1.0j, -3.0 + 50.0j,  # Larger values
+]
+
+# Generate tables
+complex_ln_gamma_table = [(z, loggamma(z)) for z in complex_z_values]
+complex_gamma_table = [(z, complex(gamma(z))) for z in complex_z_values]
+
+print("const COMPLEX_LN_GAMMA_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(complex_ln_gamma_table)))
+for z, w in complex_ln_gamma_table:
+    print(f"    ({z.real:.16e}, {z.imag:.16e}, {w.real:.16e}, {w.imag:.16e}),")
+print("];")
+
+print("\nconst COMPLEX_GAMMA_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(complex_gamma_table)))
+for z, w in complex_gamma_table:
+    print(f"    ({z.real:.16e}, {z.imag:.16e}, {w.real:.16e}, {w.imag:.16e}),")
+print("];")
@@ -0,0 +1,31 @@
+from scipy.special import ellipj
+import mpmath
+
+# ┌──────────────────────────────────────────────────────────┐
+#  ellipj
+# └──────────────────────────────────────────────────────────┘
+# (u, m) pairs for 0 <= m <= 1, including several periods and m close to 1
+ellipj_values = [
+    (0.2, 0.0), (1.0, 1e-10), (0.5, 0.3), (-1.3, 0.5), (2.0, 0.5), (7.0, 0.7), (30.0, 0.1),
+    (1.5, 0.9), (4.0, 0.99), (3.0, 0.999999), (2.0, 1.0 - 2.0**-40), (1.0, 1.0), (-5.0, 1.0)
+]
+
+print("const ELLIPJ_TABLE: [(f64, f64, f64, f64, f64, f64); {}] = [".format(len(ellipj_values)))
+for u, m in ellipj_values:
+    sn, cn, dn, ph = ellipj(u, m)
+    print(f"    ({u:.16e}, {m:.16e}, {sn:.16e}, {cn:.16e}, {dn:.16e}, {ph:.16e}),")
+print("];")
+
+# scipy only covers 0 <= m <= 1, so mpmath is used for the transformed parameters.
+mpmath.mp.dps = 50
+
+# (u, m) pairs for m < 0 and m > 1
+ellipj_transformed_values = [
+    (0.7, -0.5), (3.0, -4.0), (-2.0, -30.0), (10.0, -1e-3), (0.4, 1.5), (2.0, 4.0), (-1.0, 9.0), (5.0, 1.0001)
+]
+
+print("const ELLIPJ_TRANSFORMED_TABLE: [(f64, f64, f64, f64, f64); {}] = [".format(len(ellipj_transformed_values)))
+for u, m in ellipj_transformed_values:
+    sn, cn, dn = [float(mpmath.re(mpmath.ellipfun(k, u, m=m))) for k in ('sn', 'cn', 'dn')]
+    print(f"    ({u:.16e}, {m:.16e}, {sn:.16e}, {cn:.16e}, {dn:.16e}),")
+print("];")
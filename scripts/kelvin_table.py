@@ -0,0 +1,29 @@
+import mpmath
+
+# ┌──────────────────────────────────────────────────────────┐
+#  berbei, kerkei
+# └──────────────────────────────────────────────────────────┘
+# scipy only provides the Kelvin functions of order 0, so mpmath is used here.
+mpmath.mp.dps = 50
+
+# (nu, x) pairs cover the complex Bessel functions, the asymptotic expansions from x = 30
+# and negative orders.
+kelvin_values = [
+    (0.0, 1e-3), (0.0, 0.5), (0.0, 4.0), (0.0, 12.0), (0.0, 35.0), (0.0, 120.0),
+    (1.0, 2.0), (1.0, 29.0), (1.0, 31.0), (2.0, 0.1), (2.0, 8.0), (0.3, 6.0),
+    (2.5, 45.0), (7.25, 10.0), (7.25, 80.0), (20.0, 15.0), (35.5, 60.0), (3.0, 600.0),
+    (-0.4, 3.0), (-1.0, 7.0), (-2.5, 50.0), (-3.7, 0.9), (-3.7, 25.0), (-12.3, 40.0)
+]
+
+
+def kelvin(nu, x):
+    be = lambda t: mpmath.ber(nu, t) + 1j * mpmath.bei(nu, t)
+    ke = lambda t: mpmath.ker(nu, t) + 1j * mpmath.kei(nu, t)
+    values = [be(x), mpmath.diff(be, x), ke(x), mpmath.diff(ke, x)]
+    return [f"{float(part):.16e}" for value in values for part in (value.real, value.imag)]
+
+
+print("const KELVIN_TABLE: [(f64, f64, [f64; 8]); {}] = [".format(len(kelvin_values)))
+for nu, x in kelvin_values:
+    print(f"    ({nu:.2f}, {x:.16e}, [{', '.join(kelvin(nu, x))}]),")
+print("];")
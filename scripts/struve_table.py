@@ -0,0 +1,19 @@
+from scipy.special import struve, modstruve
+
+# ┌──────────────────────────────────────────────────────────┐
+#  struve_h, struve_l
+# └──────────────────────────────────────────────────────────┘
+# (nu, x) pairs cover the power series, the expansion in Bessel functions, the recurrence for
+# nu < -1 and the asymptotic expansions, together with negative half-integer orders.
+struve_values = [
+    (0.0, 0.5), (0.0, 5.0), (0.0, 12.0), (0.0, 30.0), (0.0, 400.0),
+    (1.0, 3.0), (1.0, 20.0), (1.0, 100.0), (0.3, 8.0), (2.5, 10.0),
+    (7.25, 40.0), (40.1, 20.0), (40.1, 45.0), (120.0, 100.0), (200.0, 190.0),
+    (-0.4, 15.0), (-0.7, 25.0), (-1.7, 12.0), (-3.0, 10.0), (-7.7, 20.0),
+    (-2.5, 3.0), (-12.3, 0.8), (0.5, 1e-3), (3.0, 250.0)
+]
+
+print("const STRUVE_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(struve_values)))
+for nu, x in struve_values:
+    print(f"    ({nu:.2f}, {x:.16e}, {struve(nu, x):.16e}, {modstruve(nu, x):.16e}),")
+print("];")
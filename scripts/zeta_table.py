@@ -0,0 +1,34 @@
+from scipy.special import zeta, zetac
+
+# ┌──────────────────────────────────────────────────────────┐
+#  zeta & zetac
+# └──────────────────────────────────────────────────────────┘
+# s values to test
+s_values = [
+    -255.5, -171.3, -49.0, -20.5, -3.0, -1.0, -0.999,  # Functional equation
+    -0.5, -1e-8, 0.0, 1e-8, 0.3, 0.5, 0.999, 0.9999999,  # Alternating series
+    1.0000001, 1.001, 1.5, 2.0, 3.0, 7.5, 20.0, 50.0, 100.0, 1000.0  # Hurwitz zeta at a = 1 and 2
+]
+
+zeta_table = [(s, zeta(s), zetac(s)) for s in s_values]
+
+print("const ZETA_TABLE: [(f64, f64, f64); {}] = [".format(len(zeta_table)))
+for s, y, yc in zeta_table:
+    print(f"    ({s:.16e}, {y:.16e}, {yc:.16e}),")
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  hurwitz_zeta
+# └──────────────────────────────────────────────────────────┘
+# (s, a) values to test
+hurwitz_values = [
+    (1.5, 0.1), (2.0, 1e-5), (2.0, 0.5), (3.0, 10.5), (1.01, 3.0), (1.2, 400.0),
+    (30.0, 0.7), (200.0, 1.5), (2.0, 1e8), (4.0, 1e-70)
+]
+
+hurwitz_zeta_table = [(s, a, zeta(s, a)) for s, a in hurwitz_values]
+
+print("\nconst HURWITZ_ZETA_TABLE: [(f64, f64, f64); {}] = [".format(len(hurwitz_zeta_table)))
+for s, a, y in hurwitz_zeta_table:
+    print(f"    ({s:.16e}, {a:.16e}, {y:.16e}),")
+print("];")
@@ -0,0 +1,204 @@
+//! This module provides implementations of the Airy functions and related functions.
+//!
+//! It includes the following main functions:
+//! - `airy`: Calculates the Airy functions Ai, Bi and their derivatives.
+//! - `airy_scaled`: Calculates the exponentially scaled Airy functions and their derivatives.
+
+use crate::bessel::{besselik, besseljy};
+use crate::{EPS, INV_SQRT_PI};
+use core::f64::consts::{FRAC_1_SQRT_2, PI};
+
+/// Ai(0)
+const AI0: f64 = 0.355_028_053_887_817_2;
+/// -Ai'(0)
+const AIP0: f64 = 0.258_819_403_792_806_8;
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+const FRAC_1_SQRT_3: f64 = 0.577_350_269_189_625_8;
+/// |x| up to which the Maclaurin series is used
+const XSERIES: f64 = 1.0;
+/// ζ = 2/3 |x|^(3/2) from which the asymptotic expansions are used
+const ZASYM: f64 = 20.0;
+
+/// Calculates the Airy functions and their derivatives
+///
+/// The Airy functions $\mathrm{Ai}(x)$ and $\mathrm{Bi}(x)$ are the two independent solutions of
+///
+/// $$ y''(x) - x y(x) = 0 $$
+///
+/// where $\mathrm{Ai}(x)$ decays for $x \to \infty$ and $\mathrm{Bi}(x)$ grows.
+/// Both oscillate for $x \to -\infty$.
+/// `Ai(x)` underflows to 0 for `x` > 105 and `Bi(x)` overflows to +∞ for `x` > 104;
+/// use [`airy_scaled`] in that range.
+///
+/// # Arguments
+///
+/// * `x` - The input value
+///
+/// # Returns
+///
+/// * `Ai(x)` - Airy function of the first kind
+/// * `Bi(x)` - Airy function of the second kind
+/// * `Ai'(x)` - Derivative of the Airy function of the first kind
+/// * `Bi'(x)` - Derivative of the Airy function of the second kind
+pub fn airy(x: f64) -> (f64, f64, f64, f64) {
+    airy_impl(x, false)
+}
+
+/// Calculates the exponentially scaled Airy functions and their derivatives
+///
+/// With $\zeta = \frac{2}{3} x^{3/2}$, the scaled functions are
+///
+/// $$ e^{\zeta} \mathrm{Ai}(x), \quad e^{-\zeta} \mathrm{Bi}(x), \quad e^{\zeta} \mathrm{Ai}'(x), \quad e^{-\zeta} \mathrm{Bi}'(x) $$
+///
+/// for $x > 0$. They neither underflow nor overflow for large $x$.
+/// For $x \le 0$ the Airy functions are returned unscaled, as in [`airy`].
+///
+/// # Arguments
+///
+/// * `x` - The input value
+///
+/// # Returns
+///
+/// * `Ai(x)` - Scaled Airy function of the first kind
+/// * `Bi(x)` - Scaled Airy function of the second kind
+/// * `Ai'(x)` - Scaled derivative of the Airy function of the first kind
+/// * `Bi'(x)` - Scaled derivative of the Airy function of the second kind
+pub fn airy_scaled(x: f64) -> (f64, f64, f64, f64) {
+    airy_impl(x, true)
+}
+
+fn airy_impl(x: f64, scaled: bool) -> (f64, f64, f64, f64) {
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+    } else if x == f64::INFINITY {
+        return if scaled {
+            (0f64, 0f64, f64::NEG_INFINITY, f64::INFINITY)
+        } else {
+            (0f64, f64::INFINITY, 0f64, f64::INFINITY)
+        };
+    }
+    let absx = x.abs();
+    let rootx = absx.sqrt();
+    let z = 2f64 / 3f64 * absx * rootx;
+    let (ai, bi, aip, bip) = if z >= ZASYM {
+        let (ai, bi, aip, bip) = airy_asymptotic(x, z);
+        if scaled || x < 0f64 {
+            return (ai, bi, aip, bip);
+        }
+        let (decay, growth) = ((-z).exp(), z.exp());
+        return (ai * decay, bi * growth, aip * decay, bip * growth);
+    } else if absx <= XSERIES {
+        airy_series(x)
+    } else if x > 0f64 {
+        // Ai(x) = √(x/3) K_{1/3}(ζ) / π,  Bi(x) = √(x/3) (I_{1/3}(ζ) + I_{-1/3}(ζ)),
+        // where I_{-ν}(ζ) = I_ν(ζ) + 2 sin(νπ) K_ν(ζ) / π.
+        let (ri, rk, _, _) = besselik(1f64 / 3f64, z);
+        let ai = rootx * FRAC_1_SQRT_3 * rk / PI;
+        let bi = rootx * (rk / PI + 2f64 * FRAC_1_SQRT_3 * ri);
+        let (ri, rk, _, _) = besselik(2f64 / 3f64, z);
+        let aip = -x * FRAC_1_SQRT_3 * rk / PI;
+        let bip = x * (rk / PI + 2f64 * FRAC_1_SQRT_3 * ri);
+        (ai, bi, aip, bip)
+    } else {
+        // Ai(-x) = √x (J_{1/3}(ζ) + J_{-1/3}(ζ)) / 3,  Bi(-x) = √(x/3) (J_{-1/3}(ζ) - J_{1/3}(ζ)),
+        // where J_{-ν}(ζ) = cos(νπ) J_ν(ζ) - sin(νπ) Y_ν(ζ).
+        let (rj, ry, _, _) = besseljy(1f64 / 3f64, z);
+        let ai = 0.5 * rootx * (rj - FRAC_1_SQRT_3 * ry);
+        let bi = -0.5 * rootx * (ry + FRAC_1_SQRT_3 * rj);
+        let (rj, ry, _, _) = besseljy(2f64 / 3f64, z);
+        let aip = 0.5 * absx * (FRAC_1_SQRT_3 * ry + rj);
+        let bip = 0.5 * absx * (FRAC_1_SQRT_3 * rj - ry);
+        (ai, bi, aip, bip)
+    };
+    if scaled && x > 0f64 {
+        let (growth, decay) = (z.exp(), (-z).exp());
+        (ai * growth, bi * decay, aip * growth, bip * decay)
+    } else {
+        (ai, bi, aip, bip)
+    }
+}
+
+/// Airy functions from the Maclaurin series
+///
+/// Ai(x) = Ai(0) f(x) + Ai'(0) g(x) and Bi(x) = √3 (Ai(0) f(x) - Ai'(0) g(x)) with
+/// f(x) = Σ 3^k (1/3)_k x^(3k) / (3k)! and g(x) = Σ 3^k (2/3)_k x^(3k+1) / (3k+1)!.
+fn airy_series(x: f64) -> (f64, f64, f64, f64) {
+    let x3 = x * x * x;
+    let (mut f, mut tf) = (1f64, 1f64);
+    let (mut g, mut tg) = (x, x);
+    let (mut fp, mut tfp) = (0.5 * x * x, 0.5 * x * x);
+    let (mut gp, mut tgp) = (1f64, 1f64);
+    for k in 1..100 {
+        let k = k as f64;
+        tf *= x3 / ((3f64 * k - 1f64) * (3f64 * k));
+        tg *= x3 / ((3f64 * k) * (3f64 * k + 1f64));
+        tfp *= x3 / ((3f64 * k) * (3f64 * k + 2f64));
+        tgp *= x3 / ((3f64 * k - 2f64) * (3f64 * k));
+        f += tf;
+        g += tg;
+        fp += tfp;
+        gp += tgp;
+        if tf.abs() <= EPS * f.abs() && tg.abs() <= EPS * g.abs() && tfp.abs() <= EPS * fp.abs() {
+            break;
+        }
+    }
+    (
+        AI0 * f - AIP0 * g,
+        SQRT_3 * (AI0 * f + AIP0 * g),
+        AI0 * fp - AIP0 * gp,
+        SQRT_3 * (AI0 * fp + AIP0 * gp),
+    )
+}
+
+/// Scaled Airy functions from the asymptotic expansions for ζ ≥ ZASYM (DLMF 9.7.5-9.7.12)
+fn airy_asymptotic(x: f64, z: f64) -> (f64, f64, f64, f64) {
+    // Sums of the terms u_k / ζ^k and v_k / ζ^k of the expansions. For x > 0 they are taken
+    // with the signs (-1)^k for Ai and without for Bi. For x < 0 they are taken with the signs
+    // (-1)^⌊k/2⌋ and split into even and odd k.
+    let zi = z.recip();
+    let mut u_sum = if x > 0f64 { [1f64, 1f64] } else { [1f64, 0f64] };
+    let mut v_sum = u_sum;
+    let mut u = 1f64;
+    for k in 1..100 {
+        let kf = k as f64;
+        u *= (6f64 * kf - 5f64) * (6f64 * kf - 3f64) * (6f64 * kf - 1f64)
+            / ((2f64 * kf - 1f64) * 216f64 * kf)
+            * zi;
+        let v = -(6f64 * kf + 1f64) / (6f64 * kf - 1f64) * u;
+        if x > 0f64 {
+            let sign = if k % 2 == 0 { 1f64 } else { -1f64 };
+            u_sum[0] += sign * u;
+            v_sum[0] += sign * v;
+            u_sum[1] += u;
+            v_sum[1] += v;
+        } else {
+            let sign = if k % 4 < 2 { 1f64 } else { -1f64 };
+            u_sum[k % 2] += sign * u;
+            v_sum[k % 2] += sign * v;
+        }
+        if u <= EPS {
+            break;
+        }
+    }
+
+    let root4 = x.abs().sqrt().sqrt();
+    if x > 0f64 {
+        let ai = 0.5 * INV_SQRT_PI / root4 * u_sum[0];
+        let bi = INV_SQRT_PI / root4 * u_sum[1];
+        let aip = -0.5 * INV_SQRT_PI * root4 * v_sum[0];
+        let bip = INV_SQRT_PI * root4 * v_sum[1];
+        (ai, bi, aip, bip)
+    } else {
+        let (sin, cos) = z.sin_cos();
+        // sin(ζ - π/4) and cos(ζ - π/4)
+        let s = FRAC_1_SQRT_2 * (sin - cos);
+        let c = FRAC_1_SQRT_2 * (cos + sin);
+        let (p, q) = (u_sum[0], u_sum[1]);
+        let (r, t) = (v_sum[0], v_sum[1]);
+        let ai = INV_SQRT_PI / root4 * (c * p + s * q);
+        let bi = INV_SQRT_PI / root4 * (c * q - s * p);
+        let aip = INV_SQRT_PI * root4 * (s * r - c * t);
+        let bip = INV_SQRT_PI * root4 * (c * r + s * t);
+        (ai, bi, aip, bip)
+    }
+}
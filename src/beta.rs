@@ -2,10 +2,18 @@
 //!
 //! It includes the following main functions:
 //! - `beta`: Calculates the beta function.
+//! - `ln_beta`: Calculates the natural logarithm of the beta function.
 //! - `betai`: Calculates the regularized incomplete beta function.
+//! - `betaic`: Calculates the complementary regularized incomplete beta function.
+//! - `ln_betai`: Calculates the natural logarithm of the regularized incomplete beta function.
 //! - `invbetai`: Calculates the inverse of the regularized incomplete beta function.
+//! - `invbetaic`: Calculates the inverse of the complementary regularized incomplete beta function.
+//! - `betai_grad`: Calculates the regularized incomplete beta function together with its derivatives with respect to the shape parameters.
+//! - `try_betai`, `try_betaic`: Versions of `betai` and `betaic` that return errors instead of panicking.
 
-use crate::{ln_gamma, EPS, FPMIN, W, Y};
+use crate::gamma::{stirling_correction, LN_SQRT_2PI};
+use crate::utils::continued_fraction_with_gradient;
+use crate::{digamma, ln_gamma, SpecialFunctionError, EPS, FPMIN, W, Y};
 const SWITCH: usize = 3000;
 
 /// Calculates the beta function.
@@ -27,7 +35,45 @@ const SWITCH: usize = 3000;
 ///
 /// The value of the beta function $B(z,w)$
 pub fn beta(z: f64, w: f64) -> f64 {
-    (ln_gamma(z) + ln_gamma(w) - ln_gamma(z + w)).exp()
+    ln_beta(z, w).exp()
+}
+
+/// Calculates the natural logarithm of the beta function.
+///
+/// $$ \ln B(a,b) = \ln\Gamma(a) + \ln\Gamma(b) - \ln\Gamma(a+b) $$
+///
+/// When one of the parameters is large, the log-gamma terms nearly cancel,
+/// so they are combined analytically with Stirling's series instead of being subtracted.
+/// For non-positive parameters $\ln|B(a,b)|$ is returned.
+///
+/// # Arguments
+///
+/// * `a` - First parameter
+/// * `b` - Second parameter
+///
+/// # Returns
+///
+/// The value of $\ln B(a,b)$
+pub fn ln_beta(a: f64, b: f64) -> f64 {
+    let (a, b) = if a < b { (a, b) } else { (b, a) };
+    if a.is_nan() || a <= 0f64 {
+        return ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b);
+    }
+    let c = a + b;
+    if a >= 10f64 {
+        // Stirling's series for all three terms
+        let correction = stirling_correction(a) + stirling_correction(b) - stirling_correction(c);
+        LN_SQRT_2PI - 0.5 * c.ln()
+            + (a - 0.5) * (a / c).ln()
+            + (b - 0.5) * (-a / c).ln_1p()
+            + correction
+    } else if b >= 10f64 {
+        // Stirling's series for ln Γ(b) - ln Γ(a+b)
+        let correction = stirling_correction(b) - stirling_correction(c);
+        ln_gamma(a) + a - a * c.ln() - (b - 0.5) * (a / b).ln_1p() + correction
+    } else {
+        ln_gamma(a) + ln_gamma(b) - ln_gamma(c)
+    }
 }
 
 // =============================================================================
@@ -55,23 +101,205 @@ pub fn beta(z: f64, w: f64) -> f64 {
 ///
 /// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
 pub fn betai(a: f64, b: f64, x: f64) -> f64 {
-    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine betai");
-    assert!((0f64..=1f64).contains(&x), "Bad x in routine betai");
+    try_betai(a, b, x).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Calculates the regularized incomplete beta function, returning an error instead of panicking.
+///
+/// See [`betai`] for details.
+///
+/// # Errors
+///
+/// Returns [`SpecialFunctionError::Domain`] if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn try_betai(a: f64, b: f64, x: f64) -> Result<f64, SpecialFunctionError> {
+    if !(a > 0f64 && b > 0f64) {
+        return Err(SpecialFunctionError::Domain("Bad a or b in routine betai"));
+    }
+    if !(0f64..=1f64).contains(&x) {
+        return Err(SpecialFunctionError::Domain("Bad x in routine betai"));
+    }
     if x == 0f64 || x == 1f64 {
-        return x;
+        return Ok(x);
     }
-    let switch = SWITCH as f64;
-    if a > switch && b > switch {
-        return betaiapprox(a, b, x);
+    if use_quadrature(a, b, x) {
+        return Ok(betaiapprox(a, b, x));
+    }
+    let bt = (a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b)).exp();
+    if x < (a + 1f64) / (a + b + 2f64) {
+        Ok(bt * betacf(a, b, x) / a)
+    } else {
+        Ok(1f64 - bt * betacf(b, a, 1f64 - x) / b)
+    }
+}
+
+/// Calculates the complementary regularized incomplete beta function.
+///
+/// The complementary regularized incomplete beta function is defined as:
+///
+/// $$ 1 - I_x(a,b) = I_{1-x}(b,a) = \frac{1}{B(a,b)} \int_x^1 t^{a-1} (1-t)^{b-1} dt $$
+///
+/// Unlike `1 - betai(a, b, x)` it is computed without cancellation,
+/// so it stays accurate in the upper tail where $I_x(a,b)$ is close to 1.
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Lower limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of $1 - I_x(a,b)$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn betaic(a: f64, b: f64, x: f64) -> f64 {
+    try_betaic(a, b, x).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Calculates the complementary regularized incomplete beta function, returning an error instead of panicking.
+///
+/// See [`betaic`] for details.
+///
+/// # Errors
+///
+/// Returns [`SpecialFunctionError::Domain`] if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn try_betaic(a: f64, b: f64, x: f64) -> Result<f64, SpecialFunctionError> {
+    if !(a > 0f64 && b > 0f64) {
+        return Err(SpecialFunctionError::Domain("Bad a or b in routine betaic"));
+    }
+    if !(0f64..=1f64).contains(&x) {
+        return Err(SpecialFunctionError::Domain("Bad x in routine betaic"));
+    }
+    if x == 0f64 || x == 1f64 {
+        return Ok(1f64 - x);
+    }
+    if use_quadrature(a, b, x) {
+        // The quadrature is accurate for the tail below the mean, which is this side of I_{1-x}(b,a).
+        return Ok(betaiapprox(b, a, 1f64 - x));
     }
-    let bt = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1f64 - x).ln()).exp();
-    if x < (a + 1f64) / (a + b * 2f64) {
-        bt * betacf(a, b, x) / a
+    let bt = (a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b)).exp();
+    if x < (a + 1f64) / (a + b + 2f64) {
+        Ok(1f64 - bt * betacf(a, b, x) / a)
     } else {
-        1f64 - bt * betacf(b, a, 1f64 - x) / b
+        Ok(bt * betacf(b, a, 1f64 - x) / b)
     }
 }
 
+/// Calculates the natural logarithm of the regularized incomplete beta function.
+///
+/// $$ \ln I_x(a,b) = \ln \left[ \frac{1}{B(a,b)} \int_0^x t^{a-1} (1-t)^{b-1} dt \right] $$
+///
+/// Unlike `betai(a, b, x).ln()` it stays finite in the deep lower tail, where $I_x(a,b)$ underflows to 0.
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Upper limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of $\ln I_x(a,b)$, which is -∞ for `x` = 0
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn ln_betai(a: f64, b: f64, x: f64) -> f64 {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine ln_betai");
+    assert!((0f64..=1f64).contains(&x), "Bad x in routine ln_betai");
+    if x == 0f64 {
+        return f64::NEG_INFINITY;
+    } else if x == 1f64 {
+        return 0f64;
+    }
+    if use_quadrature(a, b, x) {
+        if x >= a / (a + b) {
+            // I_x(a,b) = 1 - I_{1-x}(b,a), where the latter is the lower tail integral of the quadrature
+            return (-betaiapprox(b, a, 1f64 - x)).ln_1p();
+        }
+        return betaiapprox(a, b, x).ln();
+    }
+    let ln_bt = a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b);
+    if x < (a + 1f64) / (a + b + 2f64) {
+        ln_bt + (betacf(a, b, x) / a).ln()
+    } else {
+        (-ln_bt.exp() * betacf(b, a, 1f64 - x) / b).ln_1p()
+    }
+}
+
+/// Calculates the regularized incomplete beta function together with its derivatives with respect to `a` and `b`.
+///
+/// The derivatives are obtained by differentiating the continued fraction used for $I_x(a,b)$
+/// term by term, together with the prefactor $x^a (1-x)^b / (a B(a,b))$.
+/// The derivative with respect to `x` has the closed form $x^{a-1} (1-x)^{b-1} / B(a,b)$.
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Upper limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// A tuple $(I_x(a,b), \partial I_x(a,b) / \partial a, \partial I_x(a,b) / \partial b)$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn betai_grad(a: f64, b: f64, x: f64) -> (f64, f64, f64) {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine betai_grad");
+    assert!((0f64..=1f64).contains(&x), "Bad x in routine betai_grad");
+    if x == 0f64 || x == 1f64 {
+        return (x, 0f64, 0f64);
+    }
+    if x < (a + 1f64) / (a + b + 2f64) {
+        betai_cf_grad(a, b, x)
+    } else {
+        // I_x(a,b) = 1 - I_{1-x}(b,a)
+        let (value, db, da) = betai_cf_grad(b, a, 1f64 - x);
+        (1f64 - value, -da, -db)
+    }
+}
+
+/// The continued fraction representation of the incomplete beta function and its derivatives.
+fn betai_cf_grad(a: f64, b: f64, x: f64) -> (f64, f64, f64) {
+    let psi_ab = digamma(a + b);
+    let log_prefactor = a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b) - a.ln();
+    let prefactor = log_prefactor.exp();
+    let dlog_prefactor_a = x.ln() - 1f64 / a - digamma(a) + psi_ab;
+    let dlog_prefactor_b = (-x).ln_1p() - digamma(b) + psi_ab;
+
+    // betacf = 1 / (1 + d₁ / (1 + d₂ / (1 + ...)))
+    let (denominator, [dden_a, dden_b]) = continued_fraction_with_gradient(1f64, [0f64; 2], |n| {
+        let m = (n / 2) as f64;
+        if n % 2 == 1 {
+            let d = -(a + m) * (a + b + m) * x / ((a + 2f64 * m) * (a + 2f64 * m + 1f64));
+            let dd_a = d
+                * (1f64 / (a + m) + 1f64 / (a + b + m)
+                    - 1f64 / (a + 2f64 * m)
+                    - 1f64 / (a + 2f64 * m + 1f64));
+            let dd_b = d / (a + b + m);
+            (d, 1f64, [dd_a, dd_b], [0f64; 2])
+        } else {
+            let denom = (a + 2f64 * m - 1f64) * (a + 2f64 * m);
+            let d = m * (b - m) * x / denom;
+            let dd_a = -d * (1f64 / (a + 2f64 * m - 1f64) + 1f64 / (a + 2f64 * m));
+            let dd_b = m * x / denom;
+            (d, 1f64, [dd_a, dd_b], [0f64; 2])
+        }
+    });
+    let fraction = 1f64 / denominator;
+    let scale = -prefactor / (denominator * denominator);
+    let value = prefactor * fraction;
+    (
+        value,
+        value * dlog_prefactor_a + scale * dden_a,
+        value * dlog_prefactor_b + scale * dden_b,
+    )
+}
+
 /// Continued fraction beta
 fn betacf(a: f64, b: f64, x: f64) -> f64 {
     let qab = a + b;
@@ -117,6 +345,21 @@ fn betacf(a: f64, b: f64, x: f64) -> f64 {
     h
 }
 
+/// Whether the incomplete beta function is computed by [`betaiapprox`] instead of the continued fraction
+///
+/// For large `a` and `b` the continued fraction needs many iterations near the mean,
+/// but more than 5 standard deviations away it converges quickly,
+/// while the fixed quadrature loses accuracy in the far tails.
+fn use_quadrature(a: f64, b: f64, x: f64) -> bool {
+    let switch = SWITCH as f64;
+    if !(a > switch && b > switch) {
+        return false;
+    }
+    let c = a + b;
+    let sigma = (a * b / (c * c * (c + 1f64))).sqrt();
+    (x - a / c).abs() < 5f64 * sigma
+}
+
 /// Incomplete beta by Gauss Legendre quadrature
 fn betaiapprox(a: f64, b: f64, x: f64) -> f64 {
     let a1 = a - 1f64;
@@ -139,11 +382,10 @@ fn betaiapprox(a: f64, b: f64, x: f64) -> f64 {
     let mut sum = 0f64;
     for j in 0..18 {
         t = x + (xu - x) * Y[j];
-        sum += W[j] * (a1 * (t.ln() - lnmu) + b1 * (1f64 - t).ln() - lnmuc).exp();
+        sum += W[j] * (a1 * (t.ln() - lnmu) + b1 * ((1f64 - t).ln() - lnmuc)).exp();
     }
-    let ans = sum
-        * (xu - x)
-        * (a1 * lnmu - ln_gamma(a) + b1 * lnmuc - ln_gamma(b) + ln_gamma(a + b)).exp();
+    // ln_beta avoids the cancellation between the log-gamma terms, which are of order a ln a
+    let ans = sum * (xu - x) * (a1 * lnmu + b1 * lnmuc - ln_beta(a, b)).exp();
     if ans > 0f64 {
         1f64 - ans
     } else {
@@ -226,3 +468,64 @@ pub fn invbetai(p: f64, a: f64, b: f64) -> f64 {
     }
     x
 }
+
+/// Calculates the inverse of the complementary regularized incomplete beta function.
+///
+/// This function finds $x$ such that:
+///
+/// $$ q = 1 - I_x(a,b) = \frac{1}{B(a,b)} \int_x^1 t^{a-1} (1-t)^{b-1} dt $$
+///
+/// Unlike `invbetai(1 - q, a, b)` it stays accurate for small upper tail probabilities `q`,
+/// where `1 - q` rounds to 1.
+///
+/// # Arguments
+///
+/// * `q` - The upper tail probability (between 0 and 1)
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+///
+/// # Returns
+///
+/// The value of $x$ for which $1 - I_x(a,b) = q$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0 or if `b` ≤ 0.
+pub fn invbetaic(q: f64, a: f64, b: f64) -> f64 {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine invbetaic");
+    if q <= 0f64 {
+        return 1f64;
+    } else if q >= 1f64 {
+        return 0f64;
+    }
+    // Start from the inverse of whichever tail is the smaller, 1 - q is exact for q ≥ 1/2.
+    let mut x = if q < 0.5 {
+        1f64 - invbetai(q, b, a)
+    } else {
+        invbetai(1f64 - q, a, b)
+    };
+    let a1 = a - 1f64;
+    let b1 = b - 1f64;
+    let afac = -ln_beta(a, b);
+    for j in 0..10 {
+        if x == 0f64 || x == 1f64 {
+            return x;
+        }
+        // Halley's method, d(1 - I_x)/dx = -x^(a-1) (1-x)^(b-1) / B(a,b)
+        let err = betaic(a, b, x) - q;
+        let t = (a1 * x.ln() + b1 * (-x).ln_1p() + afac).exp();
+        let u = -err / t;
+        let t = u / (1f64 - 0.5 * 1f64.min(u * (a1 / x - b1 / (1f64 - x))));
+        x -= t;
+        if x <= 0f64 {
+            x = 0.5 * (x + t);
+        }
+        if x >= 1f64 {
+            x = 0.5 * (x + t + 1f64);
+        }
+        if t.abs() < EPS * x && j > 0 {
+            break;
+        }
+    }
+    x
+}
@@ -4,50 +4,54 @@
 //! - `dawson`: Calculates Dawson's integral for real values.
 
 use crate::utils::sign;
-use core::f64::consts::PI;
+use crate::{EPS, INV_SQRT_PI};
 
 /// Dawson's integral for real values.
-/// Accurate to about 2e-7 for small inputs.
+///
+/// $$ F(x) = e^{-x^2} \int_0^x e^{t^2} dt $$
+///
+/// # Notes on implementation
+///
+/// The power series is used for |x| < 0.5, otherwise Rybicki's method as in Numerical Recipes,
+/// with a step small enough for double precision, and $F(x) \sim 1/(2x)$ for very large |x|.
 pub fn dawson(x: f64) -> f64 {
-    const NMAX: usize = 6;
-    let h = 0.4;
-    let a1 = 2. / 3.;
-    let a2 = 0.4;
-    let a3 = 2. / 7.;
-    let n0: u64; // i is declared in the loops.
-    let mut d1: f64;
-    let mut d2: f64;
-    let mut e1: f64;
-    let e2: f64;
-    let mut sum: f64;
-    let x2: f64;
-    let xp: f64;
-    let xx: f64;
-    let ans: f64;
+    const NMAX: usize = 16;
+    let h = 0.2;
+    let xx = x.abs();
 
-    if x.abs() < 0.2 {
-        x2 = x * x;
-        ans = x * (1.0 - a1 * x2 * (1.0 - a2 * x2 * (1.0 - a3 * x2)));
+    if xx < 0.5 {
+        // F(x) = Σ_n (-2x²)^n x / (2n+1)!!
+        let x2 = x * x;
+        let mut term = x;
+        let mut ans = x;
+        for n in 1..NMAX {
+            term *= -2. * x2 / (2 * n + 1) as f64;
+            ans += term;
+            if term.abs() <= EPS * ans.abs() {
+                break;
+            }
+        }
+        ans
+    } else if xx > 1e8 {
+        0.5 / x * (1. + 0.5 / (x * x))
     } else {
-        xx = x.abs();
-        n0 = 2 * (0.5 * xx / h + 0.5) as u64; // Simply truncates the decimal places, as in NR.
-        xp = xx - n0 as f64 * h;
-        e1 = f64::exp(2.0 * xp * h);
-        e2 = e1 * e1;
-        d1 = n0 as f64 + 1.;
-        d2 = d1 - 2.0;
-        sum = 0.0;
+        let n0 = 2 * (0.5 * xx / h + 0.5) as u64; // Simply truncates the decimal places, as in NR.
+        let xp = xx - n0 as f64 * h;
+        let mut e1 = f64::exp(2.0 * xp * h);
+        let e2 = e1 * e1;
+        let mut d1 = n0 as f64 + 1.;
+        let mut d2 = d1 - 2.0;
+        let mut sum = 0.0;
 
         for i in 0..NMAX {
             let cval = f64::exp(-((2. * i as f64 + 1.) * h).powi(2));
+            sum += cval * (e1 / d1 + 1.0 / (d2 * e1));
+
             d1 += 2.;
             d2 -= 2.;
             e1 *= e2;
-
-            sum += cval * (e1 / d1 + 1.0 / (d2 * e1));
         }
 
-        ans = (1. / PI.sqrt()) * sign(f64::exp(-xp * xp), x) * sum;
+        INV_SQRT_PI * sign(f64::exp(-xp * xp), x) * sum
     }
-    ans
 }
@@ -0,0 +1,771 @@
+//! This module provides probability distributions built on the special functions of this crate.
+//!
+//! It includes the following distributions:
+//! - `Normal`: The normal distribution, through `erfc` and `inverfc`.
+//! - `ChiSquared`: The chi-squared distribution, through `gammp` and `gammq`.
+//! - `StudentT`: Student's t-distribution, through `betai` and `betaic`.
+//! - `FisherF`: The F-distribution, through `betai` and `betaic`.
+//! - `Gamma`: The gamma distribution, through `gammp` and `gammq`.
+//! - `Beta`: The beta distribution, through `betai` and `betaic`.
+//! - `Poisson`: The Poisson distribution, through `gammp` and `gammq`.
+//! - `Binomial`: The binomial distribution, through `betai` and `betaic`.
+//!
+//! The continuous distributions implement [`ContinuousDistribution`]
+//! and the discrete distributions implement [`DiscreteDistribution`].
+
+use crate::gamma::LN_SQRT_2PI;
+use crate::{
+    betai, betaic, erfc, gammp, gammq, invbetai, invbetaic, inverfc, invgammp, invgammq, ln_beta,
+    ln_gamma, ln_lower_incomplete_gamma, ln_upper_incomplete_gamma, SpecialFunctionError, EPS,
+};
+use core::f64::consts::SQRT_2;
+
+/// A continuous probability distribution on the real line.
+pub trait ContinuousDistribution {
+    /// Calculates the probability density function at `x`.
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the natural logarithm of the probability density function at `x`.
+    ///
+    /// This is -∞ outside of the support of the distribution.
+    fn ln_pdf(&self, x: f64) -> f64;
+
+    /// Calculates the cumulative distribution function $P(X \le x)$.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Calculates the survival function $P(X > x) = 1 - \text{cdf}(x)$ without cancellation.
+    fn sf(&self, x: f64) -> f64;
+
+    /// Calculates the quantile function, the inverse of [`cdf`](Self::cdf).
+    ///
+    /// `p` ≤ 0 and `p` ≥ 1 give the lower and the upper end of the support, and NaN gives NaN.
+    fn quantile(&self, p: f64) -> f64;
+}
+
+/// A discrete probability distribution on the non-negative integers.
+pub trait DiscreteDistribution {
+    /// Calculates the probability mass function $P(X = k)$.
+    fn pmf(&self, k: u64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    /// Calculates the natural logarithm of the probability mass function.
+    ///
+    /// This is -∞ outside of the support of the distribution.
+    fn ln_pmf(&self, k: u64) -> f64;
+
+    /// Calculates the cumulative distribution function $P(X \le k)$.
+    fn cdf(&self, k: u64) -> f64;
+
+    /// Calculates the survival function $P(X > k) = 1 - \text{cdf}(k)$ without cancellation.
+    fn sf(&self, k: u64) -> f64;
+
+    /// Calculates the quantile function, the smallest `k` with $\text{cdf}(k) \ge p$.
+    ///
+    /// The result is integer valued, or +∞ for `p` ≥ 1 if the support is unbounded, and NaN for NaN `p`.
+    fn quantile(&self, p: f64) -> f64;
+}
+
+// =============================================================================
+// Normal distribution
+// =============================================================================
+/// The normal distribution with mean μ and standard deviation σ.
+///
+/// $$ f(x) = \frac{1}{\sigma \sqrt{2\pi}} \exp\left(-\frac{(x - \mu)^2}{2\sigma^2}\right) $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Creates a normal distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mean` is not finite or if `std_dev` is not positive and finite.
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        Self::try_new(mean, std_dev).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a normal distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `mean` is not finite or if `std_dev` is not positive and finite.
+    pub fn try_new(mean: f64, std_dev: f64) -> Result<Self, SpecialFunctionError> {
+        if !(mean.is_finite() && std_dev > 0f64 && std_dev.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in Normal"));
+        }
+        Ok(Self { mean, std_dev })
+    }
+
+    /// The standard normal distribution with μ = 0 and σ = 1.
+    pub fn standard() -> Self {
+        Self::new(0f64, 1f64)
+    }
+}
+
+impl ContinuousDistribution for Normal {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.std_dev;
+        -0.5 * z * z - self.std_dev.ln() - LN_SQRT_2PI
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        0.5 * erfc((self.mean - x) / (self.std_dev * SQRT_2))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        0.5 * erfc((x - self.mean) / (self.std_dev * SQRT_2))
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p.is_nan() {
+            return f64::NAN;
+        } else if p <= 0f64 {
+            return f64::NEG_INFINITY;
+        } else if p >= 1f64 {
+            return f64::INFINITY;
+        }
+        // 1 - p is exact for p ≥ 1/2
+        let z = if p < 0.5 {
+            -SQRT_2 * inverfc(2f64 * p)
+        } else {
+            SQRT_2 * inverfc(2f64 * (1f64 - p))
+        };
+        self.mean + self.std_dev * z
+    }
+}
+
+// =============================================================================
+// Gamma & Chi-squared distributions
+// =============================================================================
+/// $P(a,x)$, also where [`gammp`] rounds to 0 below $x = a \epsilon$ or to 1 above $x = a + 20(\sqrt{a} + 1)$
+fn regularized_lower_gamma(a: f64, x: f64) -> f64 {
+    if x > 0f64 && x < a * EPS {
+        (ln_lower_incomplete_gamma(a, x) - ln_gamma(a)).exp()
+    } else if x > a + 20f64 * (a.sqrt() + 1f64) && x < f64::INFINITY {
+        1f64 - (ln_upper_incomplete_gamma(a, x) - ln_gamma(a)).exp()
+    } else {
+        gammp(a, x)
+    }
+}
+
+/// $Q(a,x)$, also where [`gammq`] rounds to 1 below $x = a \epsilon$ or to 0 above $x = a + 20(\sqrt{a} + 1)$
+fn regularized_upper_gamma(a: f64, x: f64) -> f64 {
+    if x > 0f64 && x < a * EPS {
+        1f64 - (ln_lower_incomplete_gamma(a, x) - ln_gamma(a)).exp()
+    } else if x > a + 20f64 * (a.sqrt() + 1f64) && x < f64::INFINITY {
+        (ln_upper_incomplete_gamma(a, x) - ln_gamma(a)).exp()
+    } else {
+        gammq(a, x)
+    }
+}
+
+/// The gamma distribution with shape k and scale θ.
+///
+/// $$ f(x) = \frac{x^{k-1} e^{-x/\theta}}{\Gamma(k) \theta^k}, \quad x \ge 0 $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+}
+
+impl Gamma {
+    /// Creates a gamma distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` or `scale` is not positive and finite.
+    pub fn new(shape: f64, scale: f64) -> Self {
+        Self::try_new(shape, scale).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a gamma distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `shape` or `scale` is not positive and finite.
+    pub fn try_new(shape: f64, scale: f64) -> Result<Self, SpecialFunctionError> {
+        if !(shape > 0f64 && shape.is_finite() && scale > 0f64 && scale.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in Gamma"));
+        }
+        Ok(Self { shape, scale })
+    }
+}
+
+impl ContinuousDistribution for Gamma {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let k = self.shape;
+        if x < 0f64 {
+            return f64::NEG_INFINITY;
+        } else if x == 0f64 {
+            // The density is 0, 1/θ or +∞ at the origin
+            return if k > 1f64 {
+                f64::NEG_INFINITY
+            } else if k == 1f64 {
+                -self.scale.ln()
+            } else {
+                f64::INFINITY
+            };
+        }
+        let y = x / self.scale;
+        (k - 1f64) * y.ln() - y - ln_gamma(k) - self.scale.ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        } else if x <= 0f64 {
+            return 0f64;
+        }
+        regularized_lower_gamma(self.shape, x / self.scale)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        } else if x <= 0f64 {
+            return 1f64;
+        }
+        regularized_upper_gamma(self.shape, x / self.scale)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p.is_nan() {
+            return f64::NAN;
+        } else if p <= 0f64 {
+            return 0f64;
+        } else if p >= 1f64 {
+            return f64::INFINITY;
+        }
+        let y = if p < 0.5 {
+            invgammp(p, self.shape)
+        } else {
+            invgammq(1f64 - p, self.shape)
+        };
+        y * self.scale
+    }
+}
+
+/// The chi-squared distribution with k degrees of freedom.
+///
+/// $$ f(x) = \frac{x^{k/2-1} e^{-x/2}}{2^{k/2} \Gamma(k/2)}, \quad x \ge 0 $$
+///
+/// This is the gamma distribution with shape k/2 and scale 2.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquared {
+    gamma: Gamma,
+}
+
+impl ChiSquared {
+    /// Creates a chi-squared distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dof` is not positive and finite.
+    pub fn new(dof: f64) -> Self {
+        Self::try_new(dof).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a chi-squared distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `dof` is not positive and finite.
+    pub fn try_new(dof: f64) -> Result<Self, SpecialFunctionError> {
+        if !(dof > 0f64 && dof.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in ChiSquared"));
+        }
+        Ok(Self {
+            gamma: Gamma::new(0.5 * dof, 2f64),
+        })
+    }
+}
+
+impl ContinuousDistribution for ChiSquared {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.gamma.ln_pdf(x)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        self.gamma.cdf(x)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.gamma.sf(x)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        self.gamma.quantile(p)
+    }
+}
+
+// =============================================================================
+// Beta, Student's t & F distributions
+// =============================================================================
+/// Solves $I_x(a,b) = p$ and returns $(x, 1 - x)$, where the smaller of the two is computed
+/// directly so that it keeps its relative accuracy.
+fn beta_quantile_pair(p: f64, a: f64, b: f64) -> (f64, f64) {
+    if p < 0.5 {
+        let x = invbetai(p, a, b);
+        if x <= 0.5 {
+            return (x, 1f64 - x);
+        }
+        // 1 - x solves 1 - I_{1-x}(b,a) = p
+        let y = invbetaic(p, b, a);
+        (1f64 - y, y)
+    } else {
+        // 1 - p is exact for p ≥ 1/2, and 1 - x solves I_{1-x}(b,a) = 1 - p
+        let q = 1f64 - p;
+        let y = invbetai(q, b, a);
+        if y <= 0.5 {
+            return (1f64 - y, y);
+        }
+        let x = invbetaic(q, a, b);
+        (x, 1f64 - x)
+    }
+}
+
+/// The beta distribution with shape parameters α and β.
+///
+/// $$ f(x) = \frac{x^{\alpha-1} (1-x)^{\beta-1}}{B(\alpha,\beta)}, \quad 0 \le x \le 1 $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+impl Beta {
+    /// Creates a beta distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` or `beta` is not positive and finite.
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        Self::try_new(alpha, beta).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a beta distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `alpha` or `beta` is not positive and finite.
+    pub fn try_new(alpha: f64, beta: f64) -> Result<Self, SpecialFunctionError> {
+        if !(alpha > 0f64 && alpha.is_finite() && beta > 0f64 && beta.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in Beta"));
+        }
+        Ok(Self { alpha, beta })
+    }
+}
+
+impl ContinuousDistribution for Beta {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let (a, b) = (self.alpha, self.beta);
+        if !(0f64..=1f64).contains(&x) {
+            return f64::NEG_INFINITY;
+        }
+        // Avoid 0 * ln(0) at the end points
+        let left = if a == 1f64 { 0f64 } else { (a - 1f64) * x.ln() };
+        let right = if b == 1f64 {
+            0f64
+        } else {
+            (b - 1f64) * (-x).ln_1p()
+        };
+        left + right - ln_beta(a, b)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        betai(self.alpha, self.beta, x.clamp(0f64, 1f64))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        betaic(self.alpha, self.beta, x.clamp(0f64, 1f64))
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p.is_nan() {
+            return f64::NAN;
+        } else if p <= 0f64 {
+            return 0f64;
+        } else if p >= 1f64 {
+            return 1f64;
+        }
+        beta_quantile_pair(p, self.alpha, self.beta).0
+    }
+}
+
+/// Student's t-distribution with ν degrees of freedom.
+///
+/// $$ f(t) = \frac{1}{\sqrt{\nu} B(\nu/2, 1/2)} \left(1 + \frac{t^2}{\nu}\right)^{-(\nu+1)/2} $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    dof: f64,
+}
+
+impl StudentT {
+    /// Creates a t-distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dof` is not positive and finite.
+    pub fn new(dof: f64) -> Self {
+        Self::try_new(dof).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a t-distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `dof` is not positive and finite.
+    pub fn try_new(dof: f64) -> Result<Self, SpecialFunctionError> {
+        if !(dof > 0f64 && dof.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in StudentT"));
+        }
+        Ok(Self { dof })
+    }
+
+    /// Calculates $P(T \le t)$ for `t` ≤ 0.
+    fn lower_tail(&self, t: f64) -> f64 {
+        let nu = self.dof;
+        let t2 = t * t;
+        // P(T ≤ t) = I_x(ν/2, 1/2) / 2 with x = ν / (ν + t²), 1 - x is used when x is close to 1
+        if t2 < nu {
+            0.5 * betaic(0.5, 0.5 * nu, t2 / (nu + t2))
+        } else {
+            0.5 * betai(0.5 * nu, 0.5, nu / (nu + t2))
+        }
+    }
+}
+
+impl ContinuousDistribution for StudentT {
+    fn ln_pdf(&self, t: f64) -> f64 {
+        let nu = self.dof;
+        -0.5 * nu.ln() - ln_beta(0.5 * nu, 0.5) - 0.5 * (nu + 1f64) * (t * t / nu).ln_1p()
+    }
+
+    fn cdf(&self, t: f64) -> f64 {
+        if t.is_nan() {
+            f64::NAN
+        } else if t <= 0f64 {
+            self.lower_tail(t)
+        } else {
+            1f64 - self.lower_tail(-t)
+        }
+    }
+
+    fn sf(&self, t: f64) -> f64 {
+        if t.is_nan() {
+            f64::NAN
+        } else if t >= 0f64 {
+            self.lower_tail(-t)
+        } else {
+            1f64 - self.lower_tail(t)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p.is_nan() {
+            return f64::NAN;
+        } else if p <= 0f64 {
+            return f64::NEG_INFINITY;
+        } else if p >= 1f64 {
+            return f64::INFINITY;
+        } else if p == 0.5 {
+            return 0f64;
+        }
+        let nu = self.dof;
+        // The quantile is antisymmetric, and 1 - p is exact for p ≥ 1/2
+        let (tail, sign) = if p < 0.5 {
+            (p, -1f64)
+        } else {
+            (1f64 - p, 1f64)
+        };
+        let (x, y) = beta_quantile_pair(2f64 * tail, 0.5 * nu, 0.5);
+        sign * (nu * y / x).sqrt()
+    }
+}
+
+/// The F-distribution with d₁ and d₂ degrees of freedom.
+///
+/// $$ f(x) = \frac{1}{B(d_1/2, d_2/2)} \left(\frac{d_1}{d_2}\right)^{d_1/2} x^{d_1/2-1} \left(1 + \frac{d_1 x}{d_2}\right)^{-(d_1+d_2)/2}, \quad x \ge 0 $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FisherF {
+    dof1: f64,
+    dof2: f64,
+}
+
+impl FisherF {
+    /// Creates an F-distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dof1` or `dof2` is not positive and finite.
+    pub fn new(dof1: f64, dof2: f64) -> Self {
+        Self::try_new(dof1, dof2).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates an F-distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `dof1` or `dof2` is not positive and finite.
+    pub fn try_new(dof1: f64, dof2: f64) -> Result<Self, SpecialFunctionError> {
+        if !(dof1 > 0f64 && dof1.is_finite() && dof2 > 0f64 && dof2.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in FisherF"));
+        }
+        Ok(Self { dof1, dof2 })
+    }
+
+    /// Returns x = d₁f / (d₁f + d₂) and 1 - x, both computed without cancellation.
+    fn beta_arguments(&self, f: f64) -> (f64, f64) {
+        let d1f = self.dof1 * f;
+        let denominator = d1f + self.dof2;
+        (d1f / denominator, self.dof2 / denominator)
+    }
+}
+
+impl ContinuousDistribution for FisherF {
+    fn ln_pdf(&self, f: f64) -> f64 {
+        let a = 0.5 * self.dof1;
+        let b = 0.5 * self.dof2;
+        if f < 0f64 {
+            return f64::NEG_INFINITY;
+        } else if f == 0f64 {
+            // The density at the origin is 0 for d₁ > 2, 1 for d₁ = 2 and +∞ for d₁ < 2
+            return if a > 1f64 {
+                f64::NEG_INFINITY
+            } else if a == 1f64 {
+                0f64
+            } else {
+                f64::INFINITY
+            };
+        }
+        let ratio = self.dof1 / self.dof2;
+        a * ratio.ln() + (a - 1f64) * f.ln() - (a + b) * (ratio * f).ln_1p() - ln_beta(a, b)
+    }
+
+    fn cdf(&self, f: f64) -> f64 {
+        if f.is_nan() {
+            return f64::NAN;
+        } else if f <= 0f64 {
+            return 0f64;
+        }
+        let (a, b) = (0.5 * self.dof1, 0.5 * self.dof2);
+        let (x, y) = self.beta_arguments(f);
+        if x < 0.5 {
+            betai(a, b, x)
+        } else {
+            betaic(b, a, y)
+        }
+    }
+
+    fn sf(&self, f: f64) -> f64 {
+        if f.is_nan() {
+            return f64::NAN;
+        } else if f <= 0f64 {
+            return 1f64;
+        }
+        let (a, b) = (0.5 * self.dof1, 0.5 * self.dof2);
+        let (x, y) = self.beta_arguments(f);
+        if x < 0.5 {
+            betaic(a, b, x)
+        } else {
+            betai(b, a, y)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p.is_nan() {
+            return f64::NAN;
+        } else if p <= 0f64 {
+            return 0f64;
+        } else if p >= 1f64 {
+            return f64::INFINITY;
+        }
+        let (x, y) = beta_quantile_pair(p, 0.5 * self.dof1, 0.5 * self.dof2);
+        self.dof2 * x / (self.dof1 * y)
+    }
+}
+
+// =============================================================================
+// Discrete distributions
+// =============================================================================
+/// Finds the smallest `k` with `cdf(k)` ≥ `p` by stepping from the initial guess `k`.
+fn discrete_quantile<F: Fn(u64) -> f64>(cdf: F, p: f64, mut k: u64, max: u64) -> u64 {
+    k = k.min(max);
+    while k < max && cdf(k) < p {
+        k += 1;
+    }
+    while k > 0 && cdf(k - 1) >= p {
+        k -= 1;
+    }
+    k
+}
+
+/// Cornish-Fisher approximation of the quantile of a discrete distribution from its
+/// mean, standard deviation and skewness, used as the starting point of the search.
+fn cornish_fisher_guess(p: f64, mean: f64, std_dev: f64, skewness: f64) -> u64 {
+    let z = Normal::standard().quantile(p);
+    let guess = mean + std_dev * (z + skewness * (z * z - 1f64) / 6f64);
+    if guess > 0f64 {
+        guess.floor() as u64
+    } else {
+        0
+    }
+}
+
+/// The Poisson distribution with mean λ.
+///
+/// $$ P(X = k) = \frac{\lambda^k e^{-\lambda}}{k!} $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Creates a Poisson distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is not positive and finite.
+    pub fn new(lambda: f64) -> Self {
+        Self::try_new(lambda).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a Poisson distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `lambda` is not positive and finite.
+    pub fn try_new(lambda: f64) -> Result<Self, SpecialFunctionError> {
+        if !(lambda > 0f64 && lambda.is_finite()) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in Poisson"));
+        }
+        Ok(Self { lambda })
+    }
+}
+
+impl DiscreteDistribution for Poisson {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        let k = k as f64;
+        k * self.lambda.ln() - self.lambda - ln_gamma(k + 1f64)
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        regularized_upper_gamma(k as f64 + 1f64, self.lambda)
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        regularized_lower_gamma(k as f64 + 1f64, self.lambda)
+    }
+
+    fn quantile(&self, p: f64) -> f64 {
+        if p.is_nan() {
+            return f64::NAN;
+        } else if p <= 0f64 {
+            return 0f64;
+        } else if p >= 1f64 {
+            return f64::INFINITY;
+        }
+        let sqrt_lambda = self.lambda.sqrt();
+        let guess = cornish_fisher_guess(p, self.lambda, sqrt_lambda, 1f64 / sqrt_lambda);
+        discrete_quantile(|k| self.cdf(k), p, guess, u64::MAX) as f64
+    }
+}
+
+/// The binomial distribution of the number of successes in n trials with success probability p.
+///
+/// $$ P(X = k) = \binom{n}{k} p^k (1-p)^{n-k}, \quad 0 \le k \le n $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Creates a binomial distribution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in the range `0..=1`.
+    pub fn new(n: u64, p: f64) -> Self {
+        Self::try_new(n, p).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a binomial distribution, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecialFunctionError::Domain`] if `p` is not in the range `0..=1`.
+    pub fn try_new(n: u64, p: f64) -> Result<Self, SpecialFunctionError> {
+        if !(0f64..=1f64).contains(&p) {
+            return Err(SpecialFunctionError::Domain("Bad parameters in Binomial"));
+        }
+        Ok(Self { n, p })
+    }
+}
+
+impl DiscreteDistribution for Binomial {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        let (n, p) = (self.n, self.p);
+        if k > n {
+            return f64::NEG_INFINITY;
+        } else if p == 0f64 || p == 1f64 {
+            // All the mass is at 0 or at n
+            let mode = if p == 0f64 { 0 } else { n };
+            return if k == mode { 0f64 } else { f64::NEG_INFINITY };
+        }
+        let (k, n) = (k as f64, n as f64);
+        // ln C(n,k) = -ln((n + 1) B(k + 1, n - k + 1))
+        -(n + 1f64).ln() - ln_beta(k + 1f64, n - k + 1f64) + k * p.ln() + (n - k) * (-p).ln_1p()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        if k >= self.n {
+            return 1f64;
+        }
+        // P(X ≤ k) = 1 - I_p(k + 1, n - k)
+        betaic(k as f64 + 1f64, (self.n - k) as f64, self.p)
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        if k >= self.n {
+            return 0f64;
+        }
+        betai(k as f64 + 1f64, (self.n - k) as f64, self.p)
+    }
+
+    fn quantile(&self, q: f64) -> f64 {
+        if q.is_nan() {
+            return f64::NAN;
+        } else if q <= 0f64 {
+            return 0f64;
+        } else if q >= 1f64 || self.p == 1f64 {
+            return self.n as f64;
+        } else if self.p == 0f64 {
+            return 0f64;
+        }
+        let n = self.n as f64;
+        let variance = n * self.p * (1f64 - self.p);
+        let std_dev = variance.sqrt();
+        let skewness = (1f64 - 2f64 * self.p) / std_dev;
+        let guess = cornish_fisher_guess(q, n * self.p, std_dev, skewness);
+        discrete_quantile(|k| self.cdf(k), q, guess, self.n) as f64
+    }
+}
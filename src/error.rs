@@ -4,12 +4,16 @@
 //! - `erf`: Calculates the error function.
 //! - `erfc`: Calculates the complementary error function.
 //! - `erfcx`: Calculates the scaled complementary error function.
+//! - `erfi`: Calculates the imaginary error function.
 //! - `inverf`: Calculates the inverse of the error function.
 //! - `inverfc`: Calculates the inverse of the complementary error function.
 
 use core::f64::consts::FRAC_2_SQRT_PI;
 
-use crate::{utils::frexp, INV_SQRT_PI};
+use crate::{dawson, utils::frexp, EPS, INV_SQRT_PI};
+
+/// Maximum number of terms of the power series
+const MAXIT: usize = 100;
 
 /// Calculates the error function.
 ///
@@ -25,7 +29,10 @@ use crate::{utils::frexp, INV_SQRT_PI};
 ///
 /// The value of the error function at `x`
 pub fn erf(x: f64) -> f64 {
-    if x >= 0f64 {
+    if x.abs() < 0.5 {
+        // 1 - erfc(x) cancels for small |x|, so the power series is summed instead.
+        erf_series(x)
+    } else if x >= 0f64 {
         1.0 - erfccheb(x)
     } else {
         erfccheb(-x) - 1f64
@@ -53,6 +60,32 @@ pub fn erfc(x: f64) -> f64 {
     }
 }
 
+/// Calculates the imaginary error function.
+///
+/// The imaginary error function is defined as:
+///
+/// $$ \text{erfi}(x) = -i \, \text{erf}(ix) = \frac{2}{\sqrt{\pi}} \int_0^x e^{t^2} dt $$
+///
+/// # Arguments
+///
+/// * `x` - The input value
+///
+/// # Returns
+///
+/// The value of the imaginary error function at `x`, which overflows to ±∞ for |`x`| above about 26.6
+///
+/// # Notes on implementation
+///
+/// Computed as $\text{erfi}(x) = \frac{2}{\sqrt{\pi}} e^{x^2} F(x)$ with Dawson's integral $F(x)$,
+/// where $e^{x^2}$ is applied in two halves so that no intermediate overflows before the result.
+pub fn erfi(x: f64) -> f64 {
+    if x.is_infinite() {
+        return x;
+    }
+    let half = (0.5 * x * x).exp();
+    FRAC_2_SQRT_PI * dawson(x) * half * half
+}
+
 /// Calculates the inverse of the complementary error function.
 ///
 /// This function finds x such that:
@@ -106,6 +139,22 @@ pub fn inverf(p: f64) -> f64 {
     inverfc(1f64 - p)
 }
 
+/// erf(x) = 2/√π Σ_n (-1)^n x^(2n+1) / (n! (2n+1))
+fn erf_series(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..MAXIT {
+        term *= -x2 / n as f64;
+        let contribution = term / (2 * n + 1) as f64;
+        sum += contribution;
+        if contribution.abs() <= EPS * sum.abs() {
+            break;
+        }
+    }
+    FRAC_2_SQRT_PI * sum
+}
+
 /// Helper function for erfcx(x).
 fn cheb_interpolant(x: f64) -> f64 {
     // Application-specific constants:
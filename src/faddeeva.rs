@@ -1,8 +1,25 @@
-//! This module provides an implementation of the complex Faddeeva function.
+//! This module provides an implementation of the complex Faddeeva function and the related
+//! error functions of complex argument.
+//!
+//! It includes the following main functions:
+//! - `faddeeva`: Calculates the Faddeeva function w(z).
+//! - `complex_erf`: Calculates the error function for complex values.
+//! - `complex_erfc`: Calculates the complementary error function for complex values.
+//! - `complex_erfcx`: Calculates the scaled complementary error function for complex values.
+//! - `complex_erfi`: Calculates the imaginary error function for complex values.
+//! - `complex_dawson`: Calculates Dawson's integral for complex values.
 
 use core::f64::consts::FRAC_2_SQRT_PI;
 
-use crate::{dawson, error::erfcx, INV_SQRT_PI};
+use crate::{
+    dawson,
+    error::{erf, erfc, erfcx, erfi},
+    EPS, INV_SQRT_PI,
+};
+use num_complex::Complex64;
+
+/// Maximum number of terms of the power series
+const MAXIT: usize = 100;
 
 /// The complex Faddeeva function, or w(z). This is Abramowitz
 /// & Stegun function (7.1.3), also known as the plasma dispersion
@@ -58,7 +75,13 @@ pub fn faddeeva(re: f64, im: f64) -> (f64, f64) {
         if yabs == 0. {
             return (e2, wi);
         }
-        return (e2 + y * 2. * (x * wi - INV_SQRT_PI), wi - 2. * x * y * e2);
+        // x Im w(x) - 1/√π cancels for large |x|, so its asymptotic expansion is summed instead.
+        let d = if xabs > AXIS_EXPANSION_MIN {
+            axis_expansion(x, 1.)
+        } else {
+            x * wi - INV_SQRT_PI
+        };
+        return (e2 + y * 2. * d, wi - 2. * x * y * e2);
     }
 
     /* **************************************************************** */
@@ -72,7 +95,13 @@ pub fn faddeeva(re: f64, im: f64) -> (f64, f64) {
         if xabs == 0. {
             return (wr, 0.);
         }
-        return (wr, x * (2. * (INV_SQRT_PI - y * wr)));
+        // 1/√π - y erfcx(y) cancels for large y, so its asymptotic expansion is summed instead.
+        let d = if y > AXIS_EXPANSION_MIN {
+            axis_expansion(y, -1.)
+        } else {
+            INV_SQRT_PI - y * wr
+        };
+        return (wr, x * (2. * d));
     }
 
     /* **************************************************************** */
@@ -272,6 +301,302 @@ pub fn faddeeva(re: f64, im: f64) -> (f64, f64) {
     (res.re, res.im)
 }
 
+/// Above this |t| the asymptotic expansion in [`axis_expansion`] converges to full precision.
+const AXIS_EXPANSION_MIN: f64 = 10.;
+
+/// (1/√π) Σ_{k≥1} s^(k-1) (2k-1)!! / (2t²)^k
+///
+/// This is the asymptotic expansion of t Im w(t) - 1/√π for real t and s = 1,
+/// and of 1/√π - t erfcx(t) for s = -1, both of which cancel for large |t|.
+fn axis_expansion(t: f64, s: f64) -> f64 {
+    let r = 0.5 / (t * t);
+    let mut term = r;
+    let mut sum = r;
+    for k in 1..MAXIT {
+        term *= s * (2 * k + 1) as f64 * r;
+        sum += term;
+        if term.abs() <= EPS * sum.abs() {
+            break;
+        }
+    }
+    INV_SQRT_PI * sum
+}
+
+/// Calculates the error function for complex argument.
+///
+/// $$ \mathrm{erf}(z) = \frac{2}{\sqrt{\pi}} \int_0^z e^{-t^2} dt = 1 - e^{-z^2} w(iz) $$
+///
+/// # Arguments
+///
+/// * `z` - The input value (complex number)
+///
+/// # Returns
+///
+/// The value of the error function at `z`
+///
+/// # Notes on implementation
+///
+/// As in the Faddeeva package, the real and imaginary axes are handled by [`erf`] and [`erfi`],
+/// the power series is used for |`z`| < 1/2 and a Taylor series in Re `z` close to the imaginary
+/// axis, and otherwise the formula above is evaluated through [`faddeeva`] with the sign of Re `z`
+/// chosen to avoid cancellation.
+pub fn complex_erf(z: Complex64) -> Complex64 {
+    let (x, y) = (z.re, z.im);
+    if y == 0. {
+        // Preserve the sign of zero.
+        return Complex64::new(erf(x), y);
+    } else if x == 0. {
+        return Complex64::new(x, erfi(y));
+    }
+
+    // Re(-z²), avoiding overflow, and Im(-z²)
+    let m_re_z2 = (y - x) * (x + y);
+    let m_im_z2 = -2. * x * y;
+    if m_re_z2 < -750. {
+        // e^{-z²} underflows.
+        return Complex64::new(if x >= 0. { 1. } else { -1. }, 0.);
+    }
+
+    if z.norm_sqr() < 0.25 {
+        return erf_series(z);
+    } else if x.abs() < 8e-2 {
+        if m_im_z2.abs() < 5e-3 && x.abs() < 5e-3 {
+            return erf_taylor_imaginary_axis(x, y);
+        }
+    } else if x.is_nan() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    // The real exponential and the phase are multiplied separately, so that an overflow of
+    // e^{-z²} against a small w gives ±∞ rather than NaN.
+    let phase = Complex64::new(m_im_z2.cos(), m_im_z2.sin());
+    if x >= 0. {
+        1. - m_re_z2.exp() * (phase * w(Complex64::new(-y, x)))
+    } else {
+        m_re_z2.exp() * (phase * w(Complex64::new(y, -x))) - 1.
+    }
+}
+
+/// Calculates the complementary error function for complex argument.
+///
+/// $$ \mathrm{erfc}(z) = 1 - \mathrm{erf}(z) = e^{-z^2} w(iz) $$
+///
+/// # Arguments
+///
+/// * `z` - The input value (complex number)
+///
+/// # Returns
+///
+/// The value of the complementary error function at `z`
+///
+/// # Notes on implementation
+///
+/// The real axis is handled by [`erfc`] and the imaginary axis by [`erfi`], otherwise the formula
+/// above is evaluated through [`faddeeva`], reflected by $\mathrm{erfc}(z) = 2 - \mathrm{erfc}(-z)$
+/// for Re `z` < 0.
+pub fn complex_erfc(z: Complex64) -> Complex64 {
+    let (x, y) = (z.re, z.im);
+    if x == 0. {
+        return Complex64::new(1., -erfi(y));
+    } else if y == 0. {
+        // Preserve the sign of zero.
+        return Complex64::new(erfc(x), -y);
+    }
+
+    let m_re_z2 = (y - x) * (x + y);
+    let m_im_z2 = -2. * x * y;
+    if m_re_z2 < -750. {
+        return Complex64::new(if x >= 0. { 0. } else { 2. }, 0.);
+    }
+    // As in complex_erf, the real exponential and the phase are multiplied separately. The complex
+    // exponential gives ∞ times a zero component when e^{-z²} overflows, and hence NaN.
+    let phase = Complex64::new(m_im_z2.cos(), m_im_z2.sin());
+    if x >= 0. {
+        m_re_z2.exp() * (phase * w(Complex64::new(-y, x)))
+    } else {
+        2. - m_re_z2.exp() * (phase * w(Complex64::new(y, -x)))
+    }
+}
+
+/// Calculates the scaled complementary error function for complex argument.
+///
+/// $$ \mathrm{erfcx}(z) = e^{z^2} \mathrm{erfc}(z) = w(iz) $$
+///
+/// # Arguments
+///
+/// * `z` - The input value (complex number)
+///
+/// # Returns
+///
+/// The value of the scaled complementary error function at `z`
+pub fn complex_erfcx(z: Complex64) -> Complex64 {
+    w(Complex64::new(-z.im, z.re))
+}
+
+/// Calculates the imaginary error function for complex argument.
+///
+/// $$ \mathrm{erfi}(z) = -i \, \mathrm{erf}(iz) = \frac{2}{\sqrt{\pi}} \int_0^z e^{t^2} dt $$
+///
+/// # Arguments
+///
+/// * `z` - The input value (complex number)
+///
+/// # Returns
+///
+/// The value of the imaginary error function at `z`
+pub fn complex_erfi(z: Complex64) -> Complex64 {
+    let e = complex_erf(Complex64::new(-z.im, z.re));
+    Complex64::new(e.im, -e.re)
+}
+
+/// Calculates Dawson's integral for complex argument.
+///
+/// $$ F(z) = e^{-z^2} \int_0^z e^{t^2} dt = \frac{\sqrt{\pi}}{2} e^{-z^2} \mathrm{erfi}(z) $$
+///
+/// # Arguments
+///
+/// * `z` - The input value (complex number)
+///
+/// # Returns
+///
+/// The value of Dawson's integral at `z`
+///
+/// # Notes on implementation
+///
+/// As in the Faddeeva package, the real axis is handled by [`dawson()`] and the imaginary axis by
+/// [`erfcx`], the power series is used for |`z`| < 1/2 and a Taylor series in Im `z` close to the
+/// real axis, and otherwise
+/// $F(z) = \frac{i\sqrt{\pi}}{2} \left(e^{-z^2} - w(z)\right)$ is evaluated through [`faddeeva`],
+/// reflected by $F(z) = -F(-z)$ for Im `z` < 0.
+pub fn complex_dawson(z: Complex64) -> Complex64 {
+    // √π / 2
+    let spi2 = 0.5 / INV_SQRT_PI;
+    let (x, y) = (z.re, z.im);
+
+    if y == 0. {
+        // Preserve the sign of zero.
+        return Complex64::new(dawson(x), -y);
+    } else if z.norm_sqr() < 0.25 {
+        return dawson_series(z);
+    } else if x == 0. {
+        // F(iy) = i √π/2 (e^{y²} - erfcx(y))
+        let y2 = y * y;
+        let im = if y >= 0. {
+            y2.exp() - erfcx(y)
+        } else {
+            erfcx(-y) - y2.exp()
+        };
+        return Complex64::new(x, spi2 * im);
+    }
+
+    let m_re_z2 = (y - x) * (x + y);
+    let m_im_z2 = -2. * x * y;
+    let m_z2 = Complex64::new(m_re_z2, m_im_z2);
+
+    if y.abs() < 5e-3 {
+        if m_im_z2.abs() < 5e-3 {
+            return dawson_taylor_real_axis(x, y);
+        }
+    } else if y.is_nan() {
+        return Complex64::new(if x == 0. { 0. } else { f64::NAN }, f64::NAN);
+    }
+    let res = if y >= 0. {
+        m_z2.exp() - w(z)
+    } else {
+        w(-z) - m_z2.exp()
+    };
+    spi2 * Complex64::new(-res.im, res.re)
+}
+
+/// w(z) for complex z, through [`faddeeva`]
+fn w(z: Complex64) -> Complex64 {
+    let (re, im) = faddeeva(z.re, z.im);
+    Complex64::new(re, im)
+}
+
+/// erf(z) = 2/√π Σ_n (-1)^n z^(2n+1) / (n! (2n+1)) for small |z|
+fn erf_series(z: Complex64) -> Complex64 {
+    let m_z2 = -z * z;
+    let mut term = z;
+    let mut sum = z;
+    for n in 1..MAXIT {
+        term *= m_z2 / n as f64;
+        let contribution = term / (2 * n + 1) as f64;
+        sum += contribution;
+        if contribution.norm() <= EPS * sum.norm() {
+            break;
+        }
+    }
+    FRAC_2_SQRT_PI * sum
+}
+
+/// erf(x+iy) = erf(iy) + 2e^{y²}/√π [x (1 - x²(1+2y²)/3 + x⁴(3+12y²+4y⁴)/30 - ...) -
+/// i x² y (1 - x²(3+2y²)/6 + ...)] for small |x| and |xy|, with erf(iy) = i e^{y²} Im w(y)
+fn erf_taylor_imaginary_axis(x: f64, y: f64) -> Complex64 {
+    let x2 = x * x;
+    let y2 = y * y;
+    let expy2 = y2.exp();
+    Complex64::new(
+        expy2
+            * x
+            * (FRAC_2_SQRT_PI - x2 * (0.376_126_389_031_837_5 + 0.752_252_778_063_675 * y2)
+                + x2 * x2
+                    * (0.112_837_916_709_551_26
+                        + y2 * (0.451_351_666_838_205 + 0.150_450_555_612_735 * y2))),
+        expy2
+            * (im_w_of_x(y)
+                - x2 * y * (FRAC_2_SQRT_PI - x2 * (INV_SQRT_PI + 0.376_126_389_031_837_5 * y2))),
+    )
+}
+
+/// F(z) = Σ_n (-2z²)^n z / (2n+1)!! for small |z|
+fn dawson_series(z: Complex64) -> Complex64 {
+    let m_2z2 = -2. * z * z;
+    let mut term = z;
+    let mut sum = z;
+    for n in 1..MAXIT {
+        term *= m_2z2 / (2 * n + 1) as f64;
+        sum += term;
+        if term.norm() <= EPS * sum.norm() {
+            break;
+        }
+    }
+    sum
+}
+
+/// F(x+iy) = D + y²(D + x - 2Dx²) + y⁴(D/2 + 5x/6 - 2Dx² - x³/3 + 2Dx⁴/3) +
+/// iy [(1 - 2Dx) + 2/3 y² (1 - 3Dx - x² + 2Dx³) + y⁴/15 (4 - 15Dx - 9x² + 20Dx³ + 2x⁴ - 4Dx⁵)]
+/// with D = F(x) for small |y| and |xy|. For large |x|, where 2Dx → 1 cancels, D is replaced by
+/// its continued fraction 0.5/(x-0.5/(x-1/(x-1.5/(x-2/(x-2.5/x))))).
+fn dawson_taylor_real_axis(x: f64, y: f64) -> Complex64 {
+    let x2 = x * x;
+    let y2 = y * y;
+    if x2 > 1600. {
+        if x2 > 25e14 {
+            let xy2 = (x * y) * (x * y);
+            return Complex64::new(
+                (0.5 + y2 * (0.5 + 0.25 * y2 - xy2 / 6.)) / x,
+                y * (-1. + y2 * (-2. / 3. + 2. / 15. * xy2 - 4. / 15. * y2)) / (2. * x2 - 1.),
+            );
+        }
+        return (1. / (-15. + x2 * (90. + x2 * (-60. + 8. * x2))))
+            * Complex64::new(
+                x * (33. + x2 * (-28. + 4. * x2) + y2 * (18. - 4. * x2 + 4. * y2)),
+                y * (-15. + x2 * (24. - 4. * x2) + y2 * (4. * x2 - 10. - 4. * y2)),
+            );
+    }
+    let d = dawson(x);
+    Complex64::new(
+        d + y2 * (d + x - 2. * d * x2)
+            + y2 * y2 * (d * (0.5 - x2 * (2. - 2. / 3. * x2)) + x * (5. / 6. - x2 / 3.)),
+        y * (1. - 2. * d * x
+            + y2 * 2. / 3. * (1. - x2 - d * x * (3. - 2. * x2))
+            + y2 * y2
+                * (4. / 15.
+                    - x2 * (0.6 - 2. / 15. * x2)
+                    - d * x * (1. - x2 * (4. / 3. - 4. / 15. * x2)))),
+    )
+}
+
 /// Computes the scaled Dawson integral of x,
 /// which is 2 * Dawson(x) / sqrt(pi).
 /// This equals the imaginary part of the Faddeeva
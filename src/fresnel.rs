@@ -0,0 +1,203 @@
+//! This module provides implementations of the Fresnel integrals.
+//!
+//! It includes the following main functions:
+//! - `fresnel`: Calculates the Fresnel integrals S(x) and C(x) for real values.
+//! - `fresnel_aux`: Calculates the auxiliary functions f(x) and g(x) of the Fresnel integrals.
+//! - `complex_fresnel`: Calculates the Fresnel integrals for complex values.
+
+use crate::faddeeva::{complex_erf, faddeeva};
+use crate::gamma::{cos_pi, sin_pi};
+use crate::{EPS, INV_SQRT_PI};
+use core::f64::consts::{FRAC_PI_2, PI};
+use num_complex::Complex64;
+
+/// Maximum number of terms of the series
+const MAXIT: usize = 200;
+/// |z| up to which the power series is used
+const XSERIES: f64 = 1.0;
+/// x from which the asymptotic expansions of f and g are used
+const XASYM: f64 = 5.5;
+/// x from which S(x) and C(x) round to 1/2
+const XLARGE: f64 = 1e16;
+
+/// Calculates the Fresnel integrals $S(x)$ and $C(x)$
+///
+/// $$ S(x) = \int_0^x \sin\left(\frac{\pi t^2}{2}\right) dt, \quad C(x) = \int_0^x \cos\left(\frac{\pi t^2}{2}\right) dt $$
+///
+/// # Arguments
+///
+/// * `x` - The input value (real number)
+///
+/// # Returns
+///
+/// * `S(x)` - Fresnel sine integral
+/// * `C(x)` - Fresnel cosine integral
+///
+/// # Notes on implementation
+///
+/// The power series is used for |`x`| ≤ 1, otherwise the auxiliary functions of
+/// [`fresnel_aux`] with $C(x) = \frac{1}{2} + f(x) \sin(\pi x^2/2) - g(x) \cos(\pi x^2/2)$ and
+/// $S(x) = \frac{1}{2} - f(x) \cos(\pi x^2/2) - g(x) \sin(\pi x^2/2)$ (DLMF 7.5.3, 7.5.4).
+pub fn fresnel(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN);
+    } else if x < 0f64 {
+        let (s, c) = fresnel(-x);
+        return (-s, -c);
+    } else if x <= XSERIES {
+        return fresnel_series(x);
+    } else if x >= XLARGE {
+        return (0.5, 0.5);
+    }
+    let (f, g) = fresnel_aux(x);
+    let (sin, cos) = phase(x);
+    (0.5 - f * cos - g * sin, 0.5 + f * sin - g * cos)
+}
+
+/// Calculates the auxiliary functions $f(x)$ and $g(x)$ of the Fresnel integrals
+///
+/// $$ f(x) = \left(\tfrac{1}{2} - S(x)\right) \cos\left(\frac{\pi x^2}{2}\right) - \left(\tfrac{1}{2} - C(x)\right) \sin\left(\frac{\pi x^2}{2}\right) $$
+///
+/// $$ g(x) = \left(\tfrac{1}{2} - C(x)\right) \cos\left(\frac{\pi x^2}{2}\right) + \left(\tfrac{1}{2} - S(x)\right) \sin\left(\frac{\pi x^2}{2}\right) $$
+///
+/// which describe the slowly varying amplitude of the oscillations of $S(x)$ and $C(x)$ about 1/2,
+/// with $f(x) \sim 1/(\pi x)$ and $g(x) \sim 1/(\pi^2 x^3)$ for large `x`.
+///
+/// # Arguments
+///
+/// * `x` - The input value (real number)
+///
+/// # Returns
+///
+/// * `f(x)` - First auxiliary function
+/// * `g(x)` - Second auxiliary function
+///
+/// # Notes on implementation
+///
+/// For `x` ≥ 5.5 the asymptotic expansions (DLMF 7.12.2, 7.12.3) are summed, otherwise
+/// $g(x) + i f(x) = \frac{1 + i}{2} w\left(\frac{\sqrt{\pi}}{2} (1 + i) x\right)$ is computed by [`faddeeva`].
+/// Negative `x` are reflected by DLMF 7.4.10, 7.4.11.
+pub fn fresnel_aux(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN);
+    } else if x < 0f64 {
+        // f(-x) = √2 cos(π/4 + πx²/2) - f(x) and g(-x) = √2 sin(π/4 + πx²/2) - g(x)
+        let (f, g) = fresnel_aux(-x);
+        let (sin, cos) = phase(x);
+        return (cos - sin - f, cos + sin - g);
+    } else if x == f64::INFINITY {
+        return (0f64, 0f64);
+    } else if x >= XASYM {
+        return fresnel_aux_asymptotic(x);
+    }
+    let a = 0.5 / INV_SQRT_PI * x;
+    let (re, im) = faddeeva(a, a);
+    (0.5 * (re + im), 0.5 * (re - im))
+}
+
+/// Calculates the Fresnel integrals $S(z)$ and $C(z)$ for complex argument
+///
+/// $$ S(z) = \int_0^z \sin\left(\frac{\pi t^2}{2}\right) dt, \quad C(z) = \int_0^z \cos\left(\frac{\pi t^2}{2}\right) dt $$
+///
+/// # Arguments
+///
+/// * `z` - The input value (complex number)
+///
+/// # Returns
+///
+/// * `S(z)` - Fresnel sine integral
+/// * `C(z)` - Fresnel cosine integral
+///
+/// The integrals grow like $e^{\pi |\Re z \, \Im z|}$ away from the diagonals and overflow to ±∞ there.
+///
+/// # Notes on implementation
+///
+/// The power series is used for |`z`| ≤ 1, otherwise
+/// $C(z) \pm i S(z) = \frac{1 \pm i}{2} \mathrm{erf}\left(\frac{\sqrt{\pi}}{2} (1 \mp i) z\right)$ (DLMF 7.5.7),
+/// with [`complex_erf`].
+pub fn complex_fresnel(z: Complex64) -> (Complex64, Complex64) {
+    if z.re.is_nan() || z.im.is_nan() {
+        let nan = Complex64::new(f64::NAN, f64::NAN);
+        return (nan, nan);
+    } else if z.norm() <= XSERIES {
+        return complex_fresnel_series(z);
+    }
+    let a = 0.5 / INV_SQRT_PI;
+    let plus = Complex64::new(0.5, 0.5) * complex_erf(Complex64::new(a, -a) * z);
+    let minus = Complex64::new(0.5, -0.5) * complex_erf(Complex64::new(a, a) * z);
+    // S = (plus - minus) / 2i and C = (plus + minus) / 2
+    (
+        Complex64::new(0f64, -0.5) * (plus - minus),
+        0.5 * (plus + minus),
+    )
+}
+
+/// sin(πx²/2) and cos(πx²/2), with x² split exactly into a multiple of 4 and a remainder
+fn phase(x: f64) -> (f64, f64) {
+    let hi = x * x;
+    let lo = x.mul_add(x, -hi);
+    let t = 0.5 * (hi % 4f64 + lo);
+    (sin_pi(t), cos_pi(t))
+}
+
+/// S(x) = Σ_n (-1)^n (π/2)^(2n+1) x^(4n+3) / ((2n+1)! (4n+3)) and
+/// C(x) = Σ_n (-1)^n (π/2)^(2n) x^(4n+1) / ((2n)! (4n+1)), both through t_k = ±(π/2)^k x^(2k+1) / k!
+fn fresnel_series(x: f64) -> (f64, f64) {
+    let q = FRAC_PI_2 * x * x;
+    let mut term = x;
+    let (mut s, mut c) = (0f64, 0f64);
+    for k in 0..MAXIT {
+        if k % 2 == 0 {
+            c += term / (2 * k + 1) as f64;
+        } else {
+            s += term / (2 * k + 1) as f64;
+        }
+        // The sign changes whenever k becomes even.
+        let sign = if k % 2 == 0 { 1f64 } else { -1f64 };
+        term *= sign * q / (k + 1) as f64;
+        if k >= 1 && term.abs() <= EPS * s.abs().min(c.abs()) {
+            break;
+        }
+    }
+    (s, c)
+}
+
+/// The power series of [`fresnel_series`] for complex argument
+fn complex_fresnel_series(z: Complex64) -> (Complex64, Complex64) {
+    let q = FRAC_PI_2 * z * z;
+    let mut term = z;
+    let (mut s, mut c) = (Complex64::new(0f64, 0f64), Complex64::new(0f64, 0f64));
+    for k in 0..MAXIT {
+        if k % 2 == 0 {
+            c += term / (2 * k + 1) as f64;
+        } else {
+            s += term / (2 * k + 1) as f64;
+        }
+        let sign = if k % 2 == 0 { 1f64 } else { -1f64 };
+        term *= sign * q / (k + 1) as f64;
+        if k >= 1 && term.norm() <= EPS * s.norm().min(c.norm()) {
+            break;
+        }
+    }
+    (s, c)
+}
+
+/// f(x) ~ 1/(πx) Σ_m (-1)^m (4m-1)!! / (πx²)^(2m) and
+/// g(x) ~ 1/(π²x³) Σ_m (-1)^m (4m+1)!! / (πx²)^(2m) (DLMF 7.12.2, 7.12.3)
+fn fresnel_aux_asymptotic(x: f64) -> (f64, f64) {
+    let y2 = (PI * x * x).powi(-2);
+    let (mut f_term, mut g_term) = (1f64, 1f64);
+    let (mut f, mut g) = (1f64, 1f64);
+    for m in 1..MAXIT {
+        let m = m as f64;
+        f_term *= -(4f64 * m - 3f64) * (4f64 * m - 1f64) * y2;
+        g_term *= -(4f64 * m - 1f64) * (4f64 * m + 1f64) * y2;
+        f += f_term;
+        g += g_term;
+        if g_term.abs() <= EPS * g {
+            break;
+        }
+    }
+    let pix = PI * x;
+    (f / pix, g / (pix * pix * x))
+}
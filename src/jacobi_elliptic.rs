@@ -0,0 +1,102 @@
+//! This module provides implementations of the Jacobi elliptic functions.
+//!
+//! It includes the following main function:
+//! - `ellipj`: Calculates the Jacobi elliptic functions sn, cn, dn and the Jacobi amplitude.
+
+use crate::EPS;
+use core::f64::consts::PI;
+
+/// Maximum number of AGM steps, far more than its quadratic convergence needs
+const MAXIT: usize = 64;
+
+/// Calculates the Jacobi elliptic functions $\mathrm{sn}(u|m)$, $\mathrm{cn}(u|m)$, $\mathrm{dn}(u|m)$
+/// and the Jacobi amplitude $\mathrm{am}(u|m)$
+///
+/// The amplitude $\phi = \mathrm{am}(u|m)$ inverts the incomplete elliptic integral of the first kind,
+///
+/// $$ u = \int_0^{\phi} \frac{d\theta}{\sqrt{1 - m \sin^2 \theta}} $$
+///
+/// and the elliptic functions are
+///
+/// $$ \mathrm{sn}(u|m) = \sin\phi, \quad \mathrm{cn}(u|m) = \cos\phi, \quad \mathrm{dn}(u|m) = \sqrt{1 - m \sin^2\phi} $$
+///
+/// # Arguments
+///
+/// * `u` - The input value (real number)
+/// * `m` - The parameter (real number)
+///
+/// # Returns
+///
+/// * `sn(u|m)` - Jacobi elliptic function sn
+/// * `cn(u|m)` - Jacobi elliptic function cn
+/// * `dn(u|m)` - Jacobi elliptic function dn
+/// * `am(u|m)` - Jacobi amplitude, with $\sin(\mathrm{am}) = \mathrm{sn}$ and $\cos(\mathrm{am}) = \mathrm{cn}$
+///
+/// For `m` ≤ 1 the amplitude increases with `u`, by π over each period $2K(m)$.
+/// For `m` > 1 it lies in $[-\pi/2, \pi/2]$, since cn does not change sign.
+///
+/// # Notes on implementation
+///
+/// For 0 < `m` < 1 the descending Landen transformation, i.e. the arithmetic-geometric mean,
+/// is used (DLMF 22.20(ii)). Other parameters are mapped into that range by the reciprocal
+/// parameter transformation for `m` > 1 (DLMF 22.17.2) and the imaginary modulus
+/// transformation for `m` < 0 (DLMF 22.17.4).
+pub fn ellipj(u: f64, m: f64) -> (f64, f64, f64, f64) {
+    if u.is_nan() || m.is_nan() || u.is_infinite() {
+        return (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+    }
+    if m > 1f64 {
+        // sn(u|m) = sn(v|1/m) / √m, cn(u|m) = dn(v|1/m) and dn(u|m) = cn(v|1/m) with v = √m u
+        let k = m.sqrt();
+        let (sn, cn, dn, _) = ellipj(k * u, 1f64 / m);
+        let sn = sn / k;
+        return (sn, dn, cn, sn.atan2(dn));
+    } else if m < 0f64 {
+        // With μ = -m/(1-m) and v = √(1-m) u, sn(u|m) = sn(v|μ) / (√(1-m) dn(v|μ)),
+        // cn(u|m) = cn(v|μ) / dn(v|μ) and dn(u|m) = 1 / dn(v|μ)
+        let k = (1f64 - m).sqrt();
+        let (sn, cn, dn, am) = ellipj(k * u, -m / (1f64 - m));
+        // tan(am(u|m)) = tan(am(v|μ)) / √(1-m), continued over the branches of the tangent
+        let j = (am / PI).round();
+        let phi = j * PI + ((am - j * PI).tan() / k).atan();
+        return (sn / (k * dn), cn / dn, 1f64 / dn, phi);
+    } else if m == 0f64 {
+        let (sn, cn) = u.sin_cos();
+        return (sn, cn, 1f64, u);
+    } else if m == 1f64 {
+        let sech = 1f64 / u.cosh();
+        return (u.tanh(), sech, sech, u.sinh().atan());
+    }
+
+    // a_{n+1} = (a_n + b_n) / 2, b_{n+1} = √(a_n b_n) and c_{n+1} = (a_n - b_n) / 2 = c_n² / (4 a_{n+1})
+    let mut a = [0f64; MAXIT + 1];
+    let mut c = [0f64; MAXIT + 1];
+    a[0] = 1f64;
+    c[0] = m.sqrt();
+    let mut b = (1f64 - m).sqrt();
+    let mut n = 0;
+    while n < MAXIT && c[n] > EPS * a[n] {
+        a[n + 1] = 0.5 * (a[n] + b);
+        c[n + 1] = 0.25 * c[n] * c[n] / a[n + 1];
+        b = (a[n] * b).sqrt();
+        n += 1;
+    }
+
+    let steps = n;
+
+    // φ_N = 2^N a_N u and φ_{n-1} = (φ_n + arcsin(c_n / a_n sin φ_n)) / 2
+    let mut phi = 2f64.powi(n as i32) * a[n] * u;
+    let mut phi_prev = phi;
+    while n > 0 {
+        phi_prev = phi;
+        phi = 0.5 * (phi + (c[n] / a[n] * phi.sin()).asin());
+        n -= 1;
+    }
+    let (sn, cn) = phi.sin_cos();
+    if steps == 0 {
+        // √m ≤ EPS, so there is no φ_1 and no cancellation in √(1 - m sn²).
+        return (sn, cn, (1f64 - m * sn * sn).sqrt(), phi);
+    }
+    // dn = cos φ_0 / cos(φ_1 - φ_0) avoids the cancellation in √(1 - m sn²) for m → 1.
+    (sn, cn, cn / (phi_prev - phi).cos(), phi)
+}
@@ -0,0 +1,303 @@
+//! This module provides implementations of the Kelvin functions.
+//!
+//! It includes the following main functions:
+//! - `berbei`: Calculates the Kelvin functions ber and bei and their derivatives.
+//! - `kerkei`: Calculates the Kelvin functions ker and kei and their derivatives.
+
+use crate::bessel::{combine, complex_besselj, complex_besselk};
+use crate::gamma::{cos_pi, gamma, sin_pi};
+use crate::EPS;
+use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, FRAC_PI_8, PI};
+use num_complex::Complex64;
+
+/// x from which the asymptotic expansions are tried
+const XASYM: f64 = 30.0;
+/// Largest ratio of the largest term to the sums for which the asymptotic expansions are used
+const CANCELLATION: f64 = 8.0;
+
+/// Calculates the Kelvin functions $\mathrm{ber}_\nu(x)$, $\mathrm{bei}_\nu(x)$ and their derivatives
+///
+/// The Kelvin functions of the first kind are the real and imaginary parts of
+///
+/// $$ \mathrm{ber}_\nu(x) + i \, \mathrm{bei}_\nu(x) = J_\nu(x e^{3\pi i/4}) $$
+///
+/// They grow like $e^{x/\sqrt{2}} / \sqrt{2\pi x}$ while they oscillate, and overflow to ±∞ for `x` > 1000.
+///
+/// # Arguments
+///
+/// * `nu` - The order (real number)
+/// * `x` - The input value (non-negative real number)
+///
+/// # Returns
+///
+/// * `ber(x)` - $\mathrm{ber}_\nu(x)$
+/// * `bei(x)` - $\mathrm{bei}_\nu(x)$
+/// * `ber'(x)` - Derivative of $\mathrm{ber}_\nu(x)$
+/// * `bei'(x)` - Derivative of $\mathrm{bei}_\nu(x)$
+///
+/// # Panics
+///
+/// Panics if `x` < 0.
+///
+/// # Notes on implementation
+///
+/// For large `x` the functions follow from Hankel's expansion of $K_\nu$ (DLMF 10.67.3),
+/// otherwise from [`complex_besselj`]. Negative orders are reduced by DLMF 10.61.6.
+pub fn berbei(nu: f64, x: f64) -> (f64, f64, f64, f64) {
+    assert!(x.is_nan() || x >= 0f64, "Bad args in berbei");
+    if nu.is_nan() || x.is_nan() {
+        return (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+    } else if nu >= 0f64 {
+        let (be, bep) = be_positive(nu, x);
+        return (be.re, be.im, bep.re, bep.im);
+    }
+    // ber_{-ν} = cos(νπ) ber_ν + sin(νπ) (bei_ν + 2/π ker_ν),
+    // bei_{-ν} = cos(νπ) bei_ν + sin(νπ) (2/π kei_ν - ber_ν)
+    let mu = -nu;
+    let (s, c) = (sin_pi(mu), cos_pi(mu));
+    if x == 0f64 && s != 0f64 {
+        let (be, bep) = be_at_zero(nu);
+        return (be.re, be.im, bep.re, bep.im);
+    }
+    let (be, bep) = be_positive(mu, x);
+    // The second kind is not needed for integer orders, where it may be infinite.
+    let (ke, kep) = if s == 0f64 {
+        (Complex64::new(0f64, 0f64), Complex64::new(0f64, 0f64))
+    } else {
+        ke_positive(mu, x)
+    };
+    (
+        combine(c, be.re, s, be.im + FRAC_2_PI * ke.re),
+        combine(c, be.im, s, FRAC_2_PI * ke.im - be.re),
+        combine(c, bep.re, s, bep.im + FRAC_2_PI * kep.re),
+        combine(c, bep.im, s, FRAC_2_PI * kep.im - bep.re),
+    )
+}
+
+/// Calculates the Kelvin functions $\mathrm{ker}_\nu(x)$, $\mathrm{kei}_\nu(x)$ and their derivatives
+///
+/// The Kelvin functions of the second kind are the real and imaginary parts of
+///
+/// $$ \mathrm{ker}_\nu(x) + i \, \mathrm{kei}_\nu(x) = e^{-\nu\pi i/2} K_\nu(x e^{\pi i/4}) $$
+///
+/// They decay like $e^{-x/\sqrt{2}} \sqrt{\pi / 2x}$ while they oscillate.
+///
+/// # Arguments
+///
+/// * `nu` - The order (real number)
+/// * `x` - The input value (non-negative real number)
+///
+/// # Returns
+///
+/// * `ker(x)` - $\mathrm{ker}_\nu(x)$
+/// * `kei(x)` - $\mathrm{kei}_\nu(x)$
+/// * `ker'(x)` - Derivative of $\mathrm{ker}_\nu(x)$
+/// * `kei'(x)` - Derivative of $\mathrm{kei}_\nu(x)$
+///
+/// At `x` = 0 the functions are ±∞ unless their limit is finite, as $\mathrm{kei}_0(0) = -\pi/4$.
+///
+/// # Panics
+///
+/// Panics if `x` < 0.
+///
+/// # Notes on implementation
+///
+/// For large `x` the functions follow from Hankel's expansion of $K_\nu$ (DLMF 10.67.1),
+/// otherwise from [`complex_besselk`]. Negative orders are reduced by DLMF 10.61.6.
+pub fn kerkei(nu: f64, x: f64) -> (f64, f64, f64, f64) {
+    assert!(x.is_nan() || x >= 0f64, "Bad args in kerkei");
+    if nu.is_nan() || x.is_nan() {
+        return (f64::NAN, f64::NAN, f64::NAN, f64::NAN);
+    }
+    let (ke, kep) = ke_positive(nu.abs(), x);
+    if nu >= 0f64 {
+        return (ke.re, ke.im, kep.re, kep.im);
+    }
+    // ker_{-ν} = cos(νπ) ker_ν - sin(νπ) kei_ν, kei_{-ν} = sin(νπ) ker_ν + cos(νπ) kei_ν
+    let (s, c) = (sin_pi(-nu), cos_pi(-nu));
+    if x == 0f64 {
+        let (ke, kep) = ke_at_zero(-nu, Complex64::new(c, s));
+        return (ke.re, ke.im, kep.re, kep.im);
+    }
+    (
+        combine(c, ke.re, -s, ke.im),
+        combine(s, ke.re, c, ke.im),
+        combine(c, kep.re, -s, kep.im),
+        combine(s, kep.re, c, kep.im),
+    )
+}
+
+/// ber_ν(x) + i bei_ν(x) and its derivative for ν ≥ 0
+fn be_positive(nu: f64, x: f64) -> (Complex64, Complex64) {
+    if x == 0f64 {
+        return be_at_zero(nu);
+    }
+    if x >= XASYM {
+        if let Some((be, _, bep, _)) = kelvin_asymptotic(nu, x) {
+            return (be, bep);
+        }
+    }
+    // d/dx J_ν(xc) = ν/x J_ν(xc) - c J_{ν+1}(xc) with c = e^(3πi/4)
+    let c = Complex64::from_polar(1f64, 3f64 * FRAC_PI_4);
+    let j = complex_besselj(nu, x * c);
+    let j1 = complex_besselj(nu + 1f64, x * c);
+    (j, nu / x * j - c * j1)
+}
+
+/// ker_ν(x) + i kei_ν(x) and its derivative for ν ≥ 0
+fn ke_positive(nu: f64, x: f64) -> (Complex64, Complex64) {
+    if x == 0f64 {
+        return ke_at_zero(nu, Complex64::new(1f64, 0f64));
+    }
+    if x >= XASYM {
+        if let Some((_, ke, _, kep)) = kelvin_asymptotic(nu, x) {
+            return (ke, kep);
+        }
+    }
+    // d/dx K_ν(xd) = ν/x K_ν(xd) - d K_{ν+1}(xd) with d = e^(πi/4)
+    let d = Complex64::from_polar(1f64, FRAC_PI_4);
+    let rotation = Complex64::from_polar(1f64, -FRAC_PI_2 * nu);
+    let k = complex_besselk(nu, x * d);
+    let k1 = complex_besselk(nu + 1f64, x * d);
+    (rotation * k, rotation * (nu / x * k - d * k1))
+}
+
+/// Limits of ber_ν(x) + i bei_ν(x) and its derivative for x → 0+, where ν is not a negative integer
+fn be_at_zero(nu: f64) -> (Complex64, Complex64) {
+    // ber_ν(x) + i bei_ν(x) = e^(3νπi/4) (x/2)^ν (1/Γ(ν+1) + i (x/2)² / Γ(ν+2) + ...)
+    let phase = Complex64::new(cos_pi(0.75 * nu), sin_pi(0.75 * nu));
+    let leading = phase / gamma(nu + 1f64);
+    let next = phase * Complex64::i() / gamma(nu + 2f64);
+    limits_at_zero(&[(leading, nu), (next, nu + 2f64)])
+}
+
+/// Limits of `rotation` (ker_ν(x) + i kei_ν(x)) and its derivative for x → 0+ with ν ≥ 0,
+/// where the rotation is 1 for ν = 0
+fn ke_at_zero(nu: f64, rotation: Complex64) -> (Complex64, Complex64) {
+    if nu == 0f64 {
+        // ker_0(x) ~ -ln(x/2) - γ and kei_0(x) ~ -π/4
+        return (
+            Complex64::new(f64::INFINITY, -FRAC_PI_4),
+            Complex64::new(f64::NEG_INFINITY, 0f64),
+        );
+    }
+    // ker_ν(x) + i kei_ν(x) = e^(-νπi/2) K_ν(x e^(πi/4)), where
+    // K_ν(z) = Γ(ν)/2 (z/2)^(-ν) (1 + (z/2)² / (1-ν) + ...) + Γ(-ν)/2 (z/2)^ν (1 + ...)
+    // and the second series is replaced by logarithmic terms of higher order for integer ν.
+    let phase = rotation * Complex64::new(cos_pi(0.75 * nu), -sin_pi(0.75 * nu));
+    let leading = 0.5 * gamma(nu) * phase;
+    let next = leading * Complex64::i() / (1f64 - nu);
+    if nu == nu.floor() {
+        limits_at_zero(&[(leading, -nu), (next, 2f64 - nu)])
+    } else {
+        let second = rotation * Complex64::new(cos_pi(0.25 * nu), -sin_pi(0.25 * nu));
+        limits_at_zero(&[
+            (leading, -nu),
+            (next, 2f64 - nu),
+            (0.5 * gamma(-nu) * second, nu),
+        ])
+    }
+}
+
+/// Limits of the real and imaginary parts of Σ c (x/2)^p and their derivatives for x → 0+,
+/// given the terms (c, p) of lowest power
+fn limits_at_zero(terms: &[(Complex64, f64)]) -> (Complex64, Complex64) {
+    let part = |coefficients: &dyn Fn(Complex64) -> f64| {
+        let lowest = |skip_constant: bool| {
+            terms
+                .iter()
+                .map(|&(c, p)| (coefficients(c), p))
+                .filter(|&(c, p)| c != 0f64 && !(skip_constant && p == 0f64))
+                .fold((0f64, f64::INFINITY), |a, b| if b.1 < a.1 { b } else { a })
+        };
+        let (c, p) = lowest(false);
+        let value = if p > 0f64 {
+            0f64
+        } else if p == 0f64 {
+            c
+        } else {
+            c.signum() * f64::INFINITY
+        };
+        // d/dx c (x/2)^p = c p/2 (x/2)^(p-1)
+        let (c, p) = lowest(true);
+        let derivative = if p > 1f64 {
+            0f64
+        } else if p == 1f64 {
+            0.5 * c
+        } else {
+            (c * p).signum() * f64::INFINITY
+        };
+        (value, derivative)
+    };
+    let (re, re_derivative) = part(&|c| c.re);
+    let (im, im_derivative) = part(&|c| c.im);
+    (
+        Complex64::new(re, im),
+        Complex64::new(re_derivative, im_derivative),
+    )
+}
+
+/// ber_ν + i bei_ν, ker_ν + i kei_ν and their derivatives from Hankel's expansion
+/// K_ν(z) ~ √(π/2z) e^(-z) Σ a_k(ν) / z^k at z = x e^(πi/4) and z = x e^(5πi/4) (DLMF 10.67)
+///
+/// Returns `None` if the series does not reach full precision before diverging, or if its terms
+/// grow so large on the way that they cancel.
+fn kelvin_asymptotic(nu: f64, x: f64) -> Option<(Complex64, Complex64, Complex64, Complex64)> {
+    // Σ a_k t^k and Σ (a_k + (k - 1/2) a_{k-1}) t^k with t = e^(-πi/4) / x, which give K_ν and
+    // -K_ν' up to the common factor, with alternating signs for z = x e^(5πi/4)
+    let mu = 4f64 * nu * nu;
+    let t = Complex64::from_polar(x.recip(), -FRAC_PI_4);
+    let one = Complex64::new(1f64, 0f64);
+    let (mut s_plus, mut s_minus, mut d_plus, mut d_minus) = (one, one, one, one);
+    let mut term = one;
+    let mut largest = 1f64;
+    let mut converged = false;
+    for k in 1..200 {
+        let kf = k as f64;
+        let next = term * (mu - (2f64 * kf - 1f64).powi(2)) / (8f64 * kf) * t;
+        let extra = (kf - 0.5) * term * t;
+        let size = next.norm() + extra.norm();
+        if kf > nu + 0.5 && size >= term.norm() {
+            break;
+        }
+        term = next;
+        largest = largest.max(size);
+        let sign = if k % 2 == 0 { 1f64 } else { -1f64 };
+        s_plus += term;
+        s_minus += sign * term;
+        d_plus += term + extra;
+        d_minus += sign * (term + extra);
+        if size <= EPS * s_plus.norm().min(d_plus.norm()) {
+            converged = true;
+            break;
+        }
+    }
+    let smallest = s_plus.norm().min(s_minus.norm());
+    if !converged || largest > CANCELLATION * smallest.min(d_plus.norm()).min(d_minus.norm()) {
+        return None;
+    }
+
+    // K_ν(x e^(πi/4)) e^(x/√2) and K_ν(x e^(5πi/4)) e^(-x/√2), with their derivatives
+    let y = FRAC_1_SQRT_2 * x;
+    let root = (0.5 * PI / x).sqrt();
+    let k_plus = Complex64::from_polar(root, -FRAC_PI_8 - y);
+    let k_minus = Complex64::from_polar(root, -5f64 * FRAC_PI_8 + y);
+    let (kw, kwp) = (k_plus * s_plus, -k_plus * d_plus);
+    let (ku, kup) = (k_minus * s_minus, -k_minus * d_minus);
+
+    // ker_ν + i kei_ν = e^(-νπi/2) K_ν(x e^(πi/4))
+    let rotation = Complex64::from_polar(1f64, -FRAC_PI_2 * nu);
+    let d = Complex64::from_polar(1f64, FRAC_PI_4);
+    let decay = (-y).exp();
+    let ke = rotation * kw * decay;
+    let kep = rotation * d * kwp * decay;
+
+    // ber_ν + i bei_ν = J_ν(x e^(3πi/4)) = e^(νπi/2) I_ν(x e^(πi/4)), where
+    // πi I_ν(z) = e^(-νπi) K_ν(z) - K_ν(z e^(πi))
+    let factor = Complex64::from_polar(1f64 / PI, FRAC_PI_2 * nu - FRAC_PI_2);
+    let reflection = Complex64::from_polar(decay * decay, -PI * nu);
+    let growth = y.exp();
+    let be = factor * (reflection * kw - ku) * growth;
+    let bep = factor * (reflection * d * kwp + d * kup) * growth;
+    Some((be, ke, bep, kep))
+}
@@ -9,27 +9,77 @@ pub use lambert_w::{lambert_w, lambert_w0, lambert_wm1, sp_lambert_w0, sp_lamber
 // =============================================================================
 pub mod utils;
 
+pub mod sf_error;
+pub use sf_error::SpecialFunctionError;
+
 pub mod gamma;
-pub use gamma::{gamma, gammp, gammq, invgammp, ln_gamma};
+pub use gamma::{
+    complex_gamma, complex_ln_gamma, digamma, gamma, gammp, gammp_grad, gammq, invgammp, invgammq,
+    ln_gamma, ln_gamma_sign, ln_lower_incomplete_gamma, ln_upper_incomplete_gamma,
+    lower_incomplete_gamma, polygamma, trigamma, try_gammp, try_gammq, try_invgammp,
+    upper_incomplete_gamma,
+};
 
 pub mod beta;
-pub use beta::{beta, betai, invbetai};
+pub use beta::{
+    beta, betai, betai_grad, betaic, invbetai, invbetaic, ln_beta, ln_betai, try_betai, try_betaic,
+};
+
+pub mod zeta;
+pub use zeta::{hurwitz_zeta, zeta, zetac};
 
 pub mod error;
-pub use error::{erf, erfc, inverf, inverfc};
+pub use error::{erf, erfc, erfcx, erfi, inverf, inverfc};
 
 pub mod dawson;
 pub use dawson::dawson;
 
 pub mod faddeeva;
-pub use faddeeva::faddeeva;
+pub use faddeeva::{
+    complex_dawson, complex_erf, complex_erfc, complex_erfcx, complex_erfi, faddeeva,
+};
+
+pub mod fresnel;
+pub use fresnel::{complex_fresnel, fresnel, fresnel_aux};
+
+pub mod distributions;
+pub use distributions::{
+    Beta, Binomial, ChiSquared, ContinuousDistribution, DiscreteDistribution, FisherF, Gamma,
+    Normal, Poisson, StudentT,
+};
 
 pub mod bessel;
 pub use bessel::{
-    besselik, besseljy, CachedBesselIK, CachedBesselJY, CachedInuKnu, CachedJnuYnu, In, Inu_Knu,
-    Jn, Jnu_Ynu, Kn, Yn,
+    besselik, besselik_scaled, besselik_sequence, besselik_sequence_into, besseljy,
+    besseljy_sequence, besseljy_sequence_into, complex_besseli, complex_besseli_scaled,
+    complex_besselj, complex_besselj_scaled, complex_besselk, complex_besselk_scaled,
+    complex_bessely, complex_bessely_scaled, complex_hankel1, complex_hankel1_scaled,
+    complex_hankel2, complex_hankel2_scaled, in_sequence, in_sequence_into, jn_sequence,
+    jn_sequence_into, jn_zeros, jnp_zeros, kn_sequence, kn_sequence_into, spherical_in,
+    spherical_jn, spherical_kn, spherical_yn, try_besselik, try_besselik_scaled, try_besseljy,
+    try_jn_zeros, try_jnp_zeros, try_yn_zeros, try_ynp_zeros, yn_sequence, yn_sequence_into,
+    yn_zeros, ynp_zeros, CachedBesselIK, CachedBesselJY, CachedInuKnu, CachedJnuYnu, In, In_scaled,
+    Inu_Knu, Jn, Jnu_Ynu, Kn, Kn_scaled, Yn,
 };
 
+pub mod airy;
+pub use airy::{airy, airy_scaled};
+
+pub mod struve;
+pub use struve::{struve_h, struve_l};
+
+pub mod kelvin;
+pub use kelvin::{berbei, kerkei};
+
+pub mod elliptic;
+pub use elliptic::{
+    ellipe, ellipeinc, ellipk, ellipkinc, ellipkm1, ellippi, ellippiinc, elliprc, elliprd, elliprf,
+    elliprj,
+};
+
+pub mod jacobi_elliptic;
+pub use jacobi_elliptic::ellipj;
+
 // =============================================================================
 // Constants
 // =============================================================================
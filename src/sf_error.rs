@@ -0,0 +1,44 @@
+//! This module provides the error type returned by the fallible `try_*` variants of the special functions.
+//!
+//! It includes the following:
+//! - `SpecialFunctionError`: The ways in which the evaluation of a special function can fail.
+
+use core::fmt;
+
+/// The ways in which the evaluation of a special function can fail.
+///
+/// Each variant carries a short description of what went wrong,
+/// which is also the message of the panic raised by the panicking version of the function.
+/// More variants may be added in the future, so matches on the error need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpecialFunctionError {
+    /// An argument is outside of the domain of the function.
+    Domain(&'static str),
+    /// An iterative method failed to converge.
+    NoConvergence(&'static str),
+    /// The result is too large in magnitude to be represented.
+    Overflow(&'static str),
+    /// The result is too small in magnitude to be represented with full precision.
+    Underflow(&'static str),
+}
+
+impl SpecialFunctionError {
+    /// Returns the description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::Domain(message)
+            | Self::NoConvergence(message)
+            | Self::Overflow(message)
+            | Self::Underflow(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for SpecialFunctionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for SpecialFunctionError {}
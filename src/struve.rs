@@ -0,0 +1,248 @@
+//! This module provides implementations of the Struve functions.
+//!
+//! It includes the following main functions:
+//! - `struve_h`: Calculates the Struve function of real order.
+//! - `struve_l`: Calculates the modified Struve function of real order.
+
+use crate::bessel::{besselik, besseljy, besseljy_sequence};
+use crate::gamma::{gamma, ln_gamma_sign};
+use crate::{EPS, INV_SQRT_PI};
+use core::f64::consts::FRAC_2_PI;
+
+/// Maximum number of terms of the power series
+const MAXIT: usize = 10000;
+/// x above which the asymptotic expansions are tried
+const XASYM: f64 = 16.0;
+/// Largest ratio of the largest term to the sum for which the power series of H_ν is used
+const CANCELLATION: f64 = 8.0;
+
+/// Calculates the Struve function $\mathbf{H}_\nu(x)$
+///
+/// The Struve function is the particular solution of the inhomogeneous Bessel equation
+///
+/// $$ x^2 \frac{d^2y}{dx^2} + x \frac{dy}{dx} + (x^2 - \nu^2)y = \frac{4 (x/2)^{\nu+1}}{\sqrt{\pi} \Gamma(\nu + 1/2)} $$
+///
+/// given by the series
+///
+/// $$ \mathbf{H}_\nu(x) = \sum_{k=0}^\infty \frac{(-1)^k (x/2)^{2k+\nu+1}}{\Gamma(k + 3/2) \Gamma(k + \nu + 3/2)} $$
+///
+/// # Arguments
+///
+/// * `nu` - The order (real number)
+/// * `x` - The input value (real number, non-negative unless `nu` is an integer)
+///
+/// # Returns
+///
+/// The value of $\mathbf{H}_\nu(x)$, which is NaN for `x` < 0 if `nu` is not an integer,
+/// since $\mathbf{H}_\nu(x)$ is complex there
+///
+/// # Notes on implementation
+///
+/// The power series is used where its terms do not cancel. For large `x` the asymptotic
+/// expansion of $\mathbf{H}_\nu(x) - Y_\nu(x)$ (DLMF 11.6.1) is added to [`besseljy`], and
+/// in between the expansion in $J_{\nu+2k+1}(x)$ (DLMF 11.4.18) is summed, which for
+/// $\nu \le -1$ is combined with the recurrence in the order (DLMF 11.4.23).
+pub fn struve_h(nu: f64, x: f64) -> f64 {
+    struve_impl(nu, x, false)
+}
+
+/// Calculates the modified Struve function $\mathbf{L}_\nu(x)$
+///
+/// The modified Struve function is the particular solution of the inhomogeneous modified Bessel equation
+///
+/// $$ x^2 \frac{d^2y}{dx^2} + x \frac{dy}{dx} - (x^2 + \nu^2)y = \frac{4 (x/2)^{\nu+1}}{\sqrt{\pi} \Gamma(\nu + 1/2)} $$
+///
+/// given by the series
+///
+/// $$ \mathbf{L}_\nu(x) = \sum_{k=0}^\infty \frac{(x/2)^{2k+\nu+1}}{\Gamma(k + 3/2) \Gamma(k + \nu + 3/2)} $$
+///
+/// # Arguments
+///
+/// * `nu` - The order (real number)
+/// * `x` - The input value (real number, non-negative unless `nu` is an integer)
+///
+/// # Returns
+///
+/// The value of $\mathbf{L}_\nu(x)$, which is NaN for `x` < 0 if `nu` is not an integer,
+/// since $\mathbf{L}_\nu(x)$ is complex there
+///
+/// # Notes on implementation
+///
+/// The power series is used except for large `x`, where the asymptotic expansion of
+/// $\mathbf{L}_\nu(x) - I_{-\nu}(x)$ (DLMF 11.6.2) is added to [`besselik`].
+pub fn struve_l(nu: f64, x: f64) -> f64 {
+    struve_impl(nu, x, true)
+}
+
+fn struve_impl(nu: f64, x: f64, modified: bool) -> f64 {
+    if nu.is_nan() || x.is_nan() {
+        return f64::NAN;
+    } else if x < 0f64 {
+        // H_n(-x) = (-1)^(n+1) H_n(x) and L_n(-x) = (-1)^(n+1) L_n(x)
+        return if nu != nu.floor() {
+            f64::NAN
+        } else if nu % 2f64 == 0f64 {
+            -struve_impl(nu, -x, modified)
+        } else {
+            struve_impl(nu, -x, modified)
+        };
+    }
+
+    if nu < 0f64 && nu + 0.5 == (nu + 0.5).floor() {
+        // H_{-(n+1/2)}(x) = (-1)^n J_{n+1/2}(x) and L_{-(n+1/2)}(x) = I_{n+1/2}(x) (DLMF 11.4.4, 11.4.5)
+        let n = -(nu + 0.5);
+        return if modified {
+            besselik(-nu, x).0
+        } else if n % 2f64 == 0f64 {
+            besseljy(-nu, x).0
+        } else {
+            -besseljy(-nu, x).0
+        };
+    } else if x == 0f64 {
+        // The leading term (x/2)^(ν+1) / (Γ(3/2) Γ(ν+3/2)) of the power series
+        return if nu > -1f64 {
+            0f64
+        } else if nu == -1f64 {
+            FRAC_2_PI
+        } else {
+            ln_gamma_sign(nu + 1.5).1 as f64 * f64::INFINITY
+        };
+    } else if x == f64::INFINITY {
+        return if modified || nu > 1f64 {
+            f64::INFINITY
+        } else if nu == 1f64 {
+            FRAC_2_PI
+        } else {
+            0f64
+        };
+    }
+
+    if x > XASYM {
+        if let Some(value) = struve_asymptotic(nu, x, modified) {
+            return value;
+        }
+    }
+    let (sum, largest) = struve_series(nu, x, modified);
+    if modified || largest <= CANCELLATION * sum.abs() {
+        sum
+    } else if nu > -1f64 {
+        struve_h_bessel_series(nu, x)
+    } else {
+        // H_{μ-1}(x) = 2μ/x H_μ(x) - H_{μ+1}(x) + (x/2)^μ / (√π Γ(μ+3/2)) from μ in (-1, 0]
+        let mut mu = nu - nu.ceil();
+        let mut h_next = struve_impl(mu + 1f64, x, false);
+        let mut h = struve_impl(mu, x, false);
+        while mu > nu {
+            let source = INV_SQRT_PI * power_over_gamma(0.5 * x, mu, mu + 1.5);
+            let h_prev = 2f64 * mu / x * h - h_next + source;
+            h_next = h;
+            h = h_prev;
+            mu -= 1f64;
+        }
+        h
+    }
+}
+
+/// Power series of H_ν(x) or L_ν(x) together with the magnitude of its largest term
+fn struve_series(nu: f64, x: f64, modified: bool) -> (f64, f64) {
+    let half = 0.5 * x;
+    let q = if modified { half * half } else { -half * half };
+    // 1 / Γ(3/2) = 2 / √π
+    let mut term = 2f64 * INV_SQRT_PI * power_over_gamma(half, nu + 1f64, nu + 1.5);
+    let mut sum = term;
+    let mut largest = term.abs();
+    for k in 1..MAXIT {
+        let k = k as f64;
+        term *= q / ((k + 0.5) * (k + nu + 0.5));
+        sum += term;
+        largest = largest.max(term.abs());
+        if term.abs() <= EPS * sum.abs() {
+            break;
+        }
+    }
+    (sum, largest)
+}
+
+/// H_ν(x) = Y_ν(x) + Σ_k Γ(k+1/2) (x/2)^(ν-2k-1) / (π Γ(ν+1/2-k)) (DLMF 11.6.1) and
+/// L_ν(x) = I_{-ν}(x) - Σ_k (-1)^k Γ(k+1/2) (x/2)^(ν-2k-1) / (π Γ(ν+1/2-k)) (DLMF 11.6.2)
+///
+/// Returns `None` if the smallest term of the divergent series exceeds EPS times the result.
+fn struve_asymptotic(nu: f64, x: f64, modified: bool) -> Option<f64> {
+    if nu >= x {
+        // The terms grow by up to (ν/x)² before they decrease, and the sum cancels Y_ν or I_{-ν}.
+        return None;
+    }
+    // J_ν(x) accounts for the size of H_ν(x) - Y_ν(x) where the functions oscillate.
+    let (bessel, scale) = if modified {
+        let inu = besselik(-nu, x).0;
+        if inu.is_infinite() {
+            return Some(inu);
+        }
+        (inu, 0f64)
+    } else {
+        let (jnu, ynu, _, _) = besseljy(nu, x);
+        (ynu, jnu.abs())
+    };
+    let q = if modified {
+        -4f64 / (x * x)
+    } else {
+        4f64 / (x * x)
+    };
+    // Γ(1/2) / π = 1 / √π
+    let mut term = INV_SQRT_PI * power_over_gamma(0.5 * x, nu - 1f64, nu + 0.5);
+    let mut sum = term;
+    for k in 1..MAXIT {
+        let kf = k as f64;
+        let next = term * q * (kf - 0.5) * (nu + 0.5 - kf);
+        let value = if modified { bessel - sum } else { bessel + sum };
+        if next.abs() <= EPS * (scale + value.abs()) {
+            return Some(value);
+        } else if next.abs() > term.abs() {
+            return None;
+        }
+        term = next;
+        sum += term;
+    }
+    None
+}
+
+/// H_ν(x) = 4 / (√π Γ(ν+1/2)) Σ_k (2k+ν+1) Γ(k+ν+1) / (k! (2k+1) (2k+2ν+1)) J_{2k+ν+1}(x)
+/// for ν > -1 (DLMF 11.4.18)
+fn struve_h_bessel_series(nu: f64, x: f64) -> f64 {
+    // J_{2k+ν+1}(x) decays beyond the turning point x, but for large ν the coefficients grow
+    // like binomial coefficients, so the orders are extended until the sum has converged.
+    let mut n_max = ((x - nu).max(0f64) + 12f64 * x.cbrt() + 30f64) as u32;
+    loop {
+        let (j, _) = besseljy_sequence(nu + 1f64, n_max, x);
+        // b_k = Γ(k+ν+1) / (k! Γ(ν+1/2) (2k+2ν+1)), starting from b_0 = Γ(ν+1) / (2 Γ(ν+3/2))
+        let mut b = 0.5 * gamma(nu + 1f64) * power_over_gamma(1f64, 0f64, nu + 1.5);
+        if !b.is_finite() {
+            let (lg1, sign1) = ln_gamma_sign(nu + 1f64);
+            let (lg2, sign2) = ln_gamma_sign(nu + 1.5);
+            b = 0.5 * (sign1 * sign2) as f64 * (lg1 - lg2).exp();
+        }
+        let mut sum = (nu + 1f64) * b * j[0];
+        for k in 1..=(n_max / 2) as usize {
+            let kf = k as f64;
+            b *= (kf + nu) * (2f64 * (kf + nu) - 1f64) / ((2f64 * (kf + nu) + 1f64) * kf);
+            let term = (2f64 * kf + nu + 1f64) / (2f64 * kf + 1f64) * b * j[2 * k];
+            sum += term;
+            if nu + 1f64 + 2f64 * kf > x && term.abs() <= EPS * sum.abs() {
+                return 4f64 * INV_SQRT_PI * sum;
+            }
+        }
+        n_max *= 2;
+    }
+}
+
+/// y^a / Γ(b), through logarithms where the factors over- or underflow
+fn power_over_gamma(y: f64, a: f64, b: f64) -> f64 {
+    let (power, gamma_b) = (y.powf(a), gamma(b));
+    let value = power / gamma_b;
+    if power.is_normal() && gamma_b.is_normal() && value.is_normal() {
+        value
+    } else {
+        let (lg, sign) = ln_gamma_sign(b);
+        sign as f64 * (a * y.ln() - lg).exp()
+    }
+}
@@ -131,3 +131,72 @@ fn mul_add(x: f64, mul: f64, add: f64) -> f64 {
         x.mul_add(mul, add)
     }
 }
+
+/// Evaluates the continued fraction
+///
+/// $$ b_0 + \cfrac{a_1}{b_1 + \cfrac{a_2}{b_2 + \cdots}} $$
+///
+/// together with its gradient with respect to `N` parameters, using the Wallis recurrences
+/// for the convergents and their derivatives.
+///
+/// `b0` and `db0` are the leading term and its gradient, and `terms(n)` returns $(a_n, b_n)$
+/// and their gradients for n ≥ 1.
+/// The recurrences are rescaled in every step to avoid overflow.
+pub(crate) fn continued_fraction_with_gradient<const N: usize, F>(
+    b0: f64,
+    db0: [f64; N],
+    mut terms: F,
+) -> (f64, [f64; N])
+where
+    F: FnMut(usize) -> (f64, f64, [f64; N], [f64; N]),
+{
+    const MAXIT: usize = 100_000;
+
+    // Convergents A/B of the current and the previous step.
+    let (mut a_prev, mut b_prev) = (1f64, 0f64);
+    let (mut a_curr, mut b_curr) = (b0, 1f64);
+    let (mut da_prev, mut db_prev) = ([0f64; N], [0f64; N]);
+    let (mut da_curr, mut db_curr) = (db0, [0f64; N]);
+
+    let mut value = b0;
+    let mut gradient = db0;
+    for n in 1..=MAXIT {
+        let (an, bn, dan, dbn) = terms(n);
+        let a_next = bn * a_curr + an * a_prev;
+        let b_next = bn * b_curr + an * b_prev;
+        let mut da_next = [0f64; N];
+        let mut db_next = [0f64; N];
+        for k in 0..N {
+            da_next[k] = dbn[k] * a_curr + bn * da_curr[k] + dan[k] * a_prev + an * da_prev[k];
+            db_next[k] = dbn[k] * b_curr + bn * db_curr[k] + dan[k] * b_prev + an * db_prev[k];
+        }
+
+        // All the recurrences are linear, so they can be rescaled by a common factor.
+        let scale = 1f64 / b_next;
+        a_prev = a_curr * scale;
+        b_prev = b_curr * scale;
+        a_curr = a_next * scale;
+        b_curr = 1f64;
+        for k in 0..N {
+            da_prev[k] = da_curr[k] * scale;
+            db_prev[k] = db_curr[k] * scale;
+            da_curr[k] = da_next[k] * scale;
+            db_curr[k] = db_next[k] * scale;
+        }
+
+        let new_value = a_curr;
+        let mut new_gradient = [0f64; N];
+        let mut converged = (new_value - value).abs() <= f64::EPSILON * new_value.abs();
+        for k in 0..N {
+            new_gradient[k] = da_curr[k] - a_curr * db_curr[k];
+            converged &= (new_gradient[k] - gradient[k]).abs()
+                <= f64::EPSILON * new_gradient[k].abs().max(f64::MIN_POSITIVE);
+        }
+        value = new_value;
+        gradient = new_gradient;
+        if converged {
+            break;
+        }
+    }
+    (value, gradient)
+}
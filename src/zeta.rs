@@ -0,0 +1,184 @@
+//! This module provides implementations of the Riemann zeta function and related functions.
+//!
+//! It includes the following main functions:
+//! - `zeta`: Calculates the Riemann zeta function.
+//! - `zetac`: Calculates the Riemann zeta function minus one.
+//! - `hurwitz_zeta`: Calculates the Hurwitz zeta function.
+
+use crate::gamma::{sin_pi, BERNOULLI_2K};
+use crate::{gamma, EPS};
+use core::f64::consts::{LN_2, PI};
+
+/// Calculates the Riemann zeta function.
+///
+/// The Riemann zeta function is defined for $s > 1$ as:
+///
+/// $$ \zeta(s) = \sum_{k=1}^\infty \frac{1}{k^s} $$
+///
+/// and by analytic continuation elsewhere. For $-1 < s < 1$ it is computed from the alternating
+/// series $\zeta(s) = \eta(s) / (1 - 2^{1-s})$, and for $s \le -1$ from the functional equation
+///
+/// $$ \zeta(s) = 2^s \pi^{s-1} \sin\left(\frac{\pi s}{2}\right) \Gamma(1-s) \zeta(1-s) $$
+///
+/// # Arguments
+///
+/// * `s` - The input value
+///
+/// # Returns
+///
+/// The value of $\zeta(s)$, which is +∞ at the pole `s` = 1
+pub fn zeta(s: f64) -> f64 {
+    if s.is_nan() || s == f64::NEG_INFINITY {
+        f64::NAN
+    } else if s == 1f64 {
+        f64::INFINITY
+    } else if s > 1f64 {
+        1f64 + zetac(s)
+    } else if s > -1f64 {
+        zeta_alternating(s)
+    } else {
+        zeta_reflection(s)
+    }
+}
+
+/// Calculates the Riemann zeta function minus one.
+///
+/// $$ \zeta(s) - 1 = \sum_{k=2}^\infty \frac{1}{k^s} $$
+///
+/// Unlike `zeta(s) - 1` it keeps its relative accuracy for large `s`, where $\zeta(s)$ rounds to 1.
+///
+/// # Arguments
+///
+/// * `s` - The input value
+///
+/// # Returns
+///
+/// The value of $\zeta(s) - 1$, which is +∞ at the pole `s` = 1
+pub fn zetac(s: f64) -> f64 {
+    if s > 1f64 {
+        // ζ(s) - 1 = ζ(s, 2)
+        hurwitz_zeta(s, 2f64)
+    } else {
+        zeta(s) - 1f64
+    }
+}
+
+/// Calculates the Hurwitz zeta function.
+///
+/// The Hurwitz zeta function is defined as:
+///
+/// $$ \zeta(s, a) = \sum_{k=0}^\infty \frac{1}{(k + a)^s} $$
+///
+/// so that $\zeta(s, 1) = \zeta(s)$ and $\psi^{(n)}(a) = (-1)^{n+1} n! \zeta(n + 1, a)$.
+///
+/// # Arguments
+///
+/// * `s` - The exponent (greater than 1)
+/// * `a` - The shift (positive real number)
+///
+/// # Returns
+///
+/// The value of $\zeta(s, a)$, which is +∞ at the pole `s` = 1 and NaN for `s` < 1 or `a` ≤ 0
+pub fn hurwitz_zeta(s: f64, a: f64) -> f64 {
+    if !(s >= 1f64 && a > 0f64) {
+        return f64::NAN;
+    } else if s == 1f64 {
+        return f64::INFINITY;
+    } else if s == f64::INFINITY {
+        return match a.partial_cmp(&1f64) {
+            Some(core::cmp::Ordering::Less) => f64::INFINITY,
+            Some(core::cmp::Ordering::Equal) => 1f64,
+            _ => 0f64,
+        };
+    }
+
+    // Sum the terms relative to the first one, shifting a upwards until the
+    // Euler-Maclaurin formula for the tail converges quickly.
+    let xmin = 10f64 + s;
+    let mut sum = 0f64;
+    let mut xs = a;
+    while xs < xmin {
+        let term = (a / xs).powf(s);
+        sum += term;
+        xs += 1f64;
+        if term < EPS * sum {
+            // The tail is negligible.
+            return sum * a.powf(-s);
+        }
+    }
+
+    // Euler-Maclaurin formula for a^s Σ_{k≥0} 1/(xs + k)^s
+    let x2 = (xs * xs).recip();
+    let mut tail = xs / (s - 1f64) + 0.5;
+    // Holds s(s+1)...(s+2k-2) / ((2k)! xs^(2k-1)).
+    let mut factor = s / (2f64 * xs);
+    for (k, &b) in BERNOULLI_2K.iter().enumerate() {
+        let term = b * factor;
+        tail += term;
+        if term.abs() <= EPS * tail.abs() {
+            break;
+        }
+        let k = (k + 1) as f64;
+        factor *=
+            (s + 2f64 * k - 1f64) * (s + 2f64 * k) / ((2f64 * k + 1f64) * (2f64 * k + 2f64)) * x2;
+    }
+    sum += (a / xs).powf(s) * tail;
+
+    // a^(-s), evaluated in log space if it over- or underflows in the direct computation
+    let scale = a.powf(-s);
+    if scale.is_finite() && scale > f64::MIN_POSITIVE {
+        scale * sum
+    } else {
+        (sum.ln() - s * a.ln()).exp()
+    }
+}
+
+/// ζ(s) for -1 < s < 1 from the Dirichlet eta function η(s) = (1 - 2^(1-s)) ζ(s).
+///
+/// η(s) is computed with the algorithm of P. Borwein,
+/// *An efficient algorithm for the Riemann zeta function*, CMS Conference Proceedings 27 (2000): 29-34.
+fn zeta_alternating(s: f64) -> f64 {
+    const N: usize = 24;
+    // d_k = n Σ_{i=0}^{k} (n+i-1)! 4^i / ((n-i)! (2i)!)
+    let mut d = [0f64; N + 1];
+    let mut term = 1f64;
+    let mut sum = 1f64;
+    d[0] = sum;
+    for i in 0..N {
+        let (n, i) = (N as f64, i as f64);
+        term *= 4f64 * (n + i) * (n - i) / ((2f64 * i + 1f64) * (2f64 * i + 2f64));
+        sum += term;
+        d[i as usize + 1] = sum;
+    }
+
+    let mut eta = 0f64;
+    for k in (0..N).rev() {
+        let t = (d[k] - d[N]) / ((k + 1) as f64).powf(s);
+        eta += if k % 2 == 0 { t } else { -t };
+    }
+    eta = -eta / d[N];
+
+    // 1 - 2^(1-s) without cancellation close to s = 1
+    eta / -((1f64 - s) * LN_2).exp_m1()
+}
+
+/// ζ(s) for s ≤ -1 from the functional equation.
+fn zeta_reflection(s: f64) -> f64 {
+    let sin = sin_pi(0.5 * s);
+    if sin == 0f64 {
+        // The trivial zeros at the negative even integers
+        return 0f64;
+    }
+    let one_minus_s = 1f64 - s;
+    if one_minus_s < 170f64 {
+        // 2^s π^(s-1) = (2π)^s / π
+        (2f64 * PI).powf(s) / PI * sin * gamma(one_minus_s) * zeta(one_minus_s)
+    } else if one_minus_s < 340f64 {
+        // Γ(1 - s) overflows, so it is split with the duplication formula
+        // Γ(1 - s) = 2^(-s) Γ((1 - s)/2) Γ(1 - s/2) / √π, and ζ(1 - s) rounds to 1.
+        PI.powf(s - 1.5) * gamma(0.5 * one_minus_s) * sin * gamma(1f64 - 0.5 * s)
+    } else {
+        // |ζ(s)| overflows
+        f64::INFINITY.copysign(sin)
+    }
+}
@@ -0,0 +1,284 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use core::f64::consts::FRAC_1_PI;
+use puruspe::{airy, airy_scaled};
+
+#[test]
+fn test_airy() {
+    for (x, ai, bi, aip, bip) in AIRY_TABLE {
+        let result = airy(x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result.0, ai, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(result.1, bi, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(result.2, aip, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(result.3, bip, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_airy_scaled() {
+    for (x, ai, bi, aip, bip) in AIRY_SCALED_TABLE {
+        let result = airy_scaled(x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result.0, ai, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(result.1, bi, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(result.2, aip, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(result.3, bip, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_airy_wronskian() {
+    // Ai(x) Bi'(x) - Ai'(x) Bi(x) = 1/π, also for the scaled functions
+    for &x in [-200.0, -12.0, -4.0, -1.0, 0.0, 0.7, 3.0, 9.0, 25.0].iter() {
+        let (ai, bi, aip, bip) = airy(x);
+        assert_relative_eq!(ai * bip - aip * bi, FRAC_1_PI, max_relative = 1e-13);
+        let (ai, bi, aip, bip) = airy_scaled(x);
+        assert_relative_eq!(ai * bip - aip * bi, FRAC_1_PI, max_relative = 1e-13);
+    }
+}
+
+#[test]
+fn test_airy_edge_cases() {
+    // Ai and Bi underflow and overflow only without the scaling
+    let (ai, bi, aip, bip) = airy(200.0);
+    assert_eq!((ai, aip), (0.0, 0.0));
+    assert_eq!((bi, bip), (f64::INFINITY, f64::INFINITY));
+    let (ai, bi, aip, bip) = airy_scaled(200.0);
+    assert!(ai > 0.0 && bi > 0.0 && aip < 0.0 && bip > 0.0);
+
+    assert_eq!(
+        airy(f64::INFINITY),
+        (0.0, f64::INFINITY, 0.0, f64::INFINITY)
+    );
+    let (ai, bi, aip, bip) = airy(f64::NAN);
+    assert!(ai.is_nan() && bi.is_nan() && aip.is_nan() && bip.is_nan());
+
+    // The scaling is the identity for x ≤ 0
+    for &x in [-30.0, -3.0, 0.0].iter() {
+        assert_eq!(airy(x), airy_scaled(x));
+    }
+}
+
+const AIRY_TABLE: [(f64, f64, f64, f64, f64); 22] = [
+    (
+        -5.0000000000000000e+02,
+        7.2590120104041145e-02,
+        -9.4688570132991021e-02,
+        2.1173370928026483e+00,
+        1.6231170882193451e+00,
+    ),
+    (
+        -6.0000000000000000e+01,
+        7.7787824477115589e-02,
+        -1.8719683288298331e-01,
+        1.4503455958642244e+00,
+        6.0176234991628519e-01,
+    ),
+    (
+        -1.5000000000000000e+01,
+        2.7821749087082892e-01,
+        -6.9126594531010055e-02,
+        2.7237420430864201e-01,
+        1.0764297530843747e+00,
+    ),
+    (
+        -9.0000000000000000e+00,
+        -2.2133721547341403e-02,
+        3.2494732345524491e-01,
+        -9.7566398092633155e-01,
+        -5.7400513843669253e-02,
+    ),
+    (
+        -5.0000000000000000e+00,
+        3.5076100902411433e-01,
+        -1.3836913490160058e-01,
+        3.2719281855444315e-01,
+        7.7841177300189923e-01,
+    ),
+    (
+        -2.0000000000000000e+00,
+        2.2740742820168558e-01,
+        -4.1230258795639851e-01,
+        6.1825902074169103e-01,
+        2.7879516692116951e-01,
+    ),
+    (
+        -1.5000000000000000e+00,
+        4.6425657774886941e-01,
+        -1.9178486115704121e-01,
+        3.0918696720241040e-01,
+        5.5790810302189731e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        5.3556088329235207e-01,
+        1.0399738949694461e-01,
+        -1.0160567116645210e-02,
+        5.9237562642279240e-01,
+    ),
+    (
+        -5.0000000000000000e-01,
+        4.7572809161053958e-01,
+        3.8035265975105387e-01,
+        -2.0408167033954738e-01,
+        5.0593371362384720e-01,
+    ),
+    (
+        -1.0000000000000000e-10,
+        3.5502805391369918e-01,
+        6.1492662740117188e-01,
+        -2.5881940379280682e-01,
+        4.4828835735382638e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        3.5502805388781722e-01,
+        6.1492662744600068e-01,
+        -2.5881940379280682e-01,
+        4.4828835735382638e-01,
+    ),
+    (
+        1.0000000000000000e-10,
+        3.5502805386193531e-01,
+        6.1492662749082960e-01,
+        -2.5881940379280682e-01,
+        4.4828835735382638e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.7880648195500490e-01,
+        7.5248558508731567e-01,
+        -2.4514636421905481e-01,
+        4.8004902875244804e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.3529241631288141e-01,
+        1.2074235949528713e+00,
+        -1.5914744129679320e-01,
+        9.3243593339277564e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        7.1749497008105415e-02,
+        1.8789415037478949e+00,
+        -9.7382012842301316e-02,
+        1.8862122548481655e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.4924130423274378e-02,
+        3.2980949999782148e+00,
+        -5.3090384433653631e-02,
+        4.1006820499328898e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0834442813607442e-04,
+        6.5779204417117114e+02,
+        -2.4741389086846248e-04,
+        1.4358190802179824e+03,
+    ),
+    (
+        7.0000000000000000e+00,
+        7.4921288639971666e-07,
+        8.0327790709430250e+04,
+        -2.0081508947387919e-06,
+        2.0955267087397131e+05,
+    ),
+    (
+        8.0000000000000000e+00,
+        4.6922076160992316e-08,
+        1.1995860041244600e+06,
+        -1.3414392979067865e-07,
+        3.3543423127445388e+06,
+    ),
+    (
+        1.5000000000000000e+01,
+        2.1649625207379925e-18,
+        1.8982099567493588e+16,
+        -8.4205679540177723e-18,
+        7.3197492034070112e+16,
+    ),
+    (
+        5.0000000000000000e+01,
+        4.5849417240748285e-104,
+        4.9090996994442195e+101,
+        -3.2443318198287991e-103,
+        3.4687987795459765e+102,
+    ),
+    (
+        1.0000000000000000e+02,
+        2.6344821520881846e-291,
+        6.0412239966702013e+288,
+        -2.6351403616044097e-290,
+        6.0397127453106026e+289,
+    ),
+];
+
+const AIRY_SCALED_TABLE: [(f64, f64, f64, f64, f64); 8] = [
+    (
+        -2.0000000000000000e+01,
+        -1.7640612707798470e-01,
+        -2.0013930932265134e-01,
+        8.9286285673647126e-01,
+        -7.9142903383953644e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        5.3556088329235207e-01,
+        1.0399738949694461e-01,
+        -1.0160567116645210e-02,
+        5.9237562642279240e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        2.9327715912994734e-01,
+        6.7489241111563025e-01,
+        -2.8469116209194256e-01,
+        4.3022096146376937e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        2.1057204278597699e-01,
+        4.3938402355009643e-01,
+        -3.8059274801926812e-01,
+        7.1749084648742378e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.5812366685434615e-01,
+        3.1834010533673446e-01,
+        -5.0390936071131087e-01,
+        9.9855594267383740e-01,
+    ),
+    (
+        1.2000000000000000e+02,
+        8.5224676543477401e-02,
+        1.7047636883237197e-01,
+        -9.3376702306704629e-01,
+        1.8671197264647803e+00,
+    ),
+    (
+        1.0000000000000000e+03,
+        5.0164170749970864e-02,
+        1.0032900247310518e-01,
+        -1.5863429058298844e+00,
+        3.1726565491304126e+00,
+    ),
+    (
+        1.0000000000000000e+08,
+        2.8209479177384875e-03,
+        5.6418958354781503e-03,
+        -2.8209479177391927e+01,
+        5.6418958354767398e+01,
+    ),
+];
@@ -1,7 +1,20 @@
 use approx::assert_relative_eq;
+use num_complex::Complex64;
 use puruspe::{
-    besselik, besseljy, CachedBesselIK, CachedBesselJY, CachedInuKnu, CachedJnuYnu, In, Inu_Knu,
-    Jn, Jnu_Ynu, Kn, Yn,
+    besselik, besselik_scaled, besseljy, complex_besseli, complex_besseli_scaled, complex_besselj,
+    complex_besselj_scaled, complex_besselk, complex_besselk_scaled, complex_bessely,
+    complex_bessely_scaled, complex_hankel1, complex_hankel1_scaled, complex_hankel2,
+    complex_hankel2_scaled, spherical_in, spherical_jn, spherical_kn, spherical_yn, try_besselik,
+    try_besselik_scaled, try_besseljy, CachedBesselIK, CachedBesselJY, CachedInuKnu, CachedJnuYnu,
+    In, In_scaled, Inu_Knu, Jn, Jnu_Ynu, Kn, Kn_scaled, SpecialFunctionError, Yn,
+};
+use puruspe::{
+    besselik_sequence, besselik_sequence_into, besseljy_sequence, besseljy_sequence_into,
+    in_sequence, jn_sequence, jn_sequence_into, kn_sequence, yn_sequence,
+};
+use puruspe::{
+    jn_zeros, jnp_zeros, try_jn_zeros, try_jnp_zeros, try_yn_zeros, try_ynp_zeros, yn_zeros,
+    ynp_zeros,
 };
 
 // epsilon in the assertion has been set to the smallest magnitude for which the tests pass.
@@ -160,6 +173,400 @@ fn test_besselik() {
     }
 }
 
+#[test]
+fn test_try_besseljy_besselik() {
+    for &(nu, x, ..) in BESSELJY_TABLE.iter() {
+        assert_eq!(try_besseljy(nu, x), Ok(besseljy(nu, x)));
+    }
+    for &(nu, x, ..) in BESSELIK_TABLE.iter() {
+        assert_eq!(try_besselik(nu, x), Ok(besselik(nu, x)));
+    }
+
+    assert_eq!(
+        try_besseljy(1.0, -1.0),
+        Err(SpecialFunctionError::Domain("bad arguments in besseljy"))
+    );
+    assert_eq!(
+        try_besselik(-1.0, -0.5),
+        Err(SpecialFunctionError::Domain("bad arguments in besselik"))
+    );
+
+    // The panicking versions return the overflowed values, the try_ versions report them.
+    assert_eq!(besselik(1e4, 1e4).0, f64::INFINITY);
+    assert_eq!(
+        try_besselik(1e4, 1e4),
+        Err(SpecialFunctionError::Overflow("Overflow in besselik"))
+    );
+    assert_eq!(besseljy(200.0, 1.0).1, f64::NEG_INFINITY);
+    assert_eq!(
+        try_besseljy(200.0, 1.0),
+        Err(SpecialFunctionError::Overflow("Overflow in besseljy"))
+    );
+    // J and I are subnormal here while Y and K and the derivatives are still finite.
+    assert!(besseljy(245.0, 10.0).0.is_subnormal());
+    assert_eq!(
+        try_besseljy(245.0, 10.0),
+        Err(SpecialFunctionError::Underflow("Underflow in besseljy"))
+    );
+    assert!(besselik(245.0, 10.0).0.is_subnormal());
+    assert_eq!(
+        try_besselik(245.0, 10.0),
+        Err(SpecialFunctionError::Underflow("Underflow in besselik"))
+    );
+    // The limits at x = 0 are neither overflows nor underflows.
+    assert_eq!(try_besselik(1.0, 0.0), Ok(besselik(1.0, 0.0)));
+    assert_eq!(try_besseljy(1.0, 0.0), Ok(besseljy(1.0, 0.0)));
+
+    // Large arguments are handled by the asymptotic expansions.
+    let (j, y, _, _) = try_besseljy(0.5, 1e5).unwrap();
+    let norm = (2.0 / (std::f64::consts::PI * 1e5)).sqrt();
+    assert_relative_eq!(j, norm * 1e5f64.sin(), max_relative = 1e-12);
+    assert_relative_eq!(y, -norm * 1e5f64.cos(), max_relative = 1e-12);
+
+    // Y_ν rounds to zero in the continued fraction here, which must not spoil Y_ν'.
+    let (_, y, _, yp) = try_besseljy(26.641999999999896, 29.511917512162228).unwrap();
+    assert!(y.abs() < 1e-15);
+    assert_relative_eq!(yp, 9.87389574167906e-2, max_relative = 1e-14);
+}
+
+fn check_zeros(zeros: fn(f64, usize) -> Vec<f64>, table: &[(u32, usize, f64)]) {
+    for &(n, k, expected) in table.iter() {
+        let result = zeros(n as f64, k)[k - 1];
+        assert_relative_eq!(result, expected, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_bessel_zeros() {
+    check_zeros(jn_zeros, &JN_ZEROS_TABLE);
+    check_zeros(yn_zeros, &YN_ZEROS_TABLE);
+    check_zeros(jnp_zeros, &JNP_ZEROS_TABLE);
+    check_zeros(ynp_zeros, &YNP_ZEROS_TABLE);
+
+    // Non-integer orders: the zeros interlace as ν ≤ j'_1 < y_1 < y'_1 < j_1 < j'_2 < ... and
+    // the functions vanish there up to the rounding errors of besseljy.
+    for &nu in [1e-6, 0.3, 2.5, 7.25, 33.3, 250.5].iter() {
+        let k = 30;
+        let (j, y, jp, yp) = (
+            jn_zeros(nu, k),
+            yn_zeros(nu, k),
+            jnp_zeros(nu, k),
+            ynp_zeros(nu, k),
+        );
+        assert!(jp[0] >= nu);
+        for m in 0..k {
+            assert!(jp[m] < y[m] && y[m] < yp[m] && yp[m] < j[m]);
+            if m + 1 < k {
+                assert!(j[m] < jp[m + 1]);
+            }
+            let tol = |x: f64, slope: f64| 32.0 * f64::EPSILON * x * slope.abs();
+            let (f, _, fp, _) = besseljy(nu, j[m]);
+            assert!(f.abs() <= tol(j[m], fp));
+            let (_, f, _, fp) = besseljy(nu, y[m]);
+            assert!(f.abs() <= tol(y[m], fp));
+            // The second derivative is (ν² / x² - 1) times the function at a zero of the derivative.
+            let (f, _, fp, _) = besseljy(nu, jp[m]);
+            assert!(fp.abs() <= tol(jp[m], (nu * nu / (jp[m] * jp[m]) - 1.0) * f));
+            let (_, f, _, fp) = besseljy(nu, yp[m]);
+            assert!(fp.abs() <= tol(yp[m], (nu * nu / (yp[m] * yp[m]) - 1.0) * f));
+        }
+    }
+
+    assert!(jn_zeros(3.0, 0).is_empty());
+    assert!(jn_zeros(f64::NAN, 2).iter().all(|z| z.is_nan()));
+
+    assert_eq!(try_yn_zeros(1.5, 4), Ok(yn_zeros(1.5, 4)));
+    assert_eq!(
+        try_jn_zeros(-1.0, 3),
+        Err(SpecialFunctionError::Domain("Bad args in jn_zeros"))
+    );
+    assert!(try_jnp_zeros(-0.5, 3).is_err());
+    assert!(try_ynp_zeros(-2.0, 3).is_err());
+}
+
+#[test]
+fn test_bessel_sequences() {
+    for &(n, x, expected_j, expected_y, expected_i, expected_k) in SEQUENCE_TABLE.iter() {
+        let n_max = 1000;
+        let n = n as usize;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(jn_sequence(n_max, x)[n], expected_j, max_relative = rel_eps);
+        assert_relative_eq!(yn_sequence(n_max, x)[n], expected_y, max_relative = rel_eps);
+        assert_relative_eq!(in_sequence(n_max, x)[n], expected_i, max_relative = rel_eps);
+        assert_relative_eq!(kn_sequence(n_max, x)[n], expected_k, max_relative = rel_eps);
+    }
+
+    // Integer orders agree with the single-order functions, with odd orders changing sign for x < 0.
+    for &x in [0.7, 4.0, 25.0].iter() {
+        let (j, y, i, k) = (
+            jn_sequence(40, x),
+            yn_sequence(40, x),
+            in_sequence(40, x),
+            kn_sequence(40, x),
+        );
+        let (j_neg, i_neg) = (jn_sequence(40, -x), in_sequence(40, -x));
+        for n in 0..=40u32 {
+            let m = n as usize;
+            let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+            assert_relative_eq!(j[m], Jn(n, x), epsilon = 1e-14, max_relative = 1e-12);
+            assert_relative_eq!(y[m], Yn(n, x), epsilon = 1e-14, max_relative = 1e-12);
+            assert_relative_eq!(i[m], In(n, x), max_relative = 1e-12);
+            assert_relative_eq!(k[m], Kn(n, x), max_relative = 1e-12);
+            assert_eq!(j_neg[m], sign * j[m]);
+            assert_eq!(i_neg[m], sign * i[m]);
+        }
+    }
+
+    // Fractional orders, including negative ones, agree with besseljy and besselik order by order.
+    for &nu in [0.3, 2.5, 40.1, -0.4, -3.0, -7.7].iter() {
+        for &x in [0.0, 1e-3, 3.0, 30.0, 300.0].iter() {
+            let (j, y) = besseljy_sequence(nu, 20, x);
+            let (i, k) = besselik_sequence(nu, 20, x);
+            for n in 0..=20 {
+                let order = nu + n as f64;
+                let (ej, ey, _, _) = besseljy(order, x);
+                let (ei, ek, _, _) = besselik(order, x);
+                if x == 0.0 {
+                    assert!(j[n] == ej || (j[n].is_nan() && ej.is_nan()));
+                    assert!(i[n] == ei || (i[n].is_nan() && ei.is_nan()));
+                    continue;
+                }
+                // The oscillating functions are compared relative to their envelope.
+                let scale = ej.hypot(ey);
+                assert_relative_eq!(j[n], ej, epsilon = 1e-12 * scale, max_relative = 1e-12);
+                assert_relative_eq!(y[n], ey, epsilon = 1e-12 * scale, max_relative = 1e-12);
+                assert_relative_eq!(i[n], ei, max_relative = 1e-12);
+                assert_relative_eq!(k[n], ek, max_relative = 1e-12);
+            }
+        }
+    }
+
+    // For x ≪ ν the small J_{ν+n} must not be measured against the large Y_{ν+n}.
+    for &(nu, x) in [(0.3, 1e-4), (2.0, 1e-6), (2.0, 1e-8), (7.5, 0.9)].iter() {
+        let j = besseljy_sequence(nu, 3, x).0;
+        for (n, &jn) in j.iter().enumerate() {
+            assert_relative_eq!(jn, besseljy(nu + n as f64, x).0, max_relative = 1e-14);
+        }
+    }
+    assert_relative_eq!(
+        besseljy_sequence(0.0, 3, 1e-300).0[1],
+        5e-301,
+        max_relative = 1e-15
+    );
+
+    // The slice variants fill whatever length they are given.
+    let mut j = [0.0; 5];
+    jn_sequence_into(2.0, &mut j);
+    assert_eq!(j.to_vec(), jn_sequence(4, 2.0));
+    jn_sequence_into(2.0, &mut []);
+    let (mut i, mut k) = ([0.0; 3], [0.0; 3]);
+    besselik_sequence_into(1.5, 2.0, &mut i, &mut k);
+    assert_eq!((i.to_vec(), k.to_vec()), besselik_sequence(1.5, 2, 2.0));
+}
+
+#[test]
+#[should_panic(expected = "Bad args in besseljy_sequence_into")]
+fn test_besseljy_sequence_length_mismatch() {
+    besseljy_sequence_into(0.5, 1.0, &mut [0.0; 2], &mut [0.0; 3]);
+}
+
+#[test]
+fn test_besseljy_besselik_large() {
+    // The oscillating functions are compared relative to their envelope.
+    for &(nu, x, expected_j, expected_y, expected_jp, expected_yp) in LARGE_BESSELJY_TABLE.iter() {
+        let (j, y, jp, yp) = besseljy(nu, x);
+        let scale = expected_j.hypot(expected_y);
+        let scale_p = expected_jp.hypot(expected_yp);
+        let rel_eps = 1e-12;
+        assert_relative_eq!(
+            j,
+            expected_j,
+            epsilon = rel_eps * scale,
+            max_relative = rel_eps
+        );
+        assert_relative_eq!(
+            y,
+            expected_y,
+            epsilon = rel_eps * scale,
+            max_relative = rel_eps
+        );
+        assert_relative_eq!(
+            jp,
+            expected_jp,
+            epsilon = rel_eps * scale_p,
+            max_relative = rel_eps
+        );
+        assert_relative_eq!(
+            yp,
+            expected_yp,
+            epsilon = rel_eps * scale_p,
+            max_relative = rel_eps
+        );
+    }
+
+    for &(nu, x, expected_i, expected_k, expected_ip, expected_kp) in
+        LARGE_BESSELIK_SCALED_TABLE.iter()
+    {
+        let (i, k, ip, kp) = besselik_scaled(nu, x);
+        let rel_eps = 1e-12;
+        assert_relative_eq!(i, expected_i, max_relative = rel_eps);
+        assert_relative_eq!(k, expected_k, max_relative = rel_eps);
+        assert_relative_eq!(ip, expected_ip, max_relative = rel_eps);
+        assert_relative_eq!(kp, expected_kp, max_relative = rel_eps);
+    }
+
+    // Agreement of the unscaled functions where these neither overflow nor underflow
+    for &(nu, x) in [(30.5, 60.0), (45.0, 35.0), (200.0, 150.0), (0.7, 500.0)].iter() {
+        let (i, k, ip, kp) = besselik(nu, x);
+        let (is, ks, ips, kps) = besselik_scaled(nu, x);
+        assert_relative_eq!(i, x.exp() * is, max_relative = 1e-13);
+        assert_relative_eq!(k, (-x).exp() * ks, max_relative = 1e-13);
+        assert_relative_eq!(ip, x.exp() * ips, max_relative = 1e-13);
+        assert_relative_eq!(kp, (-x).exp() * kps, max_relative = 1e-13);
+    }
+}
+
+#[test]
+fn test_besseljy_besselik_negative_order() {
+    for &(nu, x, expected_j, expected_y, expected_jp, expected_yp) in NEG_BESSELJY_TABLE.iter() {
+        let (j, y, jp, yp) = besseljy(nu, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(j, expected_j, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(y, expected_y, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(jp, expected_jp, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(yp, expected_yp, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    for &(nu, x, expected_i, expected_k, expected_ip, expected_kp) in NEG_BESSELIK_TABLE.iter() {
+        let (i, k, ip, kp) = besselik(nu, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(i, expected_i, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(k, expected_k, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(ip, expected_ip, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(kp, expected_kp, epsilon = abs_eps, max_relative = rel_eps);
+    }
+
+    // Integer orders: J_{-n} = (-1)^n J_n, Y_{-n} = (-1)^n Y_n and I_{-n} = I_n
+    for &x in [0.1, 2.0, 15.0].iter() {
+        let (j, y, jp, yp) = besseljy(3.0, x);
+        assert_eq!(besseljy(-3.0, x), (-j, -y, -jp, -yp));
+        assert_eq!(besselik(-4.0, x), besselik(4.0, x));
+        assert_eq!(Jnu_Ynu(-2.0, x), Jnu_Ynu(2.0, x));
+        assert_eq!(Inu_Knu(-2.5, x).1, Inu_Knu(2.5, x).1);
+    }
+}
+
+#[test]
+fn test_bessel_at_zero() {
+    let inf = f64::INFINITY;
+    assert_eq!(besseljy(0.0, 0.0), (1.0, -inf, 0.0, inf));
+    assert_eq!(besseljy(0.4, 0.0), (0.0, -inf, inf, inf));
+    assert_eq!(besseljy(1.0, 0.0), (0.0, -inf, 0.5, inf));
+    assert_eq!(besseljy(2.5, 0.0), (0.0, -inf, 0.0, inf));
+    assert_eq!(besselik(0.0, 0.0), (1.0, inf, 0.0, -inf));
+    assert_eq!(besselik(1.0, 0.0), (0.0, inf, 0.5, -inf));
+    assert_eq!(besselik(3.2, 0.0), (0.0, inf, 0.0, -inf));
+
+    // J_{-ν}(x) ~ (x/2)^(-ν) / Γ(1-ν) and Y_{-ν}(x) ~ -cot(νπ) J_{-ν}(x)
+    assert_eq!(besseljy(-0.3, 0.0), (inf, -inf, -inf, inf));
+    assert_eq!(besseljy(-0.7, 0.0), (inf, inf, -inf, -inf));
+    assert_eq!(besseljy(-2.3, 0.0), (inf, -inf, -inf, inf));
+    assert_eq!(besseljy(-1.0, 0.0), (0.0, inf, -0.5, -inf));
+    // J_{-1/2}(x) = √(2/πx) cos(x) and Y_{-1/2}(x) = √(2/πx) sin(x)
+    assert_eq!(besseljy(-0.5, 0.0), (inf, 0.0, -inf, inf));
+    assert_eq!(besselik(-0.3, 0.0), (inf, inf, -inf, -inf));
+    assert_eq!(besselik(-1.5, 0.0), (-inf, inf, inf, -inf));
+    assert_eq!(besselik(-2.0, 0.0), (0.0, inf, 0.0, -inf));
+
+    assert_eq!(Jnu_Ynu(1.5, 0.0), (0.0, -inf));
+    assert_eq!(Inu_Knu(0.0, 0.0), (1.0, inf));
+    assert_eq!(Yn(0, 0.0), -inf);
+    assert_eq!(Yn(3, 0.0), -inf);
+    assert_eq!(Kn(0, 0.0), inf);
+    assert_eq!(Kn(3, 0.0), inf);
+    assert!(Yn(2, -1.0).is_nan());
+    assert!(Kn(2, -10.0).is_nan());
+    assert!(besseljy(f64::NAN, 1.0).0.is_nan());
+    assert!(besselik(1.0, f64::NAN).1.is_nan());
+}
+
+#[test]
+fn in_scaled_test() {
+    for &(n, x, ans) in IN_SCALED_TABLE.iter() {
+        let result = In_scaled(n, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, ans, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn kn_scaled_test() {
+    for &(n, x, ans) in KN_SCALED_TABLE.iter() {
+        let result = Kn_scaled(n, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, ans, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_besselik_scaled() {
+    for &(nu, x, expected_i, expected_k, expected_ip, expected_kp) in BESSELIK_SCALED_TABLE.iter() {
+        let (i, k, ip, kp) = besselik_scaled(nu, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(i, expected_i, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(k, expected_k, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(ip, expected_ip, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(kp, expected_kp, epsilon = abs_eps, max_relative = rel_eps);
+    }
+
+    // Agreement with the unscaled functions where these neither overflow nor underflow
+    for &x in [0.3, 1.5, 9.0, 40.0, 300.0].iter() {
+        for &n in [0, 1, 4].iter() {
+            assert_relative_eq!(In_scaled(n, x), (-x).exp() * In(n, x), max_relative = 1e-14);
+            assert_relative_eq!(Kn_scaled(n, x), x.exp() * Kn(n, x), max_relative = 1e-14);
+        }
+        for &nu in [-0.6, 0.0, 1.3, 6.0].iter() {
+            let (i, k, ip, kp) = besselik(nu, x);
+            let (is, ks, ips, kps) = besselik_scaled(nu, x);
+            assert_relative_eq!(is, (-x).exp() * i, max_relative = 1e-14);
+            assert_relative_eq!(ks, x.exp() * k, max_relative = 1e-14);
+            assert_relative_eq!(ips, (-x).exp() * ip, max_relative = 1e-14);
+            assert_relative_eq!(kps, x.exp() * kp, max_relative = 1e-14);
+        }
+    }
+
+    assert_relative_eq!(In_scaled(0, 0.0), 1.0, max_relative = 1e-15);
+    assert_eq!(In_scaled(3, 0.0), 0.0);
+    assert_eq!(Kn_scaled(2, 0.0), f64::INFINITY);
+    assert!(Kn_scaled(2, -1.0).is_nan());
+    assert_eq!(
+        besselik_scaled(1.0, 0.0),
+        (0.0, f64::INFINITY, 0.5, f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        try_besselik_scaled(1.0, -1.0),
+        Err(SpecialFunctionError::Domain("bad arguments in besselik"))
+    );
+    assert!(try_besselik(1.0, 1e4).is_err());
+    assert!(try_besselik_scaled(1.0, 1e4).is_ok());
+    assert_eq!(
+        try_besselik_scaled(300.0, 1.0),
+        Err(SpecialFunctionError::Overflow(
+            "Overflow in besselik_scaled"
+        ))
+    );
+    assert_eq!(
+        try_besselik_scaled(242.0, 10.0),
+        Err(SpecialFunctionError::Underflow(
+            "Underflow in besselik_scaled"
+        ))
+    );
+}
+
 #[test]
 fn test_cached_besselik() {
     const ABS_EPS: f64 = f64::EPSILON;
@@ -185,6 +592,257 @@ fn test_cached_besselik() {
     }
 }
 
+#[test]
+fn spherical_jn_test() {
+    check_spherical(spherical_jn, &SPHERICAL_JN_TABLE);
+}
+
+#[test]
+fn spherical_yn_test() {
+    check_spherical(spherical_yn, &SPHERICAL_YN_TABLE);
+}
+
+#[test]
+fn spherical_in_test() {
+    check_spherical(spherical_in, &SPHERICAL_IN_TABLE);
+}
+
+#[test]
+fn spherical_kn_test() {
+    check_spherical(spherical_kn, &SPHERICAL_KN_TABLE);
+}
+
+fn check_spherical(f: fn(u32, f64) -> (f64, f64), table: &[(u32, f64, f64, f64)]) {
+    for &(n, x, expected, expected_p) in table.iter() {
+        let (value, derivative) = f(n, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(value, expected, epsilon = abs_eps, max_relative = rel_eps);
+        assert_relative_eq!(
+            derivative,
+            expected_p,
+            epsilon = abs_eps,
+            max_relative = rel_eps
+        );
+    }
+}
+
+#[test]
+fn test_spherical_bessel_edge_cases() {
+    // Limits at x = 0
+    assert_eq!(spherical_jn(0, 0.0), (1.0, 0.0));
+    assert_eq!(spherical_jn(1, 0.0), (0.0, 1.0 / 3.0));
+    assert_eq!(spherical_jn(4, 0.0), (0.0, 0.0));
+    assert_eq!(spherical_in(0, 0.0), (1.0, 0.0));
+    assert_eq!(spherical_in(1, 0.0), (0.0, 1.0 / 3.0));
+    assert_eq!(spherical_yn(2, 0.0), (f64::NEG_INFINITY, f64::INFINITY));
+    assert_eq!(spherical_kn(2, 0.0), (f64::INFINITY, f64::NEG_INFINITY));
+
+    // Small x: j_n(x) ≈ x^n / (2n+1)!!
+    assert_relative_eq!(
+        spherical_jn(3, 1e-100).0,
+        1e-300 / 105.0,
+        max_relative = 1e-15
+    );
+    assert_relative_eq!(
+        spherical_in(3, 1e-100).0,
+        1e-300 / 105.0,
+        max_relative = 1e-15
+    );
+
+    // Parity
+    for &n in [0, 1, 4, 7].iter() {
+        let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+        let (j, jp) = spherical_jn(n, 3.7);
+        assert_eq!(spherical_jn(n, -3.7), (sign * j, -sign * jp));
+        let (y, yp) = spherical_yn(n, 3.7);
+        assert_eq!(spherical_yn(n, -3.7), (-sign * y, sign * yp));
+        let (i, ip) = spherical_in(n, 3.7);
+        assert_eq!(spherical_in(n, -3.7), (sign * i, -sign * ip));
+    }
+
+    // Wronskians j_n y_n' - j_n' y_n = 1/x^2 and i_n k_n' - i_n' k_n = -π/(2x^2),
+    // and the relation to the Bessel functions of half-integer order
+    for &(n, x) in [(0, 0.5), (3, 2.0), (12, 5.0), (40, 60.0)].iter() {
+        let (j, jp) = spherical_jn(n, x);
+        let (y, yp) = spherical_yn(n, x);
+        assert_relative_eq!(j * yp - jp * y, 1.0 / (x * x), max_relative = 1e-13);
+        let (i, ip) = spherical_in(n, x);
+        let (k, kp) = spherical_kn(n, x);
+        assert_relative_eq!(
+            i * kp - ip * k,
+            -std::f64::consts::FRAC_PI_2 / (x * x),
+            max_relative = 1e-13
+        );
+        let (jnu, ynu) = Jnu_Ynu(n as f64 + 0.5, x);
+        let factor = (std::f64::consts::FRAC_PI_2 / x).sqrt();
+        assert_relative_eq!(j, factor * jnu, max_relative = 1e-12);
+        assert_relative_eq!(y, factor * ynu, max_relative = 1e-12);
+    }
+
+    assert_eq!(spherical_jn(3, f64::INFINITY), (0.0, 0.0));
+    assert!(spherical_yn(3, f64::NAN).0.is_nan());
+}
+
+#[test]
+fn test_spherical_kn_out_of_domain() {
+    for &x in [-1.0, -f64::MIN_POSITIVE, f64::NEG_INFINITY, f64::NAN].iter() {
+        let (k, kp) = spherical_kn(1, x);
+        assert!(k.is_nan() && kp.is_nan());
+    }
+}
+
+#[test]
+fn complex_besselj_test() {
+    check_complex(
+        complex_besselj,
+        complex_besselj_scaled,
+        &COMPLEX_BESSELJ_TABLE,
+    );
+}
+
+#[test]
+fn complex_bessely_test() {
+    check_complex(
+        complex_bessely,
+        complex_bessely_scaled,
+        &COMPLEX_BESSELY_TABLE,
+    );
+}
+
+#[test]
+fn complex_besseli_test() {
+    check_complex(
+        complex_besseli,
+        complex_besseli_scaled,
+        &COMPLEX_BESSELI_TABLE,
+    );
+}
+
+#[test]
+fn complex_besselk_test() {
+    check_complex(
+        complex_besselk,
+        complex_besselk_scaled,
+        &COMPLEX_BESSELK_TABLE,
+    );
+}
+
+#[test]
+fn complex_hankel1_test() {
+    check_complex(
+        complex_hankel1,
+        complex_hankel1_scaled,
+        &COMPLEX_HANKEL1_TABLE,
+    );
+}
+
+#[test]
+fn complex_hankel2_test() {
+    check_complex(
+        complex_hankel2,
+        complex_hankel2_scaled,
+        &COMPLEX_HANKEL2_TABLE,
+    );
+}
+
+fn check_complex(
+    f: fn(f64, Complex64) -> Complex64,
+    f_scaled: fn(f64, Complex64) -> Complex64,
+    table: &[(f64, f64, f64, f64, f64, f64, f64)],
+) {
+    for &(nu, re, im, y_re, y_im, ys_re, ys_im) in table.iter() {
+        let z = Complex64::new(re, im);
+        let rel_eps = 1e-13;
+        let expected = Complex64::new(y_re, y_im);
+        let expected_scaled = Complex64::new(ys_re, ys_im);
+        assert!(
+            (f(nu, z) - expected).norm() <= rel_eps * expected.norm(),
+            "nu = {}, z = {}: {} != {}",
+            nu,
+            z,
+            f(nu, z),
+            expected
+        );
+        assert!(
+            (f_scaled(nu, z) - expected_scaled).norm() <= rel_eps * expected_scaled.norm(),
+            "nu = {}, z = {}: {} != {}",
+            nu,
+            z,
+            f_scaled(nu, z),
+            expected_scaled
+        );
+    }
+}
+
+#[test]
+fn test_complex_bessel_edge_cases() {
+    let zero = Complex64::new(0.0, 0.0);
+    assert_eq!(complex_besselj(0.0, zero), Complex64::new(1.0, 0.0));
+    assert_eq!(complex_besselj(2.5, zero), zero);
+    assert_eq!(complex_besseli(0.0, zero), Complex64::new(1.0, 0.0));
+    assert_eq!(complex_bessely(1.0, zero).re, f64::NEG_INFINITY);
+    assert_eq!(complex_besselk(1.0, zero).re, f64::INFINITY);
+    assert_eq!(complex_hankel1(0.0, zero).im, f64::NEG_INFINITY);
+    assert_eq!(complex_hankel2(0.0, zero).im, f64::INFINITY);
+    let nan = complex_besselk(1.0, Complex64::new(f64::NAN, 1.0));
+    assert!(nan.re.is_nan() && nan.im.is_nan());
+
+    for &nu in [0.0, 0.4, 1.0, 3.5, 12.0].iter() {
+        // Agreement with the real-argument functions on the positive real axis
+        for &x in [0.3, 2.0, 9.0, 50.0].iter() {
+            let z = Complex64::new(x, 0.0);
+            let (j, y, _, _) = besseljy(nu, x);
+            let (i, k, _, _) = besselik(nu, x);
+            assert_relative_eq!(complex_besselj(nu, z).re, j, max_relative = 1e-12);
+            assert_relative_eq!(complex_bessely(nu, z).re, y, max_relative = 1e-12);
+            assert_relative_eq!(complex_besseli(nu, z).re, i, max_relative = 1e-12);
+            assert_relative_eq!(complex_besselk(nu, z).re, k, max_relative = 1e-12);
+            assert_eq!(complex_besselj(nu, z).im, 0.0);
+            assert_eq!(complex_besselk(nu, z).im, 0.0);
+        }
+
+        for &z in [
+            Complex64::new(0.7, 0.2),
+            Complex64::new(-4.0, 3.0),
+            Complex64::new(-2.0, -8.0),
+            Complex64::new(25.0, -1.0),
+        ]
+        .iter()
+        {
+            // J = (H1 + H2) / 2 and Y = (H1 - H2) / 2i
+            let h1 = complex_hankel1(nu, z);
+            let h2 = complex_hankel2(nu, z);
+            let j = complex_besselj(nu, z);
+            let y = complex_bessely(nu, z);
+            let scale = h1.norm().max(h2.norm());
+            assert!((j - 0.5 * (h1 + h2)).norm() <= 1e-13 * scale);
+            assert!((y - (h1 - h2) / Complex64::new(0.0, 2.0)).norm() <= 1e-13 * scale);
+
+            // Conjugate symmetry off the branch cut
+            let jc = complex_besselj(nu, z.conj());
+            assert!((jc - j.conj()).norm() <= 1e-14 * j.norm());
+            let kc = complex_besselk(nu, z.conj());
+            let k = complex_besselk(nu, z);
+            assert!((kc - k.conj()).norm() <= 1e-14 * k.norm());
+
+            // I_ν(z) = e^(-iνπ/2) J_ν(iz) for -π < arg z ≤ π/2
+            if z.arg() <= std::f64::consts::FRAC_PI_2 {
+                let i = complex_besseli(nu, z);
+                let rotated = Complex64::from_polar(1.0, -nu * std::f64::consts::FRAC_PI_2)
+                    * complex_besselj(nu, Complex64::new(-z.im, z.re));
+                assert!((i - rotated).norm() <= 1e-13 * i.norm());
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Bad args in complex_besselj")]
+fn test_complex_besselj_negative_order() {
+    complex_besselj(-1.0, Complex64::new(1.0, 1.0));
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Tables from scripts/bessel_test.py
 // └─────────────────────────────────────────────────────────┘
@@ -1052,3 +1710,2581 @@ const BESSELIK_TABLE: [(f64, f64, f64, f64, f64, f64); 40] = [
         -1.04391615737081e-22,
     ),
 ];
+
+const NEG_BESSELJY_TABLE: [(f64, f64, f64, f64, f64, f64); 20] = [
+    (
+        -0.3,
+        0.5,
+        1.06532695371918e+00,
+        9.15642276445487e-02,
+        -1.04107014706892e+00,
+        1.10568380597409e+00,
+    ),
+    (
+        -0.3,
+        1.5,
+        2.69149221025787e-01,
+        5.84358336698584e-01,
+        -6.85397933112785e-01,
+        8.87804368033594e-02,
+    ),
+    (
+        -0.3,
+        4.0,
+        -3.44459534063007e-01,
+        -1.99425513451468e-01,
+        2.43034846756840e-01,
+        -3.21337002013615e-01,
+    ),
+    (
+        -0.3,
+        12.0,
+        1.45437493368033e-01,
+        -1.78522913418744e-01,
+        1.72567765325867e-01,
+        1.52947819396699e-01,
+    ),
+    (
+        -1.0,
+        0.5,
+        -2.42268457674874e-01,
+        1.47147239267024e+00,
+        -4.53932891891065e-01,
+        -2.49842605183378e+00,
+    ),
+    (
+        -1.0,
+        1.5,
+        -5.57936507910100e-01,
+        4.12308626973911e-01,
+        -1.39869999795852e-01,
+        -6.57321341780366e-01,
+    ),
+    (
+        -1.0,
+        4.0,
+        6.60433280235491e-02,
+        -3.97925710557100e-01,
+        3.80638977857960e-01,
+        1.16422166964340e-01,
+    ),
+    (
+        -1.0,
+        12.0,
+        2.23447104490628e-01,
+        5.70992182608965e-02,
+        -6.63099028377192e-02,
+        2.20479044445953e-01,
+    ),
+    (
+        -1.5,
+        0.5,
+        -2.52146555042134e+00,
+        -9.17016996256513e-02,
+        6.57415077102061e+00,
+        -2.65868691057574e-01,
+    ),
+    (
+        -1.5,
+        1.5,
+        -6.80560185349146e-01,
+        -3.87142217276067e-01,
+        6.34477019456048e-01,
+        -2.62695857477680e-01,
+    ),
+    (
+        -1.5,
+        4.0,
+        3.67112032460934e-01,
+        -1.85285948354269e-01,
+        1.23099064504329e-01,
+        3.71402743924490e-01,
+    ),
+    (
+        -1.5,
+        12.0,
+        1.07391502303147e-01,
+        2.04663448496530e-01,
+        -2.07788341621428e-01,
+        9.80056048938757e-02,
+    ),
+    (
+        -2.7,
+        0.5,
+        1.74393380888685e+01,
+        1.26773220222398e+01,
+        -9.15044719016012e+01,
+        -6.64450916268719e+01,
+    ),
+    (
+        -2.7,
+        1.5,
+        1.20164898214869e+00,
+        9.89829976800448e-01,
+        -1.72320407043340e+00,
+        -1.06625635482176e+00,
+    ),
+    (
+        -2.7,
+        4.0,
+        -2.07794615180708e-01,
+        3.99540756297729e-01,
+        -2.74085607443004e-01,
+        -2.38921360693684e-01,
+    ),
+    (
+        -2.7,
+        12.0,
+        -2.33002454621071e-01,
+        -9.62094126359027e-03,
+        1.95814343203451e-02,
+        -2.26878536339098e-01,
+    ),
+    (
+        -6.0,
+        0.5,
+        3.36068462861885e-07,
+        -1.58426757015330e+05,
+        4.02080568701486e-06,
+        1.89317478270515e+06,
+    ),
+    (
+        -6.0,
+        1.5,
+        2.28012695393612e-04,
+        -2.40573417466841e+02,
+        8.87370985786162e-04,
+        9.25103361471865e+02,
+    ),
+    (
+        -6.0,
+        4.0,
+        4.90875751563856e-02,
+        -1.50069178425166e+00,
+        5.84552933125199e-02,
+        1.45518625526329e+00,
+    ),
+    (
+        -6.0,
+        12.0,
+        -2.43724767228867e-01,
+        -4.02972511033958e-02,
+        4.83914205127747e-02,
+        -2.09669321073385e-01,
+    ),
+];
+
+const NEG_BESSELIK_TABLE: [(f64, f64, f64, f64, f64, f64); 20] = [
+    (
+        -0.3,
+        0.5,
+        1.27387127145143e+00,
+        9.76474124381788e-01,
+        -3.31782857046076e-01,
+        -1.82434240170205e+00,
+    ),
+    (
+        -0.3,
+        1.5,
+        1.63438775502046e+00,
+        2.18937954732173e-01,
+        9.03299306740424e-01,
+        -2.86896522870767e-01,
+    ),
+    (
+        -0.3,
+        4.0,
+        1.11547232979700e+01,
+        1.12731687602682e-02,
+        9.67295765941449e+00,
+        -1.26363614882445e-02,
+    ),
+    (
+        -0.3,
+        12.0,
+        1.88747450806053e+04,
+        2.20877607273359e-06,
+        1.80768006572970e+04,
+        -2.29967230733741e-06,
+    ),
+    (
+        -1.0,
+        0.5,
+        2.57894305390896e-01,
+        1.65644112000330e+00,
+        5.47694759959531e-01,
+        -4.23730131123427e+00,
+    ),
+    (
+        -1.0,
+        1.5,
+        9.81666428577908e-01,
+        2.77387800456844e-01,
+        9.92278904054286e-01,
+        -3.98730762952088e-01,
+    ),
+    (
+        -1.0,
+        4.0,
+        9.75946515370445e+00,
+        1.24834988872684e-02,
+        8.86205566371022e+00,
+        -1.42805508076701e-02,
+    ),
+    (
+        -1.0,
+        12.0,
+        1.81413487816388e+04,
+        2.29075746476719e-06,
+        1.74371462841597e+04,
+        -2.39172185270876e-06,
+    ),
+    (
+        -1.5,
+        0.5,
+        -1.95678620803928e+00,
+        3.22514281049976e+00,
+        7.14274827153270e+00,
+        -1.07504760349992e+01,
+    ),
+    (
+        -1.5,
+        1.5,
+        3.65478834152427e-01,
+        3.80558420380442e-01,
+        1.16704549522415e+00,
+        -6.08893472608708e-01,
+    ),
+    (
+        -1.5,
+        4.0,
+        8.16349962825499e+00,
+        1.43470307207601e-02,
+        7.83309632073808e+00,
+        -1.68577610968931e-02,
+    ),
+    (
+        -1.5,
+        12.0,
+        1.71816419595286e+04,
+        2.40822820720121e-06,
+        1.65959041669976e+04,
+        -2.52400840947050e-06,
+    ),
+    (
+        -2.7,
+        0.5,
+        1.62081556038944e+01,
+        3.14587209043387e+01,
+        -8.97501821750179e+01,
+        -1.74321249203615e+02,
+    ),
+    (
+        -2.7,
+        1.5,
+        7.73762055448108e-01,
+        1.25367874752162e+00,
+        -1.13617256463943e+00,
+        -2.70246137006714e+00,
+    ),
+    (
+        -2.7,
+        4.0,
+        4.16745631070937e+00,
+        2.48808522422416e-02,
+        4.65541207624776e+00,
+        -3.21945498647098e-02,
+    ),
+    (
+        -2.7,
+        12.0,
+        1.38139614143330e+04,
+        2.94446443050380e-06,
+        1.36025923446048e+04,
+        -3.13313340857417e-06,
+    ),
+    (
+        -6.0,
+        0.5,
+        3.42123592638258e-07,
+        2.42711834619838e+05,
+        4.11768820145017e-06,
+        -2.92463999491416e+06,
+    ),
+    (
+        -6.0,
+        1.5,
+        2.67769143994476e-04,
+        3.01704078501829e+02,
+        1.09948299311965e-03,
+        -1.25088409516662e+03,
+    ),
+    (
+        -6.0,
+        4.0,
+        1.54464799870673e-01,
+        4.48085183969614e-01,
+        2.73027163307157e-01,
+        -8.26470324680418e-01,
+    ),
+    (
+        -6.0,
+        12.0,
+        4.09757688420444e+03,
+        9.09503274538381e-06,
+        4.44482413450159e+03,
+        -1.04714355569437e-05,
+    ),
+];
+
+const IN_SCALED_TABLE: [(u32, f64, f64); 40] = [
+    (0, 1.00000000000000e-02, 9.90074585149707e-01),
+    (0, 1.00000000000000e+00, 4.65759607593640e-01),
+    (0, 1.40000000000000e+01, 1.07615251670695e-01),
+    (0, 1.60000000000000e+01, 1.00544127361252e-01),
+    (0, 5.00000000000000e+01, 5.65616266474542e-02),
+    (0, 8.00000000000000e+02, 1.41069450058692e-02),
+    (0, 1.00000000000000e+04, 3.98947267460473e-03),
+    (0, 1.00000000000000e+06, 3.98942330269246e-04),
+    (1, 1.00000000000000e-02, 4.95031104711828e-03),
+    (1, 1.00000000000000e+00, 2.07910415349708e-01),
+    (1, 1.40000000000000e+01, 1.03697667463143e-01),
+    (1, 1.60000000000000e+01, 9.73496147564681e-02),
+    (1, 5.00000000000000e+01, 5.59931238928954e-02),
+    (1, 8.00000000000000e+02, 1.40981254065270e-02),
+    (1, 1.00000000000000e+04, 3.98927319598366e-03),
+    (1, 1.00000000000000e+06, 3.98942130798031e-04),
+    (2, 1.00000000000000e-02, 1.23757260523779e-05),
+    (2, 1.00000000000000e+00, 4.99387768942235e-02),
+    (2, 1.40000000000000e+01, 9.28012991759604e-02),
+    (2, 1.60000000000000e+01, 8.83754255166935e-02),
+    (2, 5.00000000000000e+01, 5.43219016917384e-02),
+    (2, 8.00000000000000e+02, 1.40716996923529e-02),
+    (2, 1.00000000000000e+04, 3.98867481996554e-03),
+    (2, 1.00000000000000e+06, 3.98941532384984e-04),
+    (5, 1.00000000000000e-02, 2.57826551813587e-14),
+    (5, 1.00000000000000e+00, 9.98657141120869e-05),
+    (5, 1.40000000000000e+01, 4.30556585096401e-02),
+    (5, 1.60000000000000e+01, 4.51785003146907e-02),
+    (5, 5.00000000000000e+01, 4.39474970246233e-02),
+    (5, 8.00000000000000e+02, 1.38881019936598e-02),
+    (5, 1.00000000000000e+04, 3.98448870028501e-03),
+    (5, 1.00000000000000e+06, 3.98937343518791e-04),
+    (30, 1.00000000000000e-02, 3.47614161223897e-102),
+    (30, 1.00000000000000e+00, 1.30210949837859e-42),
+    (30, 1.40000000000000e+01, 3.30876847458821e-13),
+    (30, 1.60000000000000e+01, 3.89207784683583e-12),
+    (30, 5.00000000000000e+01, 8.24539335208997e-06),
+    (30, 8.00000000000000e+02, 8.03559725593544e-03),
+    (30, 1.00000000000000e+04, 3.81391737726882e-03),
+    (30, 1.00000000000000e+06, 3.98762846517769e-04),
+];
+
+const KN_SCALED_TABLE: [(u32, f64, f64); 40] = [
+    (0, 1.00000000000000e-02, 4.76869402854446e+00),
+    (0, 1.00000000000000e+00, 1.14446307980690e+00),
+    (0, 1.40000000000000e+01, 3.32083638308792e-01),
+    (0, 1.60000000000000e+01, 3.10961588024941e-01),
+    (0, 5.00000000000000e+01, 1.76807155857429e-01),
+    (0, 8.00000000000000e+02, 4.43044274866460e-02),
+    (0, 1.00000000000000e+04, 1.25329847176993e-02),
+    (0, 1.00000000000000e+06, 1.25331398065132e-03),
+    (1, 1.00000000000000e-02, 1.00978648458240e+02),
+    (1, 1.00000000000000e+00, 1.63615348626326e+00),
+    (1, 1.40000000000000e+01, 3.43745632324244e-01),
+    (1, 1.60000000000000e+01, 3.20535968111973e-01),
+    (1, 5.00000000000000e+01, 1.78566558558816e-01),
+    (1, 8.00000000000000e+02, 4.43321091114121e-02),
+    (1, 1.00000000000000e+04, 1.25336113512705e-02),
+    (1, 1.00000000000000e+06, 1.25331460730815e-03),
+    (2, 1.00000000000000e-02, 2.02004983856766e+04),
+    (2, 1.00000000000000e+00, 4.41677005233341e+00),
+    (2, 1.40000000000000e+01, 3.81190157212255e-01),
+    (2, 1.60000000000000e+01, 3.51028584038937e-01),
+    (2, 5.00000000000000e+01, 1.83949818199782e-01),
+    (2, 8.00000000000000e+02, 4.44152577594245e-02),
+    (2, 1.00000000000000e+04, 1.25354914399695e-02),
+    (2, 1.00000000000000e+06, 1.25331648728054e-03),
+    (5, 1.00000000000000e-02, 3.87856840050020e+12),
+    (5, 1.00000000000000e+00, 9.81192611502916e+02),
+    (5, 1.40000000000000e+01, 7.81334854215596e-01),
+    (5, 1.60000000000000e+01, 6.60362365038997e-01),
+    (5, 5.00000000000000e+01, 2.26425539771847e-01),
+    (5, 8.00000000000000e+02, 4.50016795417085e-02),
+    (5, 1.00000000000000e+04, 1.25486599595387e-02),
+    (5, 1.00000000000000e+06, 1.25332964716616e-03),
+    (30, 1.00000000000000e-02, 4.79458767762224e+99),
+    (30, 1.00000000000000e+00, 1.27926298675398e+40),
+    (30, 1.40000000000000e+01, 4.56426496355202e+10),
+    (30, 1.60000000000000e+01, 3.77815266300601e+09),
+    (30, 5.00000000000000e+01, 1.03995695270309e+03),
+    (30, 8.00000000000000e+02, 7.77242948737057e-02),
+    (30, 1.00000000000000e+04, 1.31098212462641e-02),
+    (30, 1.00000000000000e+06, 1.25387809857753e-03),
+];
+
+const BESSELIK_SCALED_TABLE: [(f64, f64, f64, f64, f64, f64); 32] = [
+    (
+        -1.5,
+        1.00000000000000e-02,
+        -7.89905978512629e+02,
+        1.26584727868866e+03,
+        1.18493796626638e+05,
+        -1.89889624944671e+05,
+    ),
+    (
+        -1.5,
+        1.00000000000000e+00,
+        -1.07981933026376e-01,
+        2.50662827463100e+00,
+        6.14906146454185e-01,
+        -5.01325654926200e+00,
+    ),
+    (
+        -1.5,
+        1.40000000000000e+01,
+        9.90059657891353e-02,
+        3.58888170905721e-01,
+        9.60140272626993e-02,
+        -3.73414596870952e-01,
+    ),
+    (
+        -1.5,
+        1.60000000000000e+01,
+        9.35020969690844e-02,
+        3.32911567724430e-01,
+        9.09697485095078e-02,
+        -3.44538993803040e-01,
+    ),
+    (
+        -1.5,
+        5.00000000000000e+01,
+        5.52905791876801e-02,
+        1.80790292792363e-01,
+        5.47602409791452e-02,
+        -1.82669093874322e-01,
+    ),
+    (
+        -1.5,
+        8.00000000000000e+02,
+        1.40871086642080e-02,
+        4.43667354554787e-02,
+        1.40783262599485e-02,
+        -4.43945339016169e-02,
+    ),
+    (
+        -1.5,
+        1.00000000000000e+04,
+        3.98902386173393e-03,
+        1.25343946872923e-02,
+        3.98882445043507e-03,
+        -1.25350215323581e-02,
+    ),
+    (
+        -1.5,
+        1.00000000000000e+06,
+        3.98941881459152e-04,
+        1.25331539062964e-03,
+        3.98941681988610e-04,
+        -1.25331601728859e-03,
+    ),
+    (
+        0.3,
+        1.00000000000000e-02,
+        2.25079598153633e-01,
+        6.95934932103468e+00,
+        6.75325362813298e+00,
+        -2.35480022992134e+02,
+    ),
+    (
+        0.3,
+        1.00000000000000e+00,
+        4.00545277394590e-01,
+        1.18265925060499e+00,
+        2.62677264812387e-01,
+        -1.72100968791830e+00,
+    ),
+    (
+        0.3,
+        1.40000000000000e+01,
+        1.07256520769650e-01,
+        3.33117152677428e-01,
+        1.03378619255539e-01,
+        -3.44887004249252e-01,
+    ),
+    (
+        0.3,
+        1.60000000000000e+01,
+        1.00252276326190e-01,
+        3.11811660178092e-01,
+        9.70858883609566e-02,
+        -3.21463902380102e-01,
+    ),
+    (
+        0.3,
+        5.00000000000000e+01,
+        5.65102242605010e-02,
+        1.76964794223575e-01,
+        5.59432763620460e-02,
+        -1.78728889197801e-01,
+    ),
+    (
+        0.3,
+        8.00000000000000e+02,
+        1.41061510159468e-02,
+        4.43069181251953e-02,
+        1.40973329060847e-02,
+        -4.43346044175685e-02,
+    ),
+    (
+        0.3,
+        1.00000000000000e+04,
+        3.98945472112037e-03,
+        1.25330411134378e-02,
+        3.98925524519243e-03,
+        -1.25336677554680e-02,
+    ),
+    (
+        0.3,
+        1.00000000000000e+06,
+        3.98942312316832e-04,
+        1.25331403705042e-03,
+        3.98942112845644e-04,
+        -1.25331466370734e-03,
+    ),
+    (
+        2.5,
+        1.00000000000000e-02,
+        5.26634079504846e-07,
+        3.79766716747970e+05,
+        1.31659272209417e-04,
+        -9.49429450342711e+07,
+    ),
+    (
+        2.5,
+        1.00000000000000e+00,
+        2.10055148091163e-02,
+        8.77319896120850e+00,
+        5.54681460035853e-02,
+        -2.44396256776523e+01,
+    ),
+    (
+        2.5,
+        1.40000000000000e+01,
+        8.54062452136821e-02,
+        4.11866900896565e-01,
+        8.37548505725643e-02,
+        -4.32435831780107e-01,
+    ),
+    (
+        2.5,
+        1.60000000000000e+01,
+        8.22039269186531e-02,
+        3.75749453277206e-01,
+        8.06577333880476e-02,
+        -3.91622419798993e-01,
+    ),
+    (
+        2.5,
+        5.00000000000000e+01,
+        5.31015236035148e-02,
+        1.88092802658093e-01,
+        5.26355030075044e-02,
+        -1.90194932925267e-01,
+    ),
+    (
+        2.5,
+        8.00000000000000e+02,
+        1.40519129312031e-02,
+        4.44777215305959e-02,
+        1.40431964362980e-02,
+        -4.45057283352618e-02,
+    ),
+    (
+        2.5,
+        1.00000000000000e+04,
+        3.98822609685581e-03,
+        1.25369016915612e-02,
+        3.98802680520971e-03,
+        -1.25375289127152e-02,
+    ),
+    (
+        2.5,
+        1.00000000000000e+06,
+        3.98941083575788e-04,
+        1.25331789726167e-03,
+        3.98940884106443e-04,
+        -1.25331852392438e-03,
+    ),
+    (
+        10.5,
+        1.00000000000000e-02,
+        5.74535857444909e-32,
+        8.28826002909916e+29,
+        6.03262900115310e-29,
+        -8.70267739279489e+32,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        2.18171315217150e-11,
+        2.17272546827525e+09,
+        2.30026807518007e-10,
+        -2.29276198120593e+10,
+    ),
+    (
+        10.5,
+        1.40000000000000e+01,
+        2.18674246223622e-03,
+        1.30630075754349e+01,
+        2.68407504045173e-03,
+        -1.66304352113441e+01,
+    ),
+    (
+        10.5,
+        1.60000000000000e+01,
+        3.25773938229522e-03,
+        8.01884993529766e+00,
+        3.82596965379884e-03,
+        -9.76754729433442e+00,
+    ),
+    (
+        10.5,
+        5.00000000000000e+01,
+        1.86492938511086e-02,
+        5.24786041929931e-01,
+        1.88767599977107e-02,
+        -5.41239787250182e-01,
+    ),
+    (
+        10.5,
+        8.00000000000000e+02,
+        1.31670677886612e-02,
+        4.74628296816047e-02,
+        1.31599712844102e-02,
+        -4.74965675236962e-02,
+    ),
+    (
+        10.5,
+        1.00000000000000e+04,
+        3.96754011895882e-03,
+        1.26022600908553e-02,
+        3.96734392431759e-03,
+        -1.26028971344080e-02,
+    ),
+    (
+        10.5,
+        1.00000000000000e+06,
+        3.98920339168430e-04,
+        1.25338307145426e-03,
+        3.98920139730201e-04,
+        -1.25338369821473e-03,
+    ),
+];
+
+const SPHERICAL_JN_TABLE: [(u32, f64, f64, f64); 17] = [
+    (
+        0,
+        1.00000000000000e-01,
+        9.98334166468282e-01,
+        -3.33000119025576e-02,
+    ),
+    (
+        0,
+        1.00000000000000e+00,
+        8.41470984807897e-01,
+        -3.01168678939757e-01,
+    ),
+    (
+        0,
+        1.00000000000000e+01,
+        -5.44021110889370e-02,
+        -7.84669417987515e-02,
+    ),
+    (
+        1,
+        1.00000000000000e-02,
+        3.33330000011905e-03,
+        3.33323333392857e-01,
+    ),
+    (
+        1,
+        2.00000000000000e+00,
+        4.35397774979992e-01,
+        1.92509384328492e-02,
+    ),
+    (
+        1,
+        3.00000000000000e+01,
+        -6.23952791191154e-03,
+        -3.25184189423013e-02,
+    ),
+    (
+        2,
+        5.00000000000000e-01,
+        1.63711066079934e-02,
+        6.43103909881061e-02,
+    ),
+    (
+        2,
+        5.00000000000000e+00,
+        1.34731210085125e-01,
+        -1.75928134130246e-01,
+    ),
+    (
+        5,
+        1.00000000000000e+00,
+        9.25611586112582e-05,
+        4.55648856746204e-04,
+    ),
+    (
+        5,
+        7.50000000000000e+00,
+        1.56854795948035e-01,
+        -4.61992443634486e-02,
+    ),
+    (
+        10,
+        3.00000000000000e+00,
+        3.52600389317526e-06,
+        1.12860180313213e-05,
+    ),
+    (
+        10,
+        8.00000000000000e+00,
+        1.77444125927236e-02,
+        1.51330684101933e-02,
+    ),
+    (
+        10,
+        5.00000000000000e+01,
+        -1.50392214634660e-02,
+        -1.29162985286650e-02,
+    ),
+    (
+        30,
+        1.00000000000000e+01,
+        2.51205738499894e-13,
+        7.12720577762413e-13,
+    ),
+    (
+        30,
+        4.00000000000000e+01,
+        -2.75766453443043e-02,
+        1.03895482016231e-02,
+    ),
+    (
+        100,
+        2.00000000000000e+01,
+        3.51527111253170e-60,
+        1.72266296036542e-59,
+    ),
+    (
+        100,
+        1.50000000000000e+02,
+        1.64664521679285e-03,
+        -5.62813809392218e-03,
+    ),
+];
+
+const SPHERICAL_YN_TABLE: [(u32, f64, f64, f64); 17] = [
+    (
+        0,
+        1.00000000000000e-01,
+        -9.95004165278026e+00,
+        1.00498750694271e+02,
+    ),
+    (
+        0,
+        1.00000000000000e+00,
+        -5.40302305868140e-01,
+        1.38177329067604e+00,
+    ),
+    (
+        0,
+        1.00000000000000e+01,
+        8.39071529076452e-02,
+        -6.27928263797015e-02,
+    ),
+    (
+        1,
+        1.00000000000000e-02,
+        -1.00004999875001e+04,
+        2.00000000249997e+06,
+    ),
+    (
+        1,
+        2.00000000000000e+00,
+        -3.50612004276055e-01,
+        5.58685422549626e-01,
+    ),
+    (
+        1,
+        3.00000000000000e+01,
+        3.27629969698870e-02,
+        -7.32591479424527e-03,
+    ),
+    (
+        2,
+        5.00000000000000e-01,
+        -2.50599228248386e+01,
+        1.45890355624262e+02,
+    ),
+    (
+        2,
+        5.00000000000000e+00,
+        1.64995457601104e-01,
+        8.14410929534360e-02,
+    ),
+    (
+        5,
+        1.00000000000000e+00,
+        -9.99440343392236e+02,
+        5.88374387613871e+03,
+    ),
+    (
+        5,
+        7.50000000000000e+00,
+        2.27739038538038e-02,
+        1.06631362639797e-01,
+    ),
+    (
+        10,
+        3.00000000000000e+00,
+        -4.69985918881139e+03,
+        1.64686192417169e+04,
+    ),
+    (
+        10,
+        8.00000000000000e+00,
+        -5.36388904127820e-01,
+        4.23107272789845e-01,
+    ),
+    (
+        10,
+        5.00000000000000e+01,
+        1.35246875111588e-02,
+        -1.49815666420169e-02,
+    ),
+    (
+        30,
+        1.00000000000000e+01,
+        -6.90831864609452e+09,
+        2.02077355935513e+10,
+    ),
+    (
+        30,
+        4.00000000000000e+01,
+        -1.42250469707975e-02,
+        -1.73047947953216e-02,
+    ),
+    (
+        100,
+        2.00000000000000e+01,
+        -7.22088935829530e+55,
+        3.57322122802685e+56,
+    ),
+    (
+        100,
+        1.50000000000000e+02,
+        7.55989772450491e-03,
+        1.15161180785268e-03,
+    ),
+];
+
+const SPHERICAL_IN_TABLE: [(u32, f64, f64, f64); 17] = [
+    (
+        0,
+        1.00000000000000e-01,
+        1.00166750019844e+00,
+        3.33666785736334e-02,
+    ),
+    (
+        0,
+        1.00000000000000e+00,
+        1.17520119364380e+00,
+        3.67879441171442e-01,
+    ),
+    (
+        0,
+        1.00000000000000e+01,
+        1.10132328747034e+03,
+        9.91190963263298e+02,
+    ),
+    (
+        1,
+        1.00000000000000e-02,
+        3.33336666678571e-03,
+        3.33343333392857e-01,
+    ),
+    (
+        1,
+        2.00000000000000e+00,
+        9.74382743580061e-01,
+        8.39047460343448e-01,
+    ),
+    (
+        1,
+        3.00000000000000e+01,
+        1.72170979369005e+11,
+        1.66629844400807e+11,
+    ),
+    (
+        2,
+        5.00000000000000e-01,
+        1.69663603608620e-02,
+        6.90725462726002e-02,
+    ),
+    (
+        2,
+        5.00000000000000e+00,
+        7.71632534645014e+00,
+        7.24406607397593e+00,
+    ),
+    (
+        5,
+        1.00000000000000e+00,
+        9.99623752006826e-05,
+        5.07462209781369e-04,
+    ),
+    (
+        5,
+        7.50000000000000e+00,
+        1.56645723970030e+01,
+        1.77183282481408e+01,
+    ),
+    (
+        10,
+        3.00000000000000e+00,
+        5.21494612276342e-06,
+        1.80530157355487e-05,
+    ),
+    (
+        10,
+        8.00000000000000e+00,
+        2.93189415344071e-01,
+        4.59001843477317e-01,
+    ),
+    (
+        10,
+        5.00000000000000e+01,
+        1.71380507109102e+19,
+        1.71757036603280e+19,
+    ),
+    (
+        30,
+        1.00000000000000e+01,
+        1.22928432549880e-12,
+        3.87842601517100e-12,
+    ),
+    (
+        30,
+        4.00000000000000e+01,
+        3.80002860852292e+10,
+        4.70127922941073e+10,
+    ),
+    (
+        100,
+        2.00000000000000e+01,
+        2.52210821801821e-59,
+        1.28566806787856e-58,
+    ),
+    (
+        100,
+        1.50000000000000e+02,
+        3.09427136630749e+48,
+        3.70715513075219e+48,
+    ),
+];
+
+const SPHERICAL_KN_TABLE: [(u32, f64, f64, f64); 17] = [
+    (
+        0,
+        1.00000000000000e-01,
+        1.42131529259746e+01,
+        -1.56344682185721e+02,
+    ),
+    (
+        0,
+        1.00000000000000e+00,
+        5.77863674895461e-01,
+        -1.15572734979092e+00,
+    ),
+    (
+        0,
+        1.00000000000000e+01,
+        7.13140429076575e-06,
+        -7.84454471984233e-06,
+    ),
+    (
+        1,
+        1.00000000000000e-02,
+        1.57071830861906e+04,
+        -3.14159213390234e+06,
+    ),
+    (
+        1,
+        2.00000000000000e+00,
+        1.59438124345364e-01,
+        -2.65730207242273e-01,
+    ),
+    (
+        1,
+        3.00000000000000e+01,
+        5.06296125996174e-15,
+        -5.23717067965935e-15,
+    ),
+    (
+        2,
+        5.00000000000000e-01,
+        3.62039730298734e+01,
+        -2.22940254973431e+02,
+    ),
+    (
+        2,
+        5.00000000000000e+00,
+        3.64087618432794e-03,
+        -4.72467188570928e-03,
+    ),
+    (
+        5,
+        1.00000000000000e+00,
+        1.40478659367087e+03,
+        -8.58243129954738e+03,
+    ),
+    (
+        5,
+        7.50000000000000e+00,
+        7.18276235039163e-04,
+        -9.70253993626694e-04,
+    ),
+    (
+        10,
+        3.00000000000000e+00,
+        4.59583963997699e+03,
+        -1.75580260477073e+04,
+    ),
+    (
+        10,
+        8.00000000000000e+00,
+        2.53523874223493e-02,
+        -4.40223942860079e-02,
+    ),
+    (
+        10,
+        5.00000000000000e+01,
+        1.79404410875704e-23,
+        -1.86823365424195e-23,
+    ),
+    (
+        30,
+        1.00000000000000e+01,
+        1.99043613779592e+09,
+        -6.49825577686708e+09,
+    ),
+    (
+        30,
+        4.00000000000000e+01,
+        1.02718798098367e-14,
+        -1.31272157045897e-14,
+    ),
+    (
+        100,
+        2.00000000000000e+01,
+        1.51948213559181e+55,
+        -7.82456970500496e+55,
+    ),
+    (
+        100,
+        1.50000000000000e+02,
+        9.37193196979307e-54,
+        -1.13338360606506e-53,
+    ),
+];
+
+const COMPLEX_BESSELJ_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 16] = [
+    (
+        0.0,
+        5.00000000000000e-01,
+        3.00000000000000e-01,
+        9.59010687652455e-01,
+        -7.34983648667336e-02,
+        7.10452591241443e-01,
+        -5.44489278835893e-02,
+    ),
+    (
+        0.0,
+        -3.00000000000000e+00,
+        2.00000000000000e+00,
+        -1.24923487960742e+00,
+        9.47983792057735e-01,
+        -1.69065556260726e-01,
+        1.28295655001852e-01,
+    ),
+    (
+        0.3,
+        2.00000000000000e+00,
+        -1.50000000000000e+00,
+        6.37492735349886e-01,
+        9.64585476281973e-01,
+        1.42243856132081e-01,
+        2.15228111799646e-01,
+    ),
+    (
+        0.3,
+        -1.00000000000000e+00,
+        -4.00000000000000e+00,
+        2.48289988742173e+00,
+        -1.06771075239415e+01,
+        4.54758977348951e-02,
+        -1.95558045784700e-01,
+    ),
+    (
+        1.0,
+        1.00000000000000e-03,
+        1.00000000000000e-03,
+        5.00000124999990e-04,
+        4.99999874999990e-04,
+        4.99500374791740e-04,
+        4.99500125041615e-04,
+    ),
+    (
+        1.0,
+        7.50000000000000e+00,
+        5.00000000000000e+00,
+        1.33217543135487e+01,
+        1.39541929104563e+01,
+        8.97612744995290e-02,
+        9.40226122456689e-02,
+    ),
+    (
+        1.0,
+        -2.00000000000000e+01,
+        1.00000000000000e+00,
+        -1.07805260905479e-01,
+        1.91515563130210e-01,
+        -3.96593391372493e-02,
+        7.04546383399757e-02,
+    ),
+    (
+        2.5,
+        0.00000000000000e+00,
+        1.50000000000000e+01,
+        -1.93657909148623e+05,
+        -1.93657909148623e+05,
+        -5.92404037920956e-02,
+        -5.92404037920956e-02,
+    ),
+    (
+        2.5,
+        -6.00000000000000e+00,
+        0.00000000000000e+00,
+        0.00000000000000e+00,
+        -7.29497459078268e-02,
+        0.00000000000000e+00,
+        -7.29497459078268e-02,
+    ),
+    (
+        2.5,
+        4.00000000000000e+01,
+        -3.00000000000000e+01,
+        -2.63639035963690e+11,
+        -5.18524155069955e+11,
+        -2.46703469841671e-02,
+        -4.85215354338106e-02,
+    ),
+    (
+        4.0,
+        -8.00000000000000e-01,
+        1.20000000000000e+00,
+        -9.01792053092680e-03,
+        7.50714788318669e-03,
+        -2.71614546739937e-03,
+        2.26110949038477e-03,
+    ),
+    (
+        7.0,
+        3.00000000000000e+00,
+        0.00000000000000e+00,
+        2.54729445180469e-03,
+        0.00000000000000e+00,
+        2.54729445180469e-03,
+        0.00000000000000e+00,
+    ),
+    (
+        7.0,
+        -1.20000000000000e+01,
+        -9.00000000000000e+00,
+        9.60495048296720e+01,
+        2.95219828868710e+02,
+        1.18534505736524e-02,
+        3.64330212431905e-02,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        -2.00000000000000e+00,
+        1.49576131277122e-07,
+        2.47851396251437e-07,
+        2.02429280918261e-08,
+        3.35430389122782e-08,
+    ),
+    (
+        20.0,
+        6.00000000000000e+01,
+        4.50000000000000e+01,
+        1.30956554084741e+17,
+        2.94130675761720e+17,
+        3.74865569312294e-03,
+        8.41954524477473e-03,
+    ),
+    (
+        20.0,
+        -3.00000000000000e+02,
+        0.00000000000000e+00,
+        -6.48115168876277e-03,
+        0.00000000000000e+00,
+        -6.48115168876277e-03,
+        0.00000000000000e+00,
+    ),
+];
+
+const COMPLEX_BESSELY_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 16] = [
+    (
+        0.0,
+        5.00000000000000e-01,
+        3.00000000000000e-01,
+        -3.48406900299675e-01,
+        4.06915421610323e-01,
+        -2.58106179953238e-01,
+        3.01450358605310e-01,
+    ),
+    (
+        0.0,
+        -3.00000000000000e+00,
+        2.00000000000000e+00,
+        -8.95164387560579e-01,
+        -1.26702814991142e+00,
+        -1.21147325933840e-01,
+        -1.71473613537023e-01,
+    ),
+    (
+        0.3,
+        2.00000000000000e+00,
+        -1.50000000000000e+00,
+        1.00768918143134e+00,
+        -5.35834087698194e-01,
+        2.24845848432614e-01,
+        -1.19560745801086e-01,
+    ),
+    (
+        0.3,
+        -1.00000000000000e+00,
+        -4.00000000000000e+00,
+        -1.06769726881255e+01,
+        -2.48997363061248e+00,
+        -1.95555576180584e-01,
+        -4.56054578607686e-02,
+    ),
+    (
+        1.0,
+        1.00000000000000e-03,
+        1.00000000000000e-03,
+        -3.18312420730446e+02,
+        3.18307851638397e+02,
+        -3.17994267412887e+02,
+        3.17989702887646e+02,
+    ),
+    (
+        1.0,
+        7.50000000000000e+00,
+        5.00000000000000e+00,
+        -1.39560002124557e+01,
+        1.33214441508368e+01,
+        -9.40347897507523e-02,
+        8.97591846396155e-02,
+    ),
+    (
+        1.0,
+        -2.00000000000000e+01,
+        1.00000000000000e+00,
+        -1.30011287279424e-01,
+        -1.30864650993511e-01,
+        -4.78284797103342e-02,
+        -4.81424146765885e-02,
+    ),
+    (
+        2.5,
+        0.00000000000000e+00,
+        1.50000000000000e+01,
+        1.93657909148677e+05,
+        -1.93657909148677e+05,
+        5.92404037921121e-02,
+        -5.92404037921121e-02,
+    ),
+    (
+        2.5,
+        -6.00000000000000e+00,
+        0.00000000000000e+00,
+        0.00000000000000e+00,
+        -3.32205357707704e-01,
+        0.00000000000000e+00,
+        -3.32205357707704e-01,
+    ),
+    (
+        2.5,
+        4.00000000000000e+01,
+        -3.00000000000000e+01,
+        -5.18524155069955e+11,
+        2.63639035963690e+11,
+        -4.85215354338106e-02,
+        2.46703469841671e-02,
+    ),
+    (
+        4.0,
+        -8.00000000000000e-01,
+        1.20000000000000e+00,
+        5.29038594496709e+00,
+        3.89460502092918e+00,
+        1.59343362540575e+00,
+        1.17303248998807e+00,
+    ),
+    (
+        7.0,
+        3.00000000000000e+00,
+        0.00000000000000e+00,
+        -1.98399354089864e+01,
+        0.00000000000000e+00,
+        -1.98399354089864e+01,
+        0.00000000000000e+00,
+    ),
+    (
+        7.0,
+        -1.20000000000000e+01,
+        -9.00000000000000e+00,
+        2.95219765705685e+02,
+        -9.60494752529968e+01,
+        3.64330134482540e-02,
+        -1.18534469236007e-02,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        -2.00000000000000e+00,
+        -5.17815745199264e+04,
+        8.93590314855263e+04,
+        -7.00787405409200e+03,
+        1.20934298358431e+04,
+    ),
+    (
+        20.0,
+        6.00000000000000e+01,
+        4.50000000000000e+01,
+        -2.94130675761720e+17,
+        1.30956554084741e+17,
+        -8.41954524477473e-03,
+        3.74865569312294e-03,
+    ),
+    (
+        20.0,
+        -3.00000000000000e+02,
+        0.00000000000000e+00,
+        -4.56594898500582e-02,
+        -1.29623033775255e-02,
+        -4.56594898500582e-02,
+        -1.29623033775255e-02,
+    ),
+];
+
+const COMPLEX_BESSELI_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 16] = [
+    (
+        0.0,
+        5.00000000000000e-01,
+        3.00000000000000e-01,
+        1.03897674360114e+00,
+        7.64981971586920e-02,
+        6.30171249722483e-01,
+        4.63985019894885e-02,
+    ),
+    (
+        0.0,
+        -3.00000000000000e+00,
+        2.00000000000000e+00,
+        -4.69517192044070e-01,
+        -4.31378840946892e+00,
+        -2.33758845401856e-02,
+        -2.14770878466728e-01,
+    ),
+    (
+        0.3,
+        2.00000000000000e+00,
+        -1.50000000000000e+00,
+        8.03858947695827e-01,
+        -1.76637169880541e+00,
+        1.08790478368700e-01,
+        -2.39052414158967e-01,
+    ),
+    (
+        0.3,
+        -1.00000000000000e+00,
+        -4.00000000000000e+00,
+        -4.57346498648906e-01,
+        3.66480520003193e-01,
+        -1.68248374344675e-01,
+        1.34820648898994e-01,
+    ),
+    (
+        1.0,
+        1.00000000000000e-03,
+        1.00000000000000e-03,
+        4.99999874999990e-04,
+        5.00000124999990e-04,
+        4.99500125041615e-04,
+        4.99500374791740e-04,
+    ),
+    (
+        1.0,
+        7.50000000000000e+00,
+        5.00000000000000e+00,
+        4.41940860532066e+00,
+        -2.31844665358016e+02,
+        2.44430582489969e-03,
+        -1.28229660711673e-01,
+    ),
+    (
+        1.0,
+        -2.00000000000000e+01,
+        1.00000000000000e+00,
+        -2.37753717081305e+07,
+        3.51438450732467e+07,
+        -4.90046935210365e-02,
+        7.24368635791419e-02,
+    ),
+    (
+        2.5,
+        0.00000000000000e+00,
+        1.50000000000000e+01,
+        7.13331794249882e-02,
+        7.13331794249882e-02,
+        7.13331794249882e-02,
+        7.13331794249882e-02,
+    ),
+    (
+        2.5,
+        -6.00000000000000e+00,
+        0.00000000000000e+00,
+        0.00000000000000e+00,
+        3.83275344932040e+01,
+        0.00000000000000e+00,
+        9.50044595512844e-02,
+    ),
+    (
+        2.5,
+        4.00000000000000e+01,
+        -3.00000000000000e+01,
+        -1.64443355632158e+15,
+        1.25483781100337e+16,
+        -6.98613629654306e-03,
+        5.33099555407697e-02,
+    ),
+    (
+        4.0,
+        -8.00000000000000e-01,
+        1.20000000000000e+00,
+        -6.84956666350550e-03,
+        8.39133397897692e-03,
+        -3.07770869356478e-03,
+        3.77046940433534e-03,
+    ),
+    (
+        7.0,
+        3.00000000000000e+00,
+        0.00000000000000e+00,
+        4.47211872994957e-03,
+        0.00000000000000e+00,
+        2.22653680957204e-04,
+        0.00000000000000e+00,
+    ),
+    (
+        7.0,
+        -1.20000000000000e+01,
+        -9.00000000000000e+00,
+        4.30363251164643e+03,
+        1.11246432709492e+03,
+        2.64424320422429e-02,
+        6.83521706117356e-03,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        -2.00000000000000e+00,
+        1.66949948594477e-07,
+        1.91544956439806e-07,
+        6.14174537925374e-08,
+        7.04654515342842e-08,
+    ),
+    (
+        20.0,
+        6.00000000000000e+01,
+        4.50000000000000e+01,
+        -4.09963112512379e+23,
+        4.61534860307768e+23,
+        -3.58984640702321e-03,
+        4.04143497164460e-03,
+    ),
+    (
+        20.0,
+        -3.00000000000000e+02,
+        0.00000000000000e+00,
+        2.29598730331069e+128,
+        -2.30551004595736e-132,
+        1.18202023455593e-02,
+        -1.18692273313708e-262,
+    ),
+];
+
+const COMPLEX_BESSELK_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 16] = [
+    (
+        0.0,
+        5.00000000000000e-01,
+        3.00000000000000e-01,
+        7.60679778665956e-01,
+        -4.34104569821073e-01,
+        1.40964320202843e+00,
+        -3.13124633042890e-01,
+    ),
+    (
+        0.0,
+        -3.00000000000000e+00,
+        2.00000000000000e+00,
+        -1.35729532019158e+01,
+        1.49934442482693e+00,
+        2.13337165272008e-01,
+        -6.45529114297434e-01,
+    ),
+    (
+        0.3,
+        2.00000000000000e+00,
+        -1.50000000000000e+00,
+        -2.45145567042778e-02,
+        1.01871214698703e-01,
+        7.38033219505730e-01,
+        2.33931841811442e-01,
+    ),
+    (
+        0.3,
+        -1.00000000000000e+00,
+        -4.00000000000000e+00,
+        2.94656858065389e-01,
+        -1.65585989756419e+00,
+        3.90157604570374e-01,
+        4.80207493889686e-01,
+    ),
+    (
+        1.0,
+        1.00000000000000e-03,
+        1.00000000000000e-03,
+        4.99996018745209e+02,
+        -5.00003195858610e+02,
+        5.00996517962928e+02,
+        -5.00002702706288e+02,
+    ),
+    (
+        1.0,
+        7.50000000000000e+00,
+        5.00000000000000e+00,
+        1.35378995155621e-04,
+        1.96716604087421e-04,
+        4.10494746867825e-01,
+        -1.33826133763466e-01,
+    ),
+    (
+        1.0,
+        -2.00000000000000e+01,
+        1.00000000000000e+00,
+        -1.10407645501010e+08,
+        -7.46925330946295e+07,
+        6.59176289132892e-03,
+        -2.74672172103480e-01,
+    ),
+    (
+        2.5,
+        0.00000000000000e+00,
+        1.50000000000000e+01,
+        -3.13326170132948e-01,
+        8.92263776942028e-02,
+        1.80007275796541e-01,
+        -2.71536399082918e-01,
+    ),
+    (
+        2.5,
+        -6.00000000000000e+00,
+        0.00000000000000e+00,
+        0.00000000000000e+00,
+        -1.20411508914592e+02,
+        0.00000000000000e+00,
+        -2.98470289817726e-01,
+    ),
+    (
+        2.5,
+        4.00000000000000e+01,
+        -3.00000000000000e+01,
+        3.87016833136811e-19,
+        -6.88585650908494e-19,
+        1.74195055818296e-01,
+        6.50062397284859e-02,
+    ),
+    (
+        4.0,
+        -8.00000000000000e-01,
+        1.20000000000000e+00,
+        -6.79257099862321e+00,
+        -9.59137732673701e+00,
+        2.91084189899956e+00,
+        -4.40632274600397e+00,
+    ),
+    (
+        7.0,
+        3.00000000000000e+00,
+        0.00000000000000e+00,
+        1.46648264741554e+01,
+        0.00000000000000e+00,
+        2.94550913618787e+02,
+        0.00000000000000e+00,
+    ),
+    (
+        7.0,
+        -1.20000000000000e+01,
+        -9.00000000000000e+00,
+        3.49490976241333e+03,
+        -1.35202602875097e+04,
+        -5.38003653257199e-02,
+        6.68392081383302e-02,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        -2.00000000000000e+00,
+        1.27494116745386e+05,
+        -1.40830686796633e+05,
+        -4.92316868557577e+05,
+        -1.55822318611433e+05,
+    ),
+    (
+        20.0,
+        6.00000000000000e+01,
+        4.50000000000000e+01,
+        -1.03895189161452e-26,
+        -2.48098301553379e-27,
+        -3.82203098741033e-01,
+        -1.15842867904856e+00,
+    ),
+    (
+        20.0,
+        -3.00000000000000e+02,
+        0.00000000000000e+00,
+        0.00000000000000e+00,
+        -7.21305684481631e+128,
+        0.00000000000000e+00,
+        -3.71342608527538e-02,
+    ),
+];
+
+const COMPLEX_HANKEL1_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 16] = [
+    (
+        0.0,
+        5.00000000000000e-01,
+        3.00000000000000e-01,
+        5.52095266042133e-01,
+        -4.21905265166409e-01,
+        3.80980125905551e-01,
+        -8.57086470117545e-01,
+    ),
+    (
+        0.0,
+        -3.00000000000000e+00,
+        2.00000000000000e+00,
+        1.77932703039946e-02,
+        5.28194044971554e-02,
+        -1.85236830187622e-01,
+        -3.67825939310625e-01,
+    ),
+    (
+        0.3,
+        2.00000000000000e+00,
+        -1.50000000000000e+00,
+        1.17332682304808e+00,
+        1.97227465771331e+00,
+        2.91208962764211e-01,
+        -4.21193637266378e-01,
+    ),
+    (
+        0.3,
+        -1.00000000000000e+00,
+        -4.00000000000000e+00,
+        4.97287351803421e+00,
+        -2.13540802120670e+01,
+        3.78322231096844e-01,
+        -1.34677273813561e-01,
+    ),
+    (
+        1.0,
+        1.00000000000000e-03,
+        1.00000000000000e-03,
+        -3.18307351638272e+02,
+        -3.18311920730571e+02,
+        -3.18944289222510e+02,
+        -3.18311606780052e+02,
+    ),
+    (
+        1.0,
+        7.50000000000000e+00,
+        5.00000000000000e+00,
+        3.10162711847626e-04,
+        -1.80730199942083e-03,
+        -2.35640898258075e-01,
+        -1.36155318471205e-01,
+    ),
+    (
+        1.0,
+        -2.00000000000000e+01,
+        1.00000000000000e+00,
+        2.30593900880311e-02,
+        6.15042758507863e-02,
+        -1.27052256405794e-01,
+        1.25450751523689e-01,
+    ),
+    (
+        2.5,
+        0.00000000000000e+00,
+        1.50000000000000e+01,
+        5.40682526511392e-08,
+        5.40682526511392e-08,
+        1.76750057215785e-01,
+        1.76750057215785e-01,
+    ),
+    (
+        2.5,
+        -6.00000000000000e+00,
+        0.00000000000000e+00,
+        3.32205357707704e-01,
+        -7.29497459078268e-02,
+        2.98590423940673e-01,
+        -1.62867503967640e-01,
+    ),
+    (
+        2.5,
+        4.00000000000000e+01,
+        -3.00000000000000e+01,
+        -5.27278071927380e+11,
+        -1.03704831013991e+12,
+        -3.94008824409764e-02,
+        1.01486118004224e-01,
+    ),
+    (
+        4.0,
+        -8.00000000000000e-01,
+        1.20000000000000e+00,
+        -3.90362294146010e+00,
+        5.29789309285027e+00,
+        -2.16476808494183e+01,
+        2.95752685254436e+00,
+    ),
+    (
+        7.0,
+        3.00000000000000e+00,
+        0.00000000000000e+00,
+        2.54729445180469e-03,
+        -1.98399354089864e+01,
+        -2.80233364721733e+00,
+        1.96410277137205e+01,
+    ),
+    (
+        7.0,
+        -1.20000000000000e+01,
+        -9.00000000000000e+00,
+        1.92098980082669e+02,
+        5.90439594574394e+02,
+        5.91031001598318e-02,
+        4.87678126646762e-02,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        -2.00000000000000e+00,
+        -8.93590314853767e+04,
+        -5.17815745196785e+04,
+        -1.24310307078279e+04,
+        6.38989980301376e+03,
+    ),
+    (
+        20.0,
+        6.00000000000000e+01,
+        4.50000000000000e+01,
+        -3.41075862797779e-21,
+        -1.28542764357503e-20,
+        2.50358925990770e-01,
+        3.91366691959759e-01,
+    ),
+    (
+        20.0,
+        -3.00000000000000e+02,
+        0.00000000000000e+00,
+        6.48115168876277e-03,
+        -4.56594898500582e-02,
+        -4.57915531658569e-02,
+        -5.47064888645011e-03,
+    ),
+];
+
+const COMPLEX_HANKEL2_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 16] = [
+    (
+        0.0,
+        5.00000000000000e-01,
+        3.00000000000000e-01,
+        1.36592610926278e+00,
+        2.74908535432942e-01,
+        7.90389895346796e-01,
+        6.63858169764297e-01,
+    ),
+    (
+        0.0,
+        -3.00000000000000e+00,
+        2.00000000000000e+00,
+        -2.51626302951884e+00,
+        1.84314817961831e+00,
+        3.72332618378439e-01,
+        -1.98889789069424e-01,
+    ),
+    (
+        0.3,
+        2.00000000000000e+00,
+        -1.50000000000000e+00,
+        1.01658647651693e-01,
+        -4.31037051493622e-02,
+        -1.39418017128439e-02,
+        4.94668301193380e-01,
+    ),
+    (
+        0.3,
+        -1.00000000000000e+00,
+        -4.00000000000000e+00,
+        -7.07374319075421e-03,
+        -1.34835816066270e-04,
+        -2.14866661650381e-01,
+        3.21009689172476e-01,
+    ),
+    (
+        1.0,
+        1.00000000000000e-03,
+        1.00000000000000e-03,
+        3.18308351638522e+02,
+        3.18312920730321e+02,
+        3.17672048679019e+02,
+        3.18312598065032e+02,
+    ),
+    (
+        1.0,
+        7.50000000000000e+00,
+        5.00000000000000e+00,
+        2.66431984643855e+01,
+        2.79101931229121e+01,
+        -1.14169707293374e-01,
+        2.33577523815367e-01,
+    ),
+    (
+        1.0,
+        -2.00000000000000e+01,
+        1.00000000000000e+00,
+        -2.38669911898990e-01,
+        3.21526850409633e-01,
+        7.21556901381029e-02,
+        1.28427412841587e-01,
+    ),
+    (
+        2.5,
+        0.00000000000000e+00,
+        1.50000000000000e+01,
+        -3.87315818297300e+05,
+        -3.87315818297300e+05,
+        -1.18480807584208e-01,
+        -1.18480807584208e-01,
+    ),
+    (
+        2.5,
+        -6.00000000000000e+00,
+        0.00000000000000e+00,
+        -3.32205357707704e-01,
+        -7.29497459078268e-02,
+        -2.98590423940673e-01,
+        -1.62867503967640e-01,
+    ),
+    (
+        2.5,
+        4.00000000000000e+01,
+        -3.00000000000000e+01,
+        -9.83032489178909e-15,
+        4.81868116265694e-15,
+        3.16934662591964e-02,
+        -1.12619052489017e-01,
+    ),
+    (
+        4.0,
+        -8.00000000000000e-01,
+        1.20000000000000e+00,
+        3.88558710039825e+00,
+        -5.28287879708390e+00,
+        -3.26070046677608e-01,
+        -1.94811412558821e+00,
+    ),
+    (
+        7.0,
+        3.00000000000000e+00,
+        0.00000000000000e+00,
+        2.54729445180469e-03,
+        1.98399354089864e+01,
+        -2.80233364721733e+00,
+        -1.96410277137205e+01,
+    ),
+    (
+        7.0,
+        -1.20000000000000e+01,
+        -9.00000000000000e+00,
+        2.95766752032235e-05,
+        6.31630248214639e-05,
+        -7.23862593244786e-02,
+        5.60493649362512e-01,
+    ),
+    (
+        10.5,
+        1.00000000000000e+00,
+        -2.00000000000000e+00,
+        8.93590314856759e+04,
+        5.17815745201742e+04,
+        3.47891410344807e+04,
+        7.62334358587676e+05,
+    ),
+    (
+        20.0,
+        6.00000000000000e+01,
+        4.50000000000000e+01,
+        2.61913108169483e+17,
+        5.88261351523440e+17,
+        -2.00780305155892e-03,
+        -1.83230285008706e-02,
+    ),
+    (
+        20.0,
+        -3.00000000000000e+02,
+        0.00000000000000e+00,
+        -1.94434550662883e-02,
+        4.56594898500582e-02,
+        -4.52187070004410e-02,
+        -2.04476281140530e-02,
+    ),
+];
+
+const LARGE_BESSELJY_TABLE: [(f64, f64, f64, f64, f64, f64); 18] = [
+    (
+        0.5,
+        4.00000000000000e+01,
+        9.40009623895336e-02,
+        8.41386556763954e-02,
+        -8.53136677062646e-02,
+        9.29492291935786e-02,
+    ),
+    (
+        0.5,
+        1.00000000000000e+04,
+        -2.43845002453139e-03,
+        7.59710067819435e-03,
+        -7.59697875569312e-03,
+        -2.43882987956530e-03,
+    ),
+    (
+        0.5,
+        1.00000000000000e+06,
+        -2.79254411763799e-04,
+        -7.47420059857933e-04,
+        7.47420199485139e-04,
+        -2.79254038053769e-04,
+    ),
+    (
+        3.3,
+        3.00000000000000e+05,
+        -1.42566039899159e-03,
+        -2.99262985958010e-04,
+        2.99265362040986e-04,
+        -1.42565990013568e-03,
+    ),
+    (
+        25.0,
+        2.30000000000000e+01,
+        6.77650928023645e-02,
+        -4.84752493622375e-01,
+        3.41646779366194e-02,
+        1.64062458330337e-01,
+    ),
+    (
+        25.0,
+        1.40000000000000e+02,
+        -5.44000704457881e-03,
+        6.77638285403704e-02,
+        -6.66551173868654e-02,
+        -5.60259245039653e-03,
+    ),
+    (
+        25.0,
+        1.00000000000000e+06,
+        -7.25865036060799e-04,
+        -3.31269862786974e-04,
+        3.31270225616012e-04,
+        -7.25864870199125e-04,
+    ),
+    (
+        150.5,
+        1.40000000000000e+02,
+        3.52487395855661e-03,
+        -1.65467758605103e+00,
+        1.46145058722683e-03,
+        6.04008706003868e-01,
+    ),
+    (
+        150.5,
+        1.60000000000000e+02,
+        2.10244336356672e-02,
+        1.05558502046568e-01,
+        -3.67929444145264e-02,
+        4.52166658397181e-03,
+    ),
+    (
+        150.5,
+        9.00000000000000e+02,
+        -2.67801635990944e-02,
+        5.29492555216152e-04,
+        -5.06731125646794e-04,
+        -2.64033858365238e-02,
+    ),
+    (
+        150.5,
+        1.00000000000000e+04,
+        7.91433428896244e-03,
+        -1.01612053571785e-03,
+        1.01560964718743e-03,
+        7.91348875739598e-03,
+    ),
+    (
+        1000.0,
+        9.00000000000000e+02,
+        5.08411008504130e-16,
+        -1.43644587800950e+12,
+        2.47421871612681e-16,
+        6.92249320207939e+11,
+    ),
+    (
+        1000.0,
+        9.90000000000000e+02,
+        1.23619424562302e-02,
+        -1.89689439911459e-01,
+        1.99430786820970e-03,
+        2.14166287801822e-02,
+    ),
+    (
+        1000.0,
+        1.01000000000000e+03,
+        6.52818180022151e-02,
+        6.23206393250036e-03,
+        -2.26641301707370e-03,
+        9.43895550616825e-03,
+    ),
+    (
+        1000.0,
+        1.20000000000000e+03,
+        3.58266743788289e-03,
+        3.07716408791575e-02,
+        -1.70147451370809e-02,
+        1.93845789059441e-03,
+    ),
+    (
+        1000.0,
+        1.00000000000000e+06,
+        6.38565605498111e-04,
+        -4.78386933509327e-04,
+        4.78386375032739e-04,
+        6.38565525409014e-04,
+    ),
+    (
+        2000.0,
+        1.00000000000000e+05,
+        -2.38726576122796e-03,
+        -8.17577843699691e-04,
+        8.17426252891617e-04,
+        -2.38678417082574e-03,
+    ),
+    (
+        400.0,
+        5.00000000000000e+03,
+        3.65744473280201e-03,
+        1.06937577432651e-02,
+        -1.06598509465727e-02,
+        3.64464587858314e-03,
+    ),
+];
+
+const LARGE_BESSELIK_SCALED_TABLE: [(f64, f64, f64, f64, f64, f64); 18] = [
+    (
+        0.5,
+        4.00000000000000e+01,
+        6.30783130505040e-02,
+        1.98166364880301e-01,
+        6.22898341373727e-02,
+        -2.00643444441304e-01,
+    ),
+    (
+        0.5,
+        1.00000000000000e+04,
+        3.98942280401433e-03,
+        1.25331413731550e-02,
+        3.98922333287413e-03,
+        -1.25337680302237e-02,
+    ),
+    (
+        0.5,
+        1.00000000000000e+06,
+        3.98942280401433e-04,
+        1.25331413731550e-03,
+        3.98942080930292e-04,
+        -1.25331476397257e-03,
+    ),
+    (
+        3.3,
+        3.00000000000000e+05,
+        7.28352704137382e-04,
+        2.28826866036291e-03,
+        7.28351490259262e-04,
+        -2.28827247427927e-03,
+    ),
+    (
+        25.0,
+        2.30000000000000e+01,
+        2.38378775157666e-07,
+        6.17394426126303e+04,
+        3.49735934902108e-07,
+        -9.18108551091704e+04,
+    ),
+    (
+        25.0,
+        1.40000000000000e+02,
+        3.61364510252314e-03,
+        9.72931894212994e-01,
+        3.65828274779082e-03,
+        -9.91684871594098e-01,
+    ),
+    (
+        25.0,
+        1.00000000000000e+06,
+        3.98817680206305e-04,
+        1.25370570227771e-03,
+        3.98817480922046e-04,
+        -1.25370632952219e-03,
+    ),
+    (
+        150.5,
+        1.40000000000000e+02,
+        7.98016847368852e-35,
+        3.04819187455379e+31,
+        1.17033263564111e-34,
+        -4.48043011141865e+31,
+    ),
+    (
+        150.5,
+        1.60000000000000e+02,
+        3.29140409088540e-31,
+        6.91573290071746e+27,
+        4.51322513719864e-31,
+        -9.50589461712486e+27,
+    ),
+    (
+        150.5,
+        9.00000000000000e+02,
+        4.66173894612394e-08,
+        1.17541384270435e+04,
+        4.72394829008936e-08,
+        -1.19236983515394e+04,
+    ),
+    (
+        150.5,
+        1.00000000000000e+04,
+        1.28545316019128e-03,
+        3.88923844212352e-02,
+        1.28553447141791e-03,
+        -3.88987329133213e-02,
+    ),
+    (
+        1000.0,
+        9.00000000000000e+02,
+        3.07240571961394e-225,
+        1.20962882768952e+221,
+        4.59201322269562e-225,
+        -1.80851099981197e+221,
+    ),
+    (
+        1000.0,
+        9.90000000000000e+02,
+        2.12487417606007e-207,
+        1.67221919102424e+203,
+        3.01970986146185e-207,
+        -2.37726702891872e+203,
+    ),
+    (
+        1000.0,
+        1.01000000000000e+03,
+        8.37975990984298e-204,
+        4.19809221705427e+199,
+        1.17901545800973e-203,
+        -5.90873072047877e+199,
+    ),
+    (
+        1000.0,
+        1.20000000000000e+03,
+        6.82368006738732e-175,
+        4.69090265835459e+170,
+        8.88076316701671e-175,
+        -6.10734052203501e+170,
+    ),
+    (
+        1000.0,
+        1.00000000000000e+06,
+        2.41970704354894e-04,
+        2.06636481607670e-03,
+        2.41970704354954e-04,
+        -2.06636688243997e-03,
+    ),
+    (
+        2000.0,
+        1.00000000000000e+05,
+        2.60175887276841e-12,
+        1.92139262107835e+06,
+        2.60226616889283e-12,
+        -1.92178646428030e+06,
+    ),
+    (
+        400.0,
+        5.00000000000000e+03,
+        6.39337663633877e-10,
+        1.55913743237780e+05,
+        6.41316750923378e-10,
+        -1.56427362958733e+05,
+    ),
+];
+
+const JN_ZEROS_TABLE: [(u32, usize, f64); 48] = [
+    (0, 1, 2.40482555769577e+00),
+    (0, 2, 5.52007811028631e+00),
+    (0, 3, 8.65372791291101e+00),
+    (0, 4, 1.17915344390143e+01),
+    (0, 5, 1.49309177084878e+01),
+    (0, 6, 1.80710639679109e+01),
+    (0, 7, 2.12116366298793e+01),
+    (0, 8, 2.43524715307493e+01),
+    (1, 1, 3.83170597020751e+00),
+    (1, 2, 7.01558666981562e+00),
+    (1, 3, 1.01734681350627e+01),
+    (1, 4, 1.33236919363142e+01),
+    (1, 5, 1.64706300508776e+01),
+    (1, 6, 1.96158585104682e+01),
+    (1, 7, 2.27600843805928e+01),
+    (1, 8, 2.59036720876184e+01),
+    (2, 1, 5.13562230184068e+00),
+    (2, 2, 8.41724414039986e+00),
+    (2, 3, 1.16198411721491e+01),
+    (2, 4, 1.47959517823513e+01),
+    (2, 5, 1.79598194949878e+01),
+    (2, 6, 2.11169970530218e+01),
+    (2, 7, 2.42701123135731e+01),
+    (2, 8, 2.74205735499846e+01),
+    (5, 1, 8.77148381595995e+00),
+    (5, 2, 1.23386041974669e+01),
+    (5, 3, 1.57001740797117e+01),
+    (5, 4, 1.89801338751799e+01),
+    (5, 5, 2.22177998965613e+01),
+    (5, 6, 2.54303411542227e+01),
+    (5, 7, 2.86266183072911e+01),
+    (5, 8, 3.18117167240478e+01),
+    (12, 1, 1.66982499338482e+01),
+    (12, 2, 2.07899063600784e+01),
+    (12, 3, 2.44948850438814e+01),
+    (12, 4, 2.80267099499731e+01),
+    (12, 5, 3.14599600353180e+01),
+    (12, 6, 3.48299869902902e+01),
+    (12, 7, 3.81563775046814e+01),
+    (12, 8, 4.14510923079397e+01),
+    (40, 1, 4.66484094982857e+01),
+    (40, 2, 5.20161467794285e+01),
+    (40, 3, 5.66583134554301e+01),
+    (40, 4, 6.09447000132724e+01),
+    (40, 5, 6.50121990647890e+01),
+    (40, 6, 6.89293258752548e+01),
+    (40, 7, 7.27360457440247e+01),
+    (40, 8, 7.64579845873545e+01),
+];
+
+const YN_ZEROS_TABLE: [(u32, usize, f64); 48] = [
+    (0, 1, 8.93576966279167e-01),
+    (0, 2, 3.95767841931486e+00),
+    (0, 3, 7.08605106030177e+00),
+    (0, 4, 1.02223450434964e+01),
+    (0, 5, 1.33610974738728e+01),
+    (0, 6, 1.65009224415281e+01),
+    (0, 7, 1.96413097008879e+01),
+    (0, 8, 2.27820280472916e+01),
+    (1, 1, 2.19714132603102e+00),
+    (1, 2, 5.42968104079413e+00),
+    (1, 3, 8.59600586833117e+00),
+    (1, 4, 1.17491548308399e+01),
+    (1, 5, 1.48974421283367e+01),
+    (1, 6, 1.80434022767279e+01),
+    (1, 7, 2.11880689341422e+01),
+    (1, 8, 2.43319425713569e+01),
+    (2, 1, 3.38424176714959e+00),
+    (2, 2, 6.79380751326827e+00),
+    (2, 3, 1.00234779793600e+01),
+    (2, 4, 1.32099867102064e+01),
+    (2, 5, 1.63789665589475e+01),
+    (2, 6, 1.95390399902864e+01),
+    (2, 7, 2.26939559389093e+01),
+    (2, 8, 2.58456137209023e+01),
+    (5, 1, 6.74718382487102e+00),
+    (5, 2, 1.05971767267820e+01),
+    (5, 3, 1.40338041049112e+01),
+    (5, 4, 1.73470863932284e+01),
+    (5, 5, 2.06028990171753e+01),
+    (5, 6, 2.38265360302875e+01),
+    (5, 7, 2.70301349371388e+01),
+    (5, 8, 3.02203356542314e+01),
+    (12, 1, 1.42473956650739e+01),
+    (12, 2, 1.88195558947107e+01),
+    (12, 3, 2.26716971178728e+01),
+    (12, 4, 2.62763755449039e+01),
+    (12, 5, 2.97529254955490e+01),
+    (12, 6, 3.31514127089990e+01),
+    (12, 7, 3.64977637729876e+01),
+    (12, 8, 3.98071340907044e+01),
+    (40, 1, 4.32623611298250e+01),
+    (40, 2, 4.94688192857341e+01),
+    (40, 3, 5.43953762245524e+01),
+    (40, 4, 5.88350049447999e+01),
+    (40, 5, 6.30005961438481e+01),
+    (40, 6, 6.69866172620068e+01),
+    (40, 7, 7.08446438321672e+01),
+    (40, 8, 7.46063735359454e+01),
+];
+
+const JNP_ZEROS_TABLE: [(u32, usize, f64); 48] = [
+    (0, 1, 3.83170597020751e+00),
+    (0, 2, 7.01558666981562e+00),
+    (0, 3, 1.01734681350627e+01),
+    (0, 4, 1.33236919363142e+01),
+    (0, 5, 1.64706300508776e+01),
+    (0, 6, 1.96158585104682e+01),
+    (0, 7, 2.27600843805928e+01),
+    (0, 8, 2.59036720876184e+01),
+    (1, 1, 1.84118378134066e+00),
+    (1, 2, 5.33144277352503e+00),
+    (1, 3, 8.53631636634629e+00),
+    (1, 4, 1.17060049025921e+01),
+    (1, 5, 1.48635886339090e+01),
+    (1, 6, 1.80155278626818e+01),
+    (1, 7, 2.11643698591888e+01),
+    (1, 8, 2.43113268572108e+01),
+    (2, 1, 3.05423692822714e+00),
+    (2, 2, 6.70613319415846e+00),
+    (2, 3, 9.96946782308760e+00),
+    (2, 4, 1.31703708560161e+01),
+    (2, 5, 1.63475223183218e+01),
+    (2, 6, 1.95129127824882e+01),
+    (2, 7, 2.26715817724774e+01),
+    (2, 8, 2.58260371417853e+01),
+    (5, 1, 6.41561637570024e+00),
+    (5, 2, 1.05198608737723e+01),
+    (5, 3, 1.39871886301403e+01),
+    (5, 4, 1.73128424878846e+01),
+    (5, 5, 2.05755145213869e+01),
+    (5, 6, 2.38035814765939e+01),
+    (5, 7, 2.70103078977777e+01),
+    (5, 8, 3.02028490789817e+01),
+    (12, 1, 1.38788430696973e+01),
+    (12, 2, 1.87450909168144e+01),
+    (12, 3, 2.26293003028355e+01),
+    (12, 4, 2.62460477739466e+01),
+    (12, 5, 2.97289781689113e+01),
+    (12, 6, 3.31314499535717e+01),
+    (12, 7, 3.64805483022317e+01),
+    (12, 8, 3.97919407189409e+01),
+    (40, 1, 4.27853722603930e+01),
+    (40, 2, 4.93858571183522e+01),
+    (40, 3, 5.43520207665549e+01),
+    (40, 4, 5.88056905761046e+01),
+    (40, 5, 6.29783494823672e+01),
+    (40, 6, 6.69686030487958e+01),
+    (40, 7, 7.08294433435190e+01),
+    (40, 8, 7.45931790011245e+01),
+];
+
+const YNP_ZEROS_TABLE: [(u32, usize, f64); 48] = [
+    (0, 1, 2.19714132603102e+00),
+    (0, 2, 5.42968104079413e+00),
+    (0, 3, 8.59600586833117e+00),
+    (0, 4, 1.17491548308399e+01),
+    (0, 5, 1.48974421283367e+01),
+    (0, 6, 1.80434022767279e+01),
+    (0, 7, 2.11880689341422e+01),
+    (0, 8, 2.43319425713569e+01),
+    (1, 1, 3.68302285658518e+00),
+    (1, 2, 6.94149995365418e+00),
+    (1, 3, 1.01234046554366e+01),
+    (1, 4, 1.32857581567829e+01),
+    (1, 5, 1.64400580072933e+01),
+    (1, 6, 1.95902417566295e+01),
+    (1, 7, 2.27380347173963e+01),
+    (1, 8, 2.58843146187889e+01),
+    (2, 1, 5.00258293144606e+00),
+    (2, 2, 8.35072470141308e+00),
+    (2, 3, 1.15741954652176e+01),
+    (2, 4, 1.47609093062077e+01),
+    (2, 5, 1.79312859394669e+01),
+    (2, 6, 2.10928945044127e+01),
+    (2, 7, 2.42492316785191e+01),
+    (2, 8, 2.74021458371453e+01),
+    (5, 1, 8.64955624369720e+00),
+    (5, 2, 1.22808687258078e+01),
+    (5, 3, 1.56607993045404e+01),
+    (5, 4, 1.89497397560165e+01),
+    (5, 5, 2.21928418094282e+01),
+    (5, 6, 2.54090727888677e+01),
+    (5, 7, 2.86080392830776e+01),
+    (5, 8, 3.17951953531382e+01),
+    (12, 1, 1.65743170355309e+01),
+    (12, 2, 2.07361776375393e+01),
+    (12, 3, 2.44596317282388e+01),
+    (12, 4, 2.79999936688396e+01),
+    (12, 5, 3.14382087902678e+01),
+    (12, 6, 3.48115120708055e+01),
+    (12, 7, 3.81402437086113e+01),
+    (12, 8, 4.14367251438937e+01),
+    (40, 1, 4.65013697304165e+01),
+    (40, 2, 5.19590823209086e+01),
+    (40, 3, 5.66233567845325e+01),
+    (40, 4, 6.09194246687738e+01),
+    (40, 5, 6.49923052968021e+01),
+    (40, 6, 6.89128472791329e+01),
+    (40, 7, 7.27219257033824e+01),
+    (40, 8, 7.64455918977195e+01),
+];
+
+const SEQUENCE_TABLE: [(u32, f64, f64, f64, f64, f64); 13] = [
+    (
+        0,
+        1.00000000000000e-03,
+        9.99999750000016e-01,
+        -4.47141661137592e+00,
+        1.00000025000002e+00,
+        7.02368880056238e+00,
+    ),
+    (
+        7,
+        1.00000000000000e-03,
+        1.55009915790861e-27,
+        -2.93354403330081e+25,
+        1.55009925478981e-27,
+        4.60799980800000e+25,
+    ),
+    (
+        30,
+        3.00000000000000e-01,
+        7.22374620691944e-58,
+        -1.46888614053265e+55,
+        7.23423990467738e-58,
+        2.30374340481099e+55,
+    ),
+    (
+        1,
+        2.50000000000000e+00,
+        4.97094102464274e-01,
+        1.45918137966786e-01,
+        2.51671624528870e+00,
+        7.38908163477471e-02,
+    ),
+    (
+        60,
+        2.50000000000000e+00,
+        7.64362347439844e-77,
+        -6.94667556326255e+73,
+        8.04540629070545e-77,
+        1.03488955618726e+74,
+    ),
+    (
+        120,
+        5.00000000000000e+00,
+        8.03470388616231e-152,
+        -3.30427626807569e+148,
+        8.90912420250858e-152,
+        4.67279850075960e+148,
+    ),
+    (
+        0,
+        5.00000000000000e+01,
+        5.58123276692518e-02,
+        -9.80649954700771e-02,
+        2.93255378384934e+20,
+        3.41016774978950e-23,
+    ),
+    (
+        49,
+        5.00000000000000e+01,
+        1.51195142521472e-01,
+        -1.57043073971458e-01,
+        4.25250261199881e+10,
+        1.67948014751121e-13,
+    ),
+    (
+        200,
+        5.00000000000000e+01,
+        2.13836900423912e-97,
+        -7.68691822527897e+93,
+        1.07484030616998e-94,
+        2.25647910800708e+91,
+    ),
+    (
+        150,
+        1.50000000000000e+02,
+        8.41850578834028e-02,
+        -1.45817598757581e-01,
+        1.40951041751414e+33,
+        1.67222438674521e-36,
+    ),
+    (
+        1,
+        7.00000000000000e+02,
+        2.94898240840303e-02,
+        6.30934142145256e-03,
+        1.52850039023390e+302,
+        4.67311079670797e-306,
+    ),
+    (
+        600,
+        8.00000000000000e+02,
+        3.08330351878905e-02,
+        1.58875141814002e-02,
+        5.98985447860898e+251,
+        8.34744766814558e-256,
+    ),
+    (
+        1000,
+        8.00000000000000e+02,
+        5.73061491532417e-43,
+        -9.25765744934431e+38,
+        1.78500065135547e+99,
+        2.18730665802409e-103,
+    ),
+];
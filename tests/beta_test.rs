@@ -1,5 +1,10 @@
+#![allow(clippy::approx_constant)]
+
 use approx::assert_relative_eq;
-use puruspe::{beta, betai, invbetai};
+use puruspe::{
+    beta, betai, betai_grad, betaic, invbetai, invbetaic, ln_beta, ln_betai, try_betai, try_betaic,
+    SpecialFunctionError,
+};
 
 #[test]
 fn test_beta() {
@@ -21,6 +26,164 @@ fn test_betai() {
     }
 }
 
+#[test]
+fn test_ln_beta() {
+    for &(a, b, expected) in LN_BETA_TABLE.iter() {
+        let result = ln_beta(a, b);
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, expected, max_relative = rel_eps);
+        assert_eq!(result, ln_beta(b, a));
+    }
+}
+
+#[test]
+fn test_ln_betai() {
+    for &(a, b, x, expected) in LN_BETAI_TABLE.iter() {
+        let result = ln_betai(a, b, x);
+        // Parameters above 3000 go through Gauss-Legendre quadrature near the mean.
+        let rel_eps = 1e-10;
+        assert_relative_eq!(result, expected, max_relative = rel_eps);
+    }
+    assert_eq!(ln_betai(2.0, 3.0, 0.0), f64::NEG_INFINITY);
+    assert_eq!(ln_betai(2.0, 3.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_betai_large_parameters() {
+    // Regression test for the quadrature used when both parameters exceed 3000
+    assert_relative_eq!(
+        betai(1e4, 1e4, 0.49),
+        2.337_059_330_110_168e-3,
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        betai(5e3, 6e3, 0.46),
+        8.746_675_582_332_058e-1,
+        max_relative = 1e-10
+    );
+    // More than 5 standard deviations from the mean the continued fraction is used,
+    // since the quadrature loses accuracy in the far tails.
+    assert_relative_eq!(
+        ln_betai(3000.5, 3001.0, 0.3),
+        -527.332_359_304_052_9,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        betai(3000.5, 3001.0, 0.3),
+        9.604_311_234_365_962e-230,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        betaic(3001.0, 3000.5, 0.7),
+        9.604_311_234_365_962e-230,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        betai(1e4, 1e4, 0.45),
+        6.312_654_777_749_922e-46,
+        max_relative = 1e-11
+    );
+}
+
+#[test]
+fn test_betai_prefactor() {
+    // Regression test for the prefactor 1 / B(a,b), which is taken from ln_beta.
+    // The difference of the log-gamma functions is off by up to 2e-7 here.
+    assert_relative_eq!(
+        betai(2.0, 1e8, 2e-8),
+        5.939_941_584_102_79e-1,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        betai(0.5, 1e8, 1e-8),
+        8.427_007_934_685_992e-1,
+        max_relative = 1e-13
+    );
+    // The same for the quadrature, which was off by 1.4e-8 relative here.
+    assert_relative_eq!(
+        betai(1e6, 2e6, 0.333_061_167_851_718_3),
+        1.586_552_875_409_032e-1,
+        max_relative = 1e-9
+    );
+    // (1 - x).ln() instead of (-x).ln_1p() in the prefactor was off by 8e-8 here.
+    // I_x(1,b) = 1 - (1 - x)^b
+    assert_relative_eq!(
+        betai(1.0, 1e9, 1e-10),
+        9.516_258_196_856_461e-2,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bad x in routine ln_betai")]
+fn test_ln_betai_bad_x() {
+    ln_betai(2.0, 3.0, -0.5);
+}
+
+#[test]
+fn test_betaic() {
+    for &(a, b, x, expected) in BETAIC_TABLE.iter() {
+        let result = betaic(a, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    // Symmetry with betai
+    for &(a, b, x, _) in BETAI_TABLE.iter() {
+        assert_relative_eq!(betaic(a, b, x), betai(b, a, 1.0 - x), max_relative = 1e-12);
+    }
+    assert_eq!(betaic(2.0, 3.0, 0.0), 1.0);
+    assert_eq!(betaic(2.0, 3.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_betai_near_one() {
+    // Regression test for the choice between the continued fraction and its reflection
+    assert_relative_eq!(
+        1.0 - betai(0.5, 0.5, 0.9999999),
+        betai(0.5, 0.5, 1.0 - 0.9999999),
+        max_relative = 1e-8
+    );
+    // The reflection is used above (a + 1) / (a + b + 2). With (a + 1) / (a + 2b) instead,
+    // every x < 1 went to the continued fraction for I_x(2, 0.5), which does not converge this close to 1.
+    assert_relative_eq!(
+        betaic(2.0, 0.5, 1.0 - 1e-12),
+        1.499_983_408_617_651e-6,
+        max_relative = 1e-13
+    );
+}
+
+#[test]
+fn test_try_betai() {
+    for &(a, b, x, _) in BETAI_TABLE.iter() {
+        assert_eq!(try_betai(a, b, x), Ok(betai(a, b, x)));
+    }
+    assert_eq!(
+        try_betai(-1.0, 2.0, 0.5),
+        Err(SpecialFunctionError::Domain("Bad a or b in routine betai"))
+    );
+    assert_eq!(
+        try_betai(1.0, 2.0, 1.5),
+        Err(SpecialFunctionError::Domain("Bad x in routine betai"))
+    );
+    assert!(try_betai(1.0, 2.0, f64::NAN).is_err());
+}
+
+#[test]
+fn test_try_betaic() {
+    for &(a, b, x, _) in BETAIC_TABLE.iter() {
+        assert_eq!(try_betaic(a, b, x), Ok(betaic(a, b, x)));
+    }
+    assert_eq!(
+        try_betaic(1.0, 0.0, 0.5),
+        Err(SpecialFunctionError::Domain("Bad a or b in routine betaic"))
+    );
+    assert_eq!(
+        try_betaic(1.0, 2.0, -0.5),
+        Err(SpecialFunctionError::Domain("Bad x in routine betaic"))
+    );
+}
+
 #[test]
 fn test_invbetai() {
     for &(a, b, p, expected) in INVBETAI_TABLE.iter() {
@@ -31,6 +194,49 @@ fn test_invbetai() {
     }
 }
 
+#[test]
+fn test_invbetaic() {
+    for &(a, b, q, expected) in INVBETAIC_TABLE.iter() {
+        let result = invbetaic(q, a, b);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(invbetaic(0.0, 2.0, 3.0), 1.0);
+    assert_eq!(invbetaic(1.0, 2.0, 3.0), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Bad a or b in routine invbetaic")]
+fn test_invbetaic_bad_parameters() {
+    invbetaic(0.5, -1.0, 3.0);
+}
+
+#[test]
+fn test_betai_grad() {
+    for &(a, b, x, expected, da, db) in BETAI_GRAD_TABLE.iter() {
+        let (result, dresult_a, dresult_b) = betai_grad(a, b, x);
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, expected, max_relative = rel_eps);
+        assert_relative_eq!(dresult_a, da, max_relative = rel_eps);
+        assert_relative_eq!(dresult_b, db, max_relative = rel_eps);
+        assert_relative_eq!(
+            result,
+            betai(a, b, x),
+            epsilon = 1e-15,
+            max_relative = 1e-12
+        );
+    }
+    assert_eq!(betai_grad(2.0, 3.0, 0.0), (0.0, 0.0, 0.0));
+    assert_eq!(betai_grad(2.0, 3.0, 1.0), (1.0, 0.0, 0.0));
+}
+
+#[test]
+#[should_panic(expected = "Bad x in routine betai_grad")]
+fn test_betai_grad_bad_x() {
+    betai_grad(2.0, 3.0, 1.5);
+}
+
 const BETA_TABLE: [(f64, f64, f64); 7] = [
     (
         1.00000000000000e-01,
@@ -578,3 +784,1243 @@ const INVBETAI_TABLE: [(f64, f64, f64, f64); 49] = [
         7.46046920485483e-01,
     ),
 ];
+
+const BETAI_GRAD_TABLE: [(f64, f64, f64, f64, f64, f64); 38] = [
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        4.06385093936276e-01,
+        -2.88127215141469e+00,
+        2.08271696871769e+00,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        2.50000000000000e-01,
+        4.51957853676650e-01,
+        -2.73044416723336e+00,
+        2.30833761069163e+00,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -2.53252208286405e+00,
+        2.53252208286405e+00,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        7.50000000000000e-01,
+        5.48042146323350e-01,
+        -2.30833761069163e+00,
+        2.73044416723336e+00,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        9.00000000000000e-01,
+        5.93614906063724e-01,
+        -2.08271696871769e+00,
+        2.88127215141469e+00,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        2.04832764699133e-01,
+        -5.92620022143379e-01,
+        2.76815524358351e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        3.33333333333333e-01,
+        -6.46131894438901e-01,
+        4.30754596292601e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -5.83121808061638e-01,
+        5.83121808061638e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        6.66666666666667e-01,
+        -4.30754596292601e-01,
+        6.46131894438901e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        7.95167235300867e-01,
+        -2.76815524358351e-01,
+        5.92620022143379e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        -2.30258509299405e-01,
+        9.48244640920437e-02,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        2.50000000000000e-01,
+        2.50000000000000e-01,
+        -3.46573590279973e-01,
+        2.15761554338836e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -3.46573590279973e-01,
+        3.46573590279973e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        7.50000000000000e-01,
+        7.50000000000000e-01,
+        -2.15761554338836e-01,
+        3.46573590279973e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        9.00000000000000e-01,
+        9.00000000000000e-01,
+        -9.48244640920436e-02,
+        2.30258509299405e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-01,
+        2.80000000000000e-02,
+        -5.54723826038333e-02,
+        2.14104212194072e-02,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        2.50000000000000e-01,
+        1.56250000000000e-01,
+        -1.69733493924983e-01,
+        1.02106748631190e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -2.21573590279973e-01,
+        2.21573590279973e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        7.50000000000000e-01,
+        8.43750000000000e-01,
+        -1.02106748631190e-01,
+        1.69733493924983e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        9.00000000000000e-01,
+        9.72000000000000e-01,
+        -2.14104212194072e-02,
+        5.54723826038333e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-01,
+        6.83357084979988e-01,
+        -6.21626449967802e-01,
+        5.07788140533457e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        2.50000000000000e-01,
+        9.02145385742188e-01,
+        -2.47221092414723e-01,
+        3.51851847548721e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        9.89880440264566e-01,
+        -3.05859245357930e-02,
+        7.86711556329843e-03,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        7.50000000000000e-01,
+        9.99729704252745e-01,
+        -9.02543794117163e-04,
+        3.99258404876484e-04,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        9.00000000000000e-01,
+        9.99997429410301e-01,
+        -8.96457066048827e-06,
+        6.15943622568221e-06,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        2.57058969922937e-06,
+        -6.15943622568222e-06,
+        8.96457066048828e-06,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        2.70295747254618e-04,
+        -3.99258404876484e-04,
+        9.02543794117163e-04,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.01195597354337e-02,
+        -7.86711556329843e-03,
+        3.05859245357930e-02,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        9.78546142578125e-02,
+        -3.51851847548721e-02,
+        2.47221092414723e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        3.16642915020012e-01,
+        -5.07788140533457e-02,
+        6.21626449967802e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-01,
+        3.92988232712800e-06,
+        -6.65135210098558e-06,
+        2.45305948971591e-06,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        2.50000000000000e-01,
+        8.90327930392232e-03,
+        -7.08920925397540e-03,
+        4.16704794598211e-03,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -9.10929571952365e-02,
+        9.10929571952365e-02,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        7.50000000000000e-01,
+        9.91096720696078e-01,
+        -4.16704794598211e-03,
+        7.08920925397540e-03,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        9.00000000000000e-01,
+        9.99996070117673e-01,
+        -2.45305948971590e-06,
+        6.65135210098556e-06,
+    ),
+    (
+        1.00000000000000e-01,
+        8.00000000000000e+00,
+        1.00000000000000e-02,
+        8.06781714161724e-01,
+        -1.78283935306917e+00,
+        9.95708383967375e-03,
+    ),
+    (
+        5.00000000000000e+01,
+        6.00000000000000e+01,
+        4.50000000000000e-01,
+        4.64235291430604e-01,
+        -4.18558386940092e-02,
+        3.45426524339469e-02,
+    ),
+    (
+        5.00000000000000e+00,
+        2.00000000000000e+00,
+        9.90000000000000e-01,
+        9.98539552395000e-01,
+        -5.25757365574750e-04,
+        5.34473942899372e-03,
+    ),
+];
+
+const LN_BETA_TABLE: [(f64, f64, f64); 15] = [
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        2.98136148103763e+00,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.14472988584940e+00,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        0.00000000000000e+00,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        -1.79175946922805e+00,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        -2.07395194346071e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        -2.07395194346071e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        -1.37362292270366e+01,
+    ),
+    (
+        1.00000000000000e-02,
+        5.00000000000000e+01,
+        4.56045897130909e+00,
+    ),
+    (
+        3.00000000000000e+00,
+        1.00000000000000e+05,
+        -3.38456592141007e+01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+10,
+        -2.30258509299405e+01,
+    ),
+    (
+        1.00000000000000e-05,
+        1.00000000000000e+20,
+        1.15124591758772e+01,
+    ),
+    (
+        9.90000000000000e+00,
+        1.00000000000000e+01,
+        -1.36640811969349e+01,
+    ),
+    (
+        5.00000000000000e+01,
+        6.00000000000000e+01,
+        -7.65227233533505e+01,
+    ),
+    (
+        1.00000000000000e+03,
+        1.00000000000000e+08,
+        -1.25154653157265e+04,
+    ),
+    (
+        1.00000000000000e+150,
+        1.00000000000000e+150,
+        -1.38629436111989e+150,
+    ),
+];
+
+const LN_BETAI_TABLE: [(f64, f64, f64, f64); 45] = [
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        -9.00454061696938e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        2.50000000000000e-01,
+        -7.94166347575614e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        5.00000000000000e-01,
+        -6.93147180559945e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        7.50000000000000e-01,
+        -6.01403085642315e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        9.00000000000000e-01,
+        -5.21524476150914e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        -1.58556141473035e+00,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        -1.09861228866811e+00,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -6.93147180559945e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        -4.05465108108164e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        -2.29202827581115e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        1.00000000000000e-01,
+        -2.30258509299405e+00,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        2.50000000000000e-01,
+        -1.38629436111989e+00,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        5.00000000000000e-01,
+        -6.93147180559945e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        7.50000000000000e-01,
+        -2.87682072451781e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        9.00000000000000e-01,
+        -1.05360515657826e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-01,
+        -3.57555076880693e+00,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        2.50000000000000e-01,
+        -1.85629799036563e+00,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        5.00000000000000e-01,
+        -6.93147180559945e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        7.50000000000000e-01,
+        -1.69899036795397e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        9.00000000000000e-01,
+        -2.83994745216980e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-01,
+        -3.80737737614557e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        2.50000000000000e-01,
+        -1.02979590375502e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        -1.01711105560012e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        7.50000000000000e-01,
+        -2.70332283734028e-04,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        9.00000000000000e-01,
+        -2.57059300320073e-06,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        -1.28713752304248e+01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        -8.21599383823288e+00,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        -4.59328512047294e+00,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        -2.32427242981026e+00,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        -1.14998059106865e+00,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-01,
+        -1.24469010747380e+01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        2.50000000000000e-01,
+        -4.72133560899430e+00,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        5.00000000000000e-01,
+        -6.93147180559945e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        7.50000000000000e-01,
+        -8.94315032676167e-03,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        9.00000000000000e-01,
+        -3.92989004913578e-06,
+    ),
+    (
+        2.00000000000000e+00,
+        3.00000000000000e+00,
+        1.00000000000000e-200,
+        -9.19242277728390e+02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.00000000000000e-300,
+        -3.45839346654396e+02,
+    ),
+    (
+        5.00000000000000e+01,
+        6.00000000000000e+01,
+        1.00000000000000e-01,
+        -4.85980055854249e+01,
+    ),
+    (
+        1.00000000000000e+02,
+        1.00000000000000e+00,
+        2.00000000000000e-01,
+        -1.60943791243410e+02,
+    ),
+    (
+        2.00000000000000e+00,
+        3.00000000000000e+00,
+        9.99999000000000e-01,
+        -3.99999700034507e-18,
+    ),
+    (
+        1.00000000000000e+04,
+        1.00000000000000e+04,
+        3.00000000000000e-01,
+        -1.74848853782714e+03,
+    ),
+    (
+        1.00000000000000e+04,
+        1.00000000000000e+04,
+        4.90000000000000e-01,
+        -6.05886183645193e+00,
+    ),
+    (
+        1.00000000000000e+04,
+        1.00000000000000e+04,
+        5.50000000000000e-01,
+        -6.31265477774853e-46,
+    ),
+    (
+        5.00000000000000e+03,
+        6.00000000000000e+03,
+        1.00000000000000e-01,
+        -4.57121916538997e+03,
+    ),
+    (
+        4.00000000000000e+03,
+        3.50000000000000e+03,
+        5.00000000000000e-01,
+        -1.93815388641426e+01,
+    ),
+];
+
+const BETAIC_TABLE: [(f64, f64, f64, f64); 41] = [
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        5.93614906063724e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        2.50000000000000e-01,
+        5.48042146323350e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        7.50000000000000e-01,
+        4.51957853676650e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        9.00000000000000e-01,
+        4.06385093936276e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        7.95167235300867e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        6.66666666666667e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        3.33333333333333e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        2.04832764699133e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        1.00000000000000e-01,
+        9.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        2.50000000000000e-01,
+        7.50000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        7.50000000000000e-01,
+        2.50000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        9.00000000000000e-01,
+        1.00000000000000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-01,
+        9.72000000000000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        2.50000000000000e-01,
+        8.43750000000000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        7.50000000000000e-01,
+        1.56250000000000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        9.00000000000000e-01,
+        2.80000000000000e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-01,
+        3.16642915020012e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        2.50000000000000e-01,
+        9.78546142578125e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        1.01195597354337e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        7.50000000000000e-01,
+        2.70295747254618e-04,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        9.00000000000000e-01,
+        2.57058969922937e-06,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        9.99997429410301e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        9.99729704252745e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.89880440264566e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        9.02145385742188e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        6.83357084979988e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-01,
+        9.99996070117673e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        2.50000000000000e-01,
+        9.91096720696078e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        7.50000000000000e-01,
+        8.90327930392232e-03,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        9.00000000000000e-01,
+        3.92988232712799e-06,
+    ),
+    (
+        2.00000000000000e+00,
+        3.00000000000000e+00,
+        9.99000000000000e-01,
+        3.99700000000001e-09,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.99999900000000e-01,
+        2.01316851720247e-04,
+    ),
+    (
+        1.00000000000000e+01,
+        5.00000000000000e+00,
+        9.90000000000000e-01,
+        1.85689428386355e-07,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e+01,
+        1.00000000000000e-08,
+        7.91224628551908e-01,
+    ),
+    (
+        5.00000000000000e+01,
+        6.00000000000000e+01,
+        9.00000000000000e-01,
+        1.79300353823822e-31,
+    ),
+    (
+        5.00000000000000e+01,
+        6.00000000000000e+01,
+        9.99000000000000e-01,
+        0.00000000000000e+00,
+    ),
+];
+
+const INVBETAIC_TABLE: [(f64, f64, f64, f64); 55] = [
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        9.99999911307200e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        2.50000000000000e-01,
+        9.99154744467153e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        7.50000000000000e-01,
+        8.45255532847130e-04,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        9.00000000000000e-01,
+        8.86928001193456e-08,
+    ),
+    (
+        1.00000000000000e-01,
+        1.00000000000000e-01,
+        9.90000000000000e-01,
+        8.86928065555031e-18,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.00000000000000e-02,
+        9.99753280182866e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        9.75528258147577e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        8.53553390593274e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        1.46446609406726e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        2.44717418524232e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.90000000000000e-01,
+        2.46719817134222e-04,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        1.00000000000000e-02,
+        9.90000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        1.00000000000000e-01,
+        9.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        2.50000000000000e-01,
+        7.50000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        7.50000000000000e-01,
+        2.50000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        9.00000000000000e-01,
+        1.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        9.90000000000000e-01,
+        1.00000000000000e-02,
+    ),
+    (
+        1.00000000000000e+00,
+        1.00000000000000e+00,
+        1.00000000000000e-10,
+        9.99999999900000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-02,
+        9.41096864221805e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-01,
+        8.04199894340908e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        2.50000000000000e-01,
+        6.73648177666930e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        7.50000000000000e-01,
+        3.26351822333070e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        9.00000000000000e-01,
+        1.95800105659092e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        9.90000000000000e-01,
+        5.89031357781953e-02,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-10,
+        9.99994226486197e-01,
+    ),
+    (
+        2.00000000000000e+00,
+        2.00000000000000e+00,
+        1.00000000000000e-20,
+        9.99999999942265e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-02,
+        5.01104785327943e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-01,
+        2.47272226802556e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        2.50000000000000e-01,
+        1.29788914955044e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        4.66872453369664e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        7.50000000000000e-01,
+        1.06155428458617e-02,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        9.00000000000000e-01,
+        1.65851285512318e-03,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        9.90000000000000e-01,
+        1.65126948488293e-05,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-10,
+        9.86777956234516e-01,
+    ),
+    (
+        5.00000000000000e-01,
+        5.00000000000000e+00,
+        1.00000000000000e-20,
+        9.99867634412245e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        1.00000000000000e-02,
+        9.99983487305151e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        1.00000000000000e-01,
+        9.98341487144877e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        2.50000000000000e-01,
+        9.89384457154138e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+        9.53312754663034e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        7.50000000000000e-01,
+        8.70211085044956e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        9.00000000000000e-01,
+        7.52727773197444e-01,
+    ),
+    (
+        5.00000000000000e+00,
+        5.00000000000000e-01,
+        9.90000000000000e-01,
+        4.98895214672057e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-02,
+        7.46046920485483e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-01,
+        6.42070119806542e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        2.50000000000000e-01,
+        5.75913467720320e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        5.00000000000000e-01,
+        5.00000000000000e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        7.50000000000000e-01,
+        4.24086532279680e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        9.00000000000000e-01,
+        3.57929880193458e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        9.90000000000000e-01,
+        2.53953079514517e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-10,
+        9.67247416100692e-01,
+    ),
+    (
+        1.00000000000000e+01,
+        1.00000000000000e+01,
+        1.00000000000000e-20,
+        9.96804195103873e-01,
+    ),
+];
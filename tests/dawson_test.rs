@@ -1,3 +1,6 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
 use puruspe::dawson;
 
 #[test]
@@ -7,3 +10,36 @@ fn dawson_test() {
     dbg!(expected, res);
     assert!((expected - res).abs() < 1e-7); // Verify accuracy.
 }
+
+#[test]
+fn dawson_table_test() {
+    // Regression table for Rybicki's method, which was wrong for |x| ≥ 0.2 when its loop
+    // advanced the counters before the first term, and for the switches to the other methods.
+    for (x, expected) in DAWSON_TABLE {
+        assert_relative_eq!(dawson(x), expected, max_relative = 1e-15);
+    }
+}
+
+const DAWSON_TABLE: [(f64, f64); 21] = [
+    (1.0000000000000000e-10, 1.0000000000000000e-10),
+    (1.0000000000000001e-01, 9.9335992397852860e-02),
+    (2.0000000000000001e-01, 1.9475103336802807e-01),
+    (2.9999999999999999e-01, 2.8263166502131193e-01),
+    (4.9990000000000001e-01, 4.2437882001833693e-01),
+    (5.0000000000000000e-01, 4.2443638350202229e-01),
+    (9.2410000000000003e-01, 5.4104422381758455e-01),
+    (1.5000000000000000e+00, 4.2824907108539861e-01),
+    (2.5000000000000000e+00, 2.2308372216743549e-01),
+    (4.0000000000000000e+00, 1.2934800123600512e-01),
+    (1.0000000000000000e+01, 5.0253847187598531e-02),
+    (1.2300000000000000e+02, 4.0651750097083399e-03),
+    (1.0000000000000000e+05, 5.0000000002499996e-06),
+    (9.9000000000000000e+07, 5.0505050505050511e-09),
+    (1.0100000000000000e+08, 4.9504950495049509e-09),
+    (1.0000000000000000e+09, 5.0000000000000003e-10),
+    (-2.0000000000000001e-01, -1.9475103336802807e-01),
+    (-6.9999999999999996e-01, -5.1050405755923178e-01),
+    (-3.0000000000000000e+00, -1.7827103061055830e-01),
+    (-1.0000000000000000e+05, -5.0000000002499996e-06),
+    (-1.0000000000000000e+09, -5.0000000000000003e-10),
+];
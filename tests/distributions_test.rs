@@ -0,0 +1,3026 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use puruspe::{
+    Beta, Binomial, ChiSquared, ContinuousDistribution, DiscreteDistribution, FisherF, Gamma,
+    Normal, Poisson, SpecialFunctionError, StudentT,
+};
+
+/// Compares the log-density, the distribution function and the survival function.
+fn check_continuous<D: ContinuousDistribution>(
+    dist: &D,
+    x: f64,
+    (ln_pdf, cdf, sf): (f64, f64, f64),
+    rel_eps: f64,
+) {
+    assert_relative_eq!(
+        dist.ln_pdf(x),
+        ln_pdf,
+        epsilon = 1e-14,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(dist.pdf(x), ln_pdf.exp(), max_relative = 1e-11);
+    // No absolute tolerance, so that the far tails are compared relative to their tiny values
+    assert_relative_eq!(dist.cdf(x), cdf, epsilon = 0.0, max_relative = rel_eps);
+    assert_relative_eq!(dist.sf(x), sf, epsilon = 0.0, max_relative = rel_eps);
+}
+
+fn check_quantile<D: ContinuousDistribution>(dist: &D, p: f64, x: f64) {
+    assert_relative_eq!(dist.quantile(p), x, epsilon = 1e-13, max_relative = 1e-9);
+}
+
+#[test]
+fn test_normal() {
+    for (mean, std_dev, x, ln_pdf, cdf, sf) in NORMAL_TABLE {
+        check_continuous(&Normal::new(mean, std_dev), x, (ln_pdf, cdf, sf), 1e-11);
+    }
+    for (mean, std_dev, p, x) in NORMAL_QUANTILE_TABLE {
+        check_quantile(&Normal::new(mean, std_dev), p, x);
+    }
+}
+
+#[test]
+fn test_chi_squared() {
+    for (dof, x, ln_pdf, cdf, sf) in CHI_SQUARED_TABLE {
+        check_continuous(&ChiSquared::new(dof), x, (ln_pdf, cdf, sf), 1e-11);
+    }
+    for (dof, p, x) in CHI_SQUARED_QUANTILE_TABLE {
+        check_quantile(&ChiSquared::new(dof), p, x);
+    }
+}
+
+#[test]
+fn test_gamma() {
+    for (shape, scale, x, ln_pdf, cdf, sf) in GAMMA_TABLE {
+        check_continuous(&Gamma::new(shape, scale), x, (ln_pdf, cdf, sf), 1e-11);
+    }
+    for (shape, scale, p, x) in GAMMA_QUANTILE_TABLE {
+        check_quantile(&Gamma::new(shape, scale), p, x);
+    }
+}
+
+#[test]
+fn test_beta() {
+    for (alpha, beta, x, ln_pdf, cdf, sf) in BETA_TABLE {
+        check_continuous(&Beta::new(alpha, beta), x, (ln_pdf, cdf, sf), 1e-11);
+    }
+    for (alpha, beta, p, x) in BETA_QUANTILE_TABLE {
+        check_quantile(&Beta::new(alpha, beta), p, x);
+    }
+}
+
+#[test]
+fn test_student_t() {
+    for (dof, x, ln_pdf, cdf, sf) in STUDENT_T_TABLE {
+        // The continued fraction of the incomplete beta function loses a few digits for ν = 1e6.
+        let rel_eps = if dof > 1e3 { 1e-10 } else { 1e-11 };
+        check_continuous(&StudentT::new(dof), x, (ln_pdf, cdf, sf), rel_eps);
+    }
+    for (dof, p, x) in STUDENT_T_QUANTILE_TABLE {
+        check_quantile(&StudentT::new(dof), p, x);
+    }
+}
+
+#[test]
+fn test_fisher_f() {
+    for (dof1, dof2, x, ln_pdf, cdf, sf) in FISHER_F_TABLE {
+        check_continuous(&FisherF::new(dof1, dof2), x, (ln_pdf, cdf, sf), 1e-11);
+    }
+    for (dof1, dof2, p, x) in FISHER_F_QUANTILE_TABLE {
+        check_quantile(&FisherF::new(dof1, dof2), p, x);
+    }
+}
+
+#[test]
+fn test_poisson() {
+    for (lambda, k, ln_pmf, cdf, sf) in POISSON_TABLE {
+        let dist = Poisson::new(lambda);
+        assert_relative_eq!(
+            dist.ln_pmf(k),
+            ln_pmf,
+            epsilon = 1e-14,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(dist.cdf(k), cdf, epsilon = 0.0, max_relative = 1e-11);
+        assert_relative_eq!(dist.sf(k), sf, epsilon = 0.0, max_relative = 1e-11);
+    }
+    for (lambda, p, k) in POISSON_QUANTILE_TABLE {
+        assert_eq!(Poisson::new(lambda).quantile(p), k);
+    }
+}
+
+#[test]
+fn test_binomial() {
+    for (n, p, k, ln_pmf, cdf, sf) in BINOMIAL_TABLE {
+        let dist = Binomial::new(n, p);
+        assert_relative_eq!(
+            dist.ln_pmf(k),
+            ln_pmf,
+            epsilon = 1e-14,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(dist.cdf(k), cdf, epsilon = 0.0, max_relative = 1e-11);
+        assert_relative_eq!(dist.sf(k), sf, epsilon = 0.0, max_relative = 1e-11);
+    }
+    for (n, p, q, k) in BINOMIAL_QUANTILE_TABLE {
+        assert_eq!(Binomial::new(n, p).quantile(q), k);
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_distribution_edge_cases() {
+    // Outside of the support
+    assert_eq!(Gamma::new(2.0, 1.0).pdf(-1.0), 0.0);
+    assert_eq!(Gamma::new(2.0, 1.0).cdf(-1.0), 0.0);
+    assert_eq!(Beta::new(2.0, 3.0).ln_pdf(1.5), f64::NEG_INFINITY);
+    assert_eq!(Beta::new(2.0, 3.0).sf(1.5), 0.0);
+    assert_eq!(Binomial::new(10, 0.5).pmf(11), 0.0);
+    assert_eq!(Binomial::new(10, 0.5).sf(10), 0.0);
+
+    // Far tails, where gammp and gammq round to 0 or 1
+    assert_relative_eq!(
+        ChiSquared::new(2.0).sf(90.0),
+        2.8625185805493936e-20,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        Poisson::new(100.0).cdf(10),
+        1.1376879516952979e-30,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        Gamma::new(0.3, 1.0).sf(1e-20),
+        0.99999888575749141,
+        max_relative = 1e-15
+    );
+
+    // Densities at the origin
+    assert_eq!(Gamma::new(1.0, 2.0).pdf(0.0), 0.5);
+    assert_eq!(Gamma::new(0.5, 2.0).pdf(0.0), f64::INFINITY);
+    assert_eq!(ChiSquared::new(3.0).pdf(0.0), 0.0);
+    assert_eq!(FisherF::new(2.0, 5.0).pdf(0.0), 1.0);
+    assert_eq!(Beta::new(1.0, 1.0).pdf(0.0), 1.0);
+
+    // Quantiles at the ends of the support
+    assert_eq!(Normal::standard().quantile(0.0), f64::NEG_INFINITY);
+    assert_eq!(StudentT::new(4.0).quantile(1.0), f64::INFINITY);
+    assert_eq!(StudentT::new(4.0).quantile(0.5), 0.0);
+    assert_eq!(Beta::new(2.0, 3.0).quantile(1.0), 1.0);
+    assert_eq!(Poisson::new(2.0).quantile(1.0), f64::INFINITY);
+    assert_eq!(Binomial::new(7, 0.2).quantile(1.0), 7.0);
+    assert_eq!(Binomial::new(7, 0.2).quantile(0.0), 0.0);
+    assert_eq!(Binomial::new(7, 0.2).quantile(-0.5), 0.0);
+    assert_eq!(Binomial::new(5, 1.0).quantile(0.0), 0.0);
+    assert_eq!(Poisson::new(2.0).quantile(0.0), 0.0);
+
+    // Degenerate binomial distributions
+    assert_eq!(Binomial::new(5, 0.0).pmf(0), 1.0);
+    assert_eq!(Binomial::new(5, 1.0).pmf(5), 1.0);
+    assert_eq!(Binomial::new(5, 1.0).quantile(0.3), 5.0);
+
+    // NaN arguments
+    let continuous: [&dyn ContinuousDistribution; 6] = [
+        &Normal::standard(),
+        &Gamma::new(2.0, 1.0),
+        &ChiSquared::new(3.0),
+        &Beta::new(2.0, 3.0),
+        &StudentT::new(4.0),
+        &FisherF::new(2.0, 5.0),
+    ];
+    for dist in continuous.iter() {
+        assert!(dist.cdf(f64::NAN).is_nan());
+        assert!(dist.sf(f64::NAN).is_nan());
+        assert!(dist.quantile(f64::NAN).is_nan());
+    }
+    assert!(Poisson::new(2.0).quantile(f64::NAN).is_nan());
+    assert!(Binomial::new(7, 0.2).quantile(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_quantile_round_trip() {
+    let continuous: [&dyn ContinuousDistribution; 4] = [
+        &Normal::new(2.0, 3.0),
+        &Gamma::new(4.0, 0.5),
+        &StudentT::new(7.0),
+        &FisherF::new(3.0, 9.0),
+    ];
+    for dist in continuous.iter() {
+        for &p in [1e-8, 0.2, 0.6, 0.99].iter() {
+            assert_relative_eq!(dist.cdf(dist.quantile(p)), p, max_relative = 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(
+        Normal::try_new(0.0, -1.0),
+        Err(SpecialFunctionError::Domain("Bad parameters in Normal"))
+    );
+    assert!(ChiSquared::try_new(0.0).is_err());
+    assert!(Gamma::try_new(1.0, f64::NAN).is_err());
+    assert!(Beta::try_new(1.0, f64::INFINITY).is_err());
+    assert!(StudentT::try_new(-3.0).is_err());
+    assert!(StudentT::try_new(f64::INFINITY).is_err());
+    assert!(FisherF::try_new(1.0, 0.0).is_err());
+    assert!(FisherF::try_new(f64::INFINITY, 2.0).is_err());
+    assert!(Poisson::try_new(0.0).is_err());
+    assert!(Binomial::try_new(3, 1.5).is_err());
+    assert_eq!(Gamma::try_new(2.0, 3.0), Ok(Gamma::new(2.0, 3.0)));
+}
+
+#[test]
+#[should_panic(expected = "Bad parameters in StudentT")]
+fn test_student_t_bad_dof() {
+    StudentT::new(0.0);
+}
+
+const NORMAL_TABLE: [(f64, f64, f64, f64, f64, f64); 24] = [
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -3.0000000000000000e+01,
+        -4.5091893853320465e+02,
+        4.9067139271481872e-198,
+        1.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -5.0000000000000000e+00,
+        -1.3418938533204672e+01,
+        2.8665157187919391e-07,
+        9.9999971334842808e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.0000000000000000e+00,
+        -1.4189385332046727e+00,
+        1.5865525393145705e-01,
+        8.4134474606854293e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.0000000000000000e-03,
+        -9.1893903320467274e-01,
+        4.9960105778608893e-01,
+        5.0039894221391101e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -9.1893853320467278e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -1.0439385332046727e+00,
+        6.9146246127401312e-01,
+        3.0853753872598688e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -2.9189385332046727e+00,
+        9.7724986805182079e-01,
+        2.2750131948179209e-02,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        8.0000000000000000e+00,
+        -3.2918938533204674e+01,
+        9.9999999999999933e-01,
+        6.2209605742717839e-16,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        -7.5000000000000000e+00,
+        -4.4971496572887878e+02,
+        4.9067139271480238e-198,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        0.0000000000000000e+00,
+        -1.2214965728878738e+01,
+        2.8665157187919365e-07,
+        9.9999971334842808e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        1.2000000000000000e+00,
+        -2.1496572887873688e-01,
+        1.5865525393145702e-01,
+        8.4134474606854304e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        1.4997000000000000e+00,
+        2.8503377112126327e-01,
+        4.9960105778608899e-01,
+        5.0039894221391101e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        1.5000000000000000e+00,
+        2.8503427112126328e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        1.6499999999999999e+00,
+        1.6003427112126342e-01,
+        6.9146246127401301e-01,
+        3.0853753872598699e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        2.1000000000000001e+00,
+        -1.7149657288787374e+00,
+        9.7724986805182079e-01,
+        2.2750131948179188e-02,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        3.8999999999999999e+00,
+        -3.1714965728878738e+01,
+        9.9999999999999933e-01,
+        6.2209605742717839e-16,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -1.4000000000000000e+02,
+        -4.5230523289432455e+02,
+        4.9067139271481872e-198,
+        1.0000000000000000e+00,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -4.0000000000000000e+01,
+        -1.4805232894324563e+01,
+        2.8665157187919391e-07,
+        9.9999971334842808e-01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -2.4000000000000000e+01,
+        -2.8052328943245635e+00,
+        1.5865525393145705e-01,
+        8.4134474606854293e-01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -2.0004000000000001e+01,
+        -2.3052333943245635e+00,
+        4.9960105778608882e-01,
+        5.0039894221391124e-01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -2.0000000000000000e+01,
+        -2.3052328943245635e+00,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -1.8000000000000000e+01,
+        -2.4302328943245635e+00,
+        6.9146246127401312e-01,
+        3.0853753872598688e-01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        -1.2000000000000000e+01,
+        -4.3052328943245630e+00,
+        9.7724986805182079e-01,
+        2.2750131948179209e-02,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        1.2000000000000000e+01,
+        -3.4305232894324561e+01,
+        9.9999999999999933e-01,
+        6.2209605742717839e-16,
+    ),
+];
+
+const NORMAL_QUANTILE_TABLE: [(f64, f64, f64, f64); 27] = [
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9999999999999998e-13,
+        -7.0344838253011321e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e-03,
+        -3.0902323061678136e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.0000000000000003e-02,
+        -1.6448536269514726e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        -5.2440051270804078e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -1.6765425497062624e-51,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        6.9999999999999996e-01,
+        5.2440051270804067e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.4999999999999996e-01,
+        1.6448536269514722e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9900000000000000e-01,
+        3.0902323061678132e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9999999989999999e-01,
+        6.3613408896974217e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        9.9999999999999998e-13,
+        -6.1034514759033953e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        1.0000000000000000e-03,
+        5.7293030814965595e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        5.0000000000000003e-02,
+        1.0065439119145583e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        1.3426798461875877e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        1.5000000000000000e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        6.9999999999999996e-01,
+        1.6573201538124123e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        9.4999999999999996e-01,
+        1.9934560880854417e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        9.9900000000000000e-01,
+        2.4270696918503440e+00,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        9.9999999989999999e-01,
+        3.4084022669092264e+00,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9999999999999998e-13,
+        -4.8137935301204529e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        1.0000000000000000e-03,
+        -3.2360929224671253e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        5.0000000000000003e-02,
+        -2.6579414507805890e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        2.9999999999999999e-01,
+        -2.2097602050832162e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -2.0000000000000000e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        6.9999999999999996e-01,
+        -1.7902397949167838e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.4999999999999996e-01,
+        -1.3420585492194110e+01,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9900000000000000e-01,
+        -7.6390707753287472e+00,
+    ),
+    (
+        -2.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9999999989999999e-01,
+        5.4453635587896878e+00,
+    ),
+];
+
+const CHI_SQUARED_TABLE: [(f64, f64, f64, f64, f64); 28] = [
+    (
+        1.0000000000000000e+00,
+        9.9999999999999995e-07,
+        5.9888162457774641e+00,
+        7.9788442782212517e-04,
+        9.9920211557217786e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000001e-01,
+        1.8235401329235007e-01,
+        2.4817036595415073e-01,
+        7.5182963404584924e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.4189385332046727e+00,
+        6.8268949213708585e-01,
+        3.1731050786291409e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.4189385332046727e+00,
+        6.8268949213708585e-01,
+        3.1731050786291409e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.3000000000000000e+01,
+        -8.7014132119354404e+00,
+        9.9968850902323259e-01,
+        3.1149097676738387e-04,
+    ),
+    (
+        1.0000000000000000e+00,
+        6.0000000000000000e+01,
+        -3.2966110814315726e+01,
+        9.9999999999999056e-01,
+        9.4857375710738478e-15,
+    ),
+    (
+        1.0000000000000000e+00,
+        8.0000000000000000e+01,
+        -4.3109951850541613e+01,
+        1.0000000000000000e+00,
+        3.7440973842028989e-19,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9999999999999995e-07,
+        -6.9314768055994536e-01,
+        4.9999987500002081e-07,
+        9.9999950000012505e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000001e-01,
+        -7.4314718055994533e-01,
+        4.8770575499285991e-02,
+        9.5122942450071402e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.1931471805599454e+00,
+        3.9346934028736658e-01,
+        6.0653065971263342e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -1.6931471805599454e+00,
+        6.3212055882855767e-01,
+        3.6787944117144233e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.6000000000000000e+01,
+        -8.6931471805599454e+00,
+        9.9966453737209748e-01,
+        3.3546262790251185e-04,
+    ),
+    (
+        2.0000000000000000e+00,
+        7.0000000000000000e+01,
+        -3.5693147180559947e+01,
+        9.9999999999999933e-01,
+        6.3051167601469892e-16,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+02,
+        -5.0693147180559947e+01,
+        1.0000000000000000e+00,
+        1.9287498479639178e-22,
+    ),
+    (
+        7.5000000000000000e+00,
+        9.9999999999999995e-07,
+        -4.2078772040094968e+01,
+        1.4170666450102629e-25,
+        1.0000000000000000e+00,
+    ),
+    (
+        7.5000000000000000e+00,
+        1.0000000000000001e-01,
+        -1.0468226511426838e+01,
+        7.6606034290144940e-07,
+        9.9999923393965706e-01,
+    ),
+    (
+        7.5000000000000000e+00,
+        1.0000000000000000e+00,
+        -4.5861175056932115e+00,
+        3.0309093116296687e-03,
+        9.9696909068837036e-01,
+    ),
+    (
+        7.5000000000000000e+00,
+        7.5000000000000000e+00,
+        -2.2951341992019838e+00,
+        5.6871055953910477e-01,
+        4.3128944046089529e-01,
+    ),
+    (
+        7.5000000000000000e+00,
+        3.2500000000000000e+01,
+        -1.0762707260020060e+01,
+        9.9994967704557969e-01,
+        5.0322954420262085e-05,
+    ),
+    (
+        7.5000000000000000e+00,
+        1.2500000000000000e+02,
+        -5.3308254728111883e+01,
+        1.0000000000000000e+00,
+        1.4749182290187949e-23,
+    ),
+    (
+        7.5000000000000000e+00,
+        2.1000000000000000e+02,
+        -9.4381571796220172e+01,
+        1.0000000000000000e+00,
+        2.1040117568929686e-41,
+    ),
+    (
+        1.0000000000000000e+02,
+        9.9999999999999995e-07,
+        -8.5618312081459158e+02,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0000000000000001e-01,
+        -2.9209977253105041e+02,
+        2.7805877168286971e-130,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0000000000000000e+00,
+        -1.7972310297434214e+02,
+        1.7887765104351365e-80,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0000000000000000e+02,
+        -3.5697638609256743e+00,
+        5.1880831547204331e-01,
+        4.8119168452795674e-01,
+    ),
+    (
+        1.0000000000000000e+02,
+        3.1000000000000000e+02,
+        -5.3131060397861745e+01,
+        1.0000000000000000e+00,
+        2.4529530895501660e-23,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0500000000000000e+03,
+        -3.6335237625991527e+02,
+        1.0000000000000000e+00,
+        3.4798207266769104e-158,
+    ),
+    (
+        1.0000000000000000e+02,
+        2.0600000000000000e+03,
+        -8.3533050114694447e+02,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+];
+
+const CHI_SQUARED_QUANTILE_TABLE: [(f64, f64, f64); 36] = [
+    (
+        1.0000000000000000e+00,
+        9.9999999999999998e-13,
+        1.5707963267948966e-24,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-03,
+        1.5707971492624900e-06,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000003e-02,
+        3.9321400000195232e-03,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.4847186183254543e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        4.5493642311957277e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        6.9999999999999996e-01,
+        1.0741941708575851e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.4999999999999996e-01,
+        3.8414588206941245e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.0827566170662731e+01,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9999999989999999e-01,
+        4.1821456202982787e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9999999999999998e-13,
+        2.0000000000010000e-12,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e-03,
+        2.0010006671670670e-03,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000003e-02,
+        1.0258658877510107e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        7.1334988787746467e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.3862943611198906e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        6.9999999999999996e-01,
+        2.4079456086518718e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.4999999999999996e-01,
+        5.9914645471079799e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.3815510557964272e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9999999989999999e-01,
+        4.6051701694400180e+01,
+    ),
+    (
+        7.5000000000000000e+00,
+        9.9999999999999998e-13,
+        2.6694261537093546e-03,
+    ),
+    (
+        7.5000000000000000e+00,
+        1.0000000000000000e-03,
+        7.2297549975068043e-01,
+    ),
+    (
+        7.5000000000000000e+00,
+        5.0000000000000003e-02,
+        2.4462588670359247e+00,
+    ),
+    (
+        7.5000000000000000e+00,
+        2.9999999999999999e-01,
+        5.0980170321167790e+00,
+    ),
+    (
+        7.5000000000000000e+00,
+        5.0000000000000000e-01,
+        6.8449054696010725e+00,
+    ),
+    (
+        7.5000000000000000e+00,
+        6.9999999999999996e-01,
+        8.9552008560478864e+00,
+    ),
+    (
+        7.5000000000000000e+00,
+        9.4999999999999996e-01,
+        1.4791162353525857e+01,
+    ),
+    (
+        7.5000000000000000e+00,
+        9.9900000000000000e-01,
+        2.5230054920678601e+01,
+    ),
+    (
+        7.5000000000000000e+00,
+        9.9999999989999999e-01,
+        6.2157915388115661e+01,
+    ),
+    (
+        1.0000000000000000e+02,
+        9.9999999999999998e-13,
+        3.0084167586161840e+01,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0000000000000000e-03,
+        6.1917939206936623e+01,
+    ),
+    (
+        1.0000000000000000e+02,
+        5.0000000000000003e-02,
+        7.7929465165017263e+01,
+    ),
+    (
+        1.0000000000000000e+02,
+        2.9999999999999999e-01,
+        9.2128944338896702e+01,
+    ),
+    (
+        1.0000000000000000e+02,
+        5.0000000000000000e-01,
+        9.9334129235988456e+01,
+    ),
+    (
+        1.0000000000000000e+02,
+        6.9999999999999996e-01,
+        1.0690576065384511e+02,
+    ),
+    (
+        1.0000000000000000e+02,
+        9.4999999999999996e-01,
+        1.2434211340400408e+02,
+    ),
+    (
+        1.0000000000000000e+02,
+        9.9900000000000000e-01,
+        1.4944925277903872e+02,
+    ),
+    (
+        1.0000000000000000e+02,
+        9.9999999989999999e-01,
+        2.1771420284120470e+02,
+    ),
+];
+
+const GAMMA_TABLE: [(f64, f64, f64, f64, f64, f64); 21] = [
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        9.9999999999999995e-21,
+        3.1140393307098563e+01,
+        1.1142425085473024e-06,
+        9.9999888575749141e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        1.0000000000000001e-05,
+        6.9632398306610845e+00,
+        3.5235360615562580e-02,
+        9.6476463938443746e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        2.0000000000000001e-01,
+        -1.6919145611420533e-01,
+        6.5750672426972179e-01,
+        3.4249327573027827e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -2.0957979948180756e+00,
+        9.1567415624110882e-01,
+        8.4325843758891239e-02,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        -5.5301703178992034e-01,
+        7.2695734371036624e-01,
+        2.7304265628963381e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        2.0600000000000001e+01,
+        -2.3813501747874952e+01,
+        9.9999999995594124e-01,
+        4.4058712792858608e-11,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        6.1200000000000003e+01,
+        -6.5175701027480869e+01,
+        1.0000000000000000e+00,
+        4.8942977889425453e-29,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        4.9999999999999997e-21,
+        -4.5358554679320967e+01,
+        4.9999999999999996e-41,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        5.0000000000000004e-06,
+        -1.0819788284410283e+01,
+        4.9999666667916669e-11,
+        9.9999999995000033e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        -1.1162907318741551e+00,
+        1.7523096306421772e-02,
+        9.8247690369357821e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        -3.0685281944005471e-01,
+        2.6424111765711533e-01,
+        7.3575888234288467e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+        -6.1370563888010943e-01,
+        5.9399415029016189e-01,
+        4.0600584970983805e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.2000000000000000e+01,
+        -2.0128798989092108e+01,
+        9.9999999905621639e-01,
+        9.4378363606977447e-10,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        3.4000000000000000e+01,
+        -6.3087345114263947e+01,
+        1.0000000000000000e+00,
+        2.0268626570804542e-28,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        2.9999999999999997e-20,
+        -1.1611241863239225e+03,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        3.0000000000000004e-05,
+        -3.3219356284606590e+02,
+        6.4468882947763846e-151,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        6.0000000000000009e-01,
+        -9.4709851585198834e+01,
+        1.7848346677404552e-43,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        3.0000000000000000e+00,
+        -5.6883341686780426e+01,
+        2.4664231717319416e-26,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        7.5000000000000000e+01,
+        -3.6303218899436107e+00,
+        5.2660153144365063e-01,
+        4.7339846855634937e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        2.1000000000000000e+02,
+        -2.3919455877595812e+01,
+        9.9999999981517340e-01,
+        1.8482656783258067e-10,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        4.8000000000000000e+02,
+        -9.4079170121168588e+01,
+        1.0000000000000000e+00,
+        4.8874709322278963e-41,
+    ),
+];
+
+const GAMMA_QUANTILE_TABLE: [(f64, f64, f64, f64); 27] = [
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        9.9999999999999998e-13,
+        6.9726990964093373e-41,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        1.0000000000000000e-03,
+        6.9726990967833443e-11,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        5.0000000000000003e-02,
+        3.2110346997229618e-05,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.2726657769948701e-02,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        7.3131135866951899e-02,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        6.9999999999999996e-01,
+        2.5656491332105208e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        9.4999999999999996e-01,
+        1.3723499441008866e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        9.9900000000000000e-01,
+        4.6189360427913337e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        9.9999999989999999e-01,
+        1.9806636767191637e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.9999999999999998e-13,
+        7.0710711452009691e-07,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0000000000000000e-03,
+        2.2701008884744779e-02,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        5.0000000000000003e-02,
+        1.7768075534933103e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        2.9999999999999999e-01,
+        5.4867460535174584e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        8.3917349500833027e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        6.9999999999999996e-01,
+        1.2196082416401022e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.4999999999999996e-01,
+        2.3719322591952885e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.9900000000000000e-01,
+        4.6167067382257923e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.9999999989999999e-01,
+        1.3166990759824269e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        9.9999999999999998e-13,
+        1.1747837571179669e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        1.0000000000000000e-03,
+        3.7010857907815897e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        5.0000000000000003e-02,
+        5.2146377525252618e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        2.9999999999999999e-01,
+        6.6469960465985992e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        5.0000000000000000e-01,
+        7.4002405100965248e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        6.9999999999999996e-01,
+        8.2084190952616567e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        9.4999999999999996e-01,
+        1.0125720982431179e+02,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.2999122278560469e+02,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        9.9999999989999999e-01,
+        2.1276570503828839e+02,
+    ),
+];
+
+const BETA_TABLE: [(f64, f64, f64, f64, f64, f64); 15] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000000e-08,
+        8.0656104911267832e+00,
+        6.3661977342861426e-05,
+        9.9993633802265713e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        5.9242918476535796e-02,
+        2.0483276469913345e-01,
+        7.9516723530086653e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        -4.5158270528945488e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        5.9242918476535920e-02,
+        7.9516723530086653e-01,
+        2.0483276469913342e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.9999998999999995e-01,
+        8.0656104886144036e+00,
+        9.9993633802249715e-01,
+        6.3661977502804489e-05,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        1.0000000000000000e-08,
+        -1.5019483402290211e+01,
+        1.4999999600000005e-15,
+        9.9999999999999845e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        1.0000000000000001e-01,
+        6.7717022603680455e-01,
+        1.1426500000000001e-01,
+        8.8573499999999994e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -6.4538521137571178e-02,
+        8.9062500000000000e-01,
+        1.0937500000000000e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.0000000000000002e-01,
+        -5.9145035059718545e+00,
+        9.9994499999999997e-01,
+        5.4999999999999941e-05,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.9999998999999995e-01,
+        -7.0281525584048268e+01,
+        1.0000000000000000e+00,
+        6.0000001007427593e-40,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        1.0000000000000000e-08,
+        -5.2219407302343313e+02,
+        5.4559998416000052e-237,
+        1.0000000000000000e+00,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        1.0000000000000001e-01,
+        -5.5085380662615350e+01,
+        4.0204900000000064e-27,
+        1.0000000000000000e+00,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -1.0175041196732796e+01,
+        7.0058740675449371e-07,
+        9.9999929941259325e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.0000000000000002e-01,
+        2.0424583481263539e+00,
+        5.7694366412569265e-01,
+        4.2305633587430730e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9999998999999995e-01,
+        -4.3256373925597366e+01,
+        1.0000000000000000e+00,
+        4.0919991329013476e-28,
+    ),
+];
+
+const BETA_QUANTILE_TABLE: [(f64, f64, f64, f64); 27] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.9999999999999998e-13,
+        2.4674011002723395e-24,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000000e-03,
+        2.4673990709169442e-06,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000003e-02,
+        6.1558297024311374e-03,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        2.9999999999999999e-01,
+        2.0610737385376343e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        6.9999999999999996e-01,
+        7.9389262614623646e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.4999999999999996e-01,
+        9.9384417029756889e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.9900000000000000e-01,
+        9.9999753260092905e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.9999999989999999e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.9999999999999998e-13,
+        2.5819897863610069e-07,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        1.0000000000000000e-03,
+        8.2554927877466783e-03,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        5.0000000000000003e-02,
+        6.2849891708354380e-02,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.8180347131894917e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        2.6444998329565994e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        6.9999999999999996e-01,
+        3.6035769038002025e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.4999999999999996e-01,
+        5.8180340925202578e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        8.1861386691913396e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.9999999989999999e-01,
+        9.9300355122065409e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9999999999999998e-13,
+        3.0967031543251677e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        1.0000000000000000e-03,
+        6.5993078717032605e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        5.0000000000000003e-02,
+        7.8149679729783272e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        2.9999999999999999e-01,
+        8.5953118185906996e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        5.0000000000000000e-01,
+        8.8985126448441354e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        6.9999999999999996e-01,
+        9.1592314695180799e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.4999999999999996e-01,
+        9.5751870020726126e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9900000000000000e-01,
+        9.8647809794815933e-01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+00,
+        9.9999999989999999e-01,
+        9.9977737349415330e-01,
+    ),
+];
+
+const STUDENT_T_TABLE: [(f64, f64, f64, f64, f64); 32] = [
+    (
+        1.0000000000000000e+00,
+        -1.0000000000000000e+04,
+        -1.9565410639801765e+01,
+        3.1830988512275770e-05,
+        9.9996816901148777e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -3.0000000000000000e+01,
+        -7.9482351434577385e+00,
+        1.0606402405535424e-02,
+        9.8939359759446455e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        -2.7541677982835004e+00,
+        1.4758361765043326e-01,
+        8.5241638234956674e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -1.0000000000000000e-04,
+        -1.1447298958494001e+00,
+        4.9996816901148772e-01,
+        5.0003183098851223e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -1.1447298858494002e+00,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -1.3678734371636099e+00,
+        6.4758361765043326e-01,
+        3.5241638234956674e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        3.0000000000000000e+00,
+        -3.4473149788434458e+00,
+        8.9758361765043326e-01,
+        1.0241638234956672e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        6.0000000000000000e+01,
+        -9.3336967494982748e+00,
+        9.9469532636737668e-01,
+        5.3046736326232708e-03,
+    ),
+    (
+        3.0000000000000000e+00,
+        -1.0000000000000000e+04,
+        -3.5645025820192018e+01,
+        1.1026577511479049e-12,
+        9.9999999999889733e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        -3.0000000000000000e+01,
+        -1.2415109379121262e+01,
+        4.0676402135819796e-05,
+        9.9995932359786421e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        -2.6954845703979169e+00,
+        6.9662984279421594e-02,
+        9.3033701572057836e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        -1.0000000000000000e-04,
+        -1.0008888562901763e+00,
+        4.9996324474038689e-01,
+        5.0003675525961311e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -1.0008888496235098e+00,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -1.1609742649705825e+00,
+        6.7427601757592448e-01,
+        3.2572398242407552e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        3.0000000000000000e+00,
+        -3.7734775718632911e+00,
+        9.7116555718878139e-01,
+        2.8834442811218653e-02,
+    ),
+    (
+        3.0000000000000000e+00,
+        6.0000000000000000e+01,
+        -1.5182708493783476e+01,
+        9.9999490020316339e-01,
+        5.0997968366598774e-06,
+    ),
+    (
+        2.5000000000000000e+01,
+        -1.0000000000000000e+04,
+        -1.9855240306972709e+02,
+        2.3542201582185278e-84,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        -3.0000000000000000e+01,
+        -4.7870868736008276e+01,
+        1.9979314251275372e-21,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        -2.0000000000000000e+00,
+        -2.8583959381709128e+00,
+        2.8237990213448649e-02,
+        9.7176200978655136e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        -1.0000000000000000e-04,
+        -9.2893587683335999e-01,
+        4.9996050262110481e-01,
+        5.0003949737889519e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        0.0000000000000000e+00,
+        -9.2893587163336000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        5.0000000000000000e-01,
+        -1.0582901727245451e+00,
+        6.8927610740488576e-01,
+        3.1072389259511429e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        3.0000000000000000e+00,
+        -4.9262369683568483e+00,
+        9.9698091021742830e-01,
+        3.0190897825717435e-03,
+    ),
+    (
+        2.5000000000000000e+01,
+        6.0000000000000000e+01,
+        -6.5626474523100825e+01,
+        1.0000000000000000e+00,
+        7.6188489648646634e-29,
+    ),
+    (
+        1.0000000000000000e+06,
+        -1.0000000000000000e+04,
+        -2.3075634849196714e+06,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        -3.0000000000000000e+01,
+        -4.5071700999887258e+02,
+        6.0100471168317189e-198,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        -2.0000000000000000e+00,
+        -2.9189367832113393e+00,
+        2.2750266925659603e-02,
+        9.7724973307434038e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        -1.0000000000000000e-04,
+        -9.1893878820467778e-01,
+        4.9996010578199990e-01,
+        5.0003989421800010e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        0.0000000000000000e+00,
+        -9.1893878320467270e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        5.0000000000000000e-01,
+        -1.0439388925796598e+00,
+        6.9146240626381428e-01,
+        3.0853759373618567e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        3.0000000000000000e+00,
+        -5.4189230333059220e+00,
+        9.9865006872928908e-01,
+        1.3499312707108985e-03,
+    ),
+    (
+        1.0000000000000000e+06,
+        6.0000000000000000e+01,
+        -1.7976884906160451e+03,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+];
+
+const STUDENT_T_QUANTILE_TABLE: [(f64, f64, f64); 36] = [
+    (
+        1.0000000000000000e+00,
+        9.9999999999999998e-13,
+        -3.1830988618379065e+11,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-03,
+        -3.1830883898555044e+02,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000003e-02,
+        -6.3137515146750429e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        -7.2654252800536090e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -3.6556563775143008e-26,
+    ),
+    (
+        1.0000000000000000e+00,
+        6.9999999999999996e-01,
+        7.2654252800536068e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.4999999999999996e-01,
+        6.3137515146750376e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9900000000000000e-01,
+        3.1830883898555015e+02,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9999999989999999e-01,
+        3.1830985984671478e+09,
+    ),
+    (
+        3.0000000000000000e+00,
+        9.9999999999999998e-13,
+        -1.0331108244292485e+04,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.0000000000000000e-03,
+        -1.0214531852407386e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        5.0000000000000003e-02,
+        -2.3533634348018238e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        2.9999999999999999e-01,
+        -5.8438972743981865e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -5.1698788284564230e-26,
+    ),
+    (
+        3.0000000000000000e+00,
+        6.9999999999999996e-01,
+        5.8438972743981854e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        9.4999999999999996e-01,
+        2.3533634348018229e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.0214531852407383e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        9.9999999989999999e-01,
+        2.2257692232960749e+03,
+    ),
+    (
+        2.5000000000000000e+01,
+        9.9999999999999998e-13,
+        -1.2731341677108123e+01,
+    ),
+    (
+        2.5000000000000000e+01,
+        1.0000000000000000e-03,
+        -3.4501887269730642e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        5.0000000000000003e-02,
+        -1.7081407612518993e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        2.9999999999999999e-01,
+        -5.3115378958192838e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        5.0000000000000000e-01,
+        -1.4622625510057203e-25,
+    ),
+    (
+        2.5000000000000000e+01,
+        6.9999999999999996e-01,
+        5.3115378958192827e-01,
+    ),
+    (
+        2.5000000000000000e+01,
+        9.4999999999999996e-01,
+        1.7081407612518988e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        9.9900000000000000e-01,
+        3.4501887269730638e+00,
+    ),
+    (
+        2.5000000000000000e+01,
+        9.9999999989999999e-01,
+        1.0235577137617408e+01,
+    ),
+    (
+        1.0000000000000000e+06,
+        9.9999999999999998e-13,
+        -7.0345726084112563e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        1.0000000000000000e-03,
+        -3.0902404563165198e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        5.0000000000000003e-02,
+        -1.6448551507220404e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        2.9999999999999999e-01,
+        -5.2440067986020888e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        5.0000000000000000e-01,
+        -2.6469779601696886e-23,
+    ),
+    (
+        1.0000000000000000e+06,
+        6.9999999999999996e-01,
+        5.2440067986020877e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        9.4999999999999996e-01,
+        1.6448551507220401e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        9.9900000000000000e-01,
+        3.0902404563165193e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        9.9999999989999999e-01,
+        6.3614068361697198e+00,
+    ),
+];
+
+const FISHER_F_TABLE: [(f64, f64, f64, f64, f64, f64); 24] = [
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9999999999999995e-07,
+        5.7630243931332368e+00,
+        6.3661956016111785e-04,
+        9.9936338043983886e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000001e-01,
+        -8.8747519156702231e-02,
+        1.9498222904213666e-01,
+        8.0501777095786331e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.8378770664093456e+00,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.5000000000000000e+00,
+        -2.8556382202818456e+00,
+        6.4098296402862387e-01,
+        3.5901703597137608e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.0000000000000000e+01,
+        -7.0325670212877993e+00,
+        9.1056147804968446e-01,
+        8.9438521950315528e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e+04,
+        -1.4960340438814008e+01,
+        9.9363401447018351e-01,
+        6.3659855298165103e-03,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        9.9999999999999995e-07,
+        -1.7516257025375932e+01,
+        9.8820559250608474e-15,
+        9.9999999999999012e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.0000000000000001e-01,
+        -1.0278625075312600e+00,
+        1.7888543819998319e-02,
+        9.8211145618000173e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.1776528281742453e+00,
+        4.3120115037169215e-01,
+        5.6879884962830785e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        2.5000000000000000e+00,
+        -2.3520514816622664e+00,
+        6.9000943139510951e-01,
+        3.0999056860489049e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        5.0000000000000000e+01,
+        -7.8519346046284113e+00,
+        9.8027667657942286e-01,
+        1.9723323420577150e-02,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.0000000000000000e+04,
+        -1.8420820741152440e+01,
+        9.9990000699958004e-01,
+        9.9993000419976895e-05,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        9.9999999999999995e-07,
+        -4.9926728906890887e+01,
+        4.1507596314078786e-29,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        1.0000000000000001e-01,
+        -4.4923361117700438e+00,
+        2.4888697632435413e-04,
+        9.9975111302367570e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        1.0000000000000000e+00,
+        -2.4326920788981746e-01,
+        5.4012267024242089e-01,
+        4.5987732975757911e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        2.5000000000000000e+00,
+        -3.1372128920804734e+00,
+        9.8051763806329684e-01,
+        1.9482361936703142e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        5.0000000000000000e+01,
+        -4.4083830539431581e+01,
+        1.0000000000000000e+00,
+        1.9617286130628896e-19,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        1.0000000000000000e+04,
+        -1.5343446720907031e+02,
+        1.0000000000000000e+00,
+        1.1572690389933629e-64,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        9.9999999999999995e-07,
+        -9.4073952882825495e+02,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        1.0000000000000001e-01,
+        -7.6966569720767852e+00,
+        3.5948204444886575e-06,
+        9.9999640517955546e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -7.7847672159898351e-01,
+        3.9392164980907823e-01,
+        6.0607835019092182e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        2.5000000000000000e+00,
+        -2.1651896584397474e+00,
+        7.5314878020655318e-01,
+        2.4685121979344679e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        5.0000000000000000e+01,
+        -9.0777855004669519e+00,
+        9.9614744380720077e-01,
+        3.8525561927991894e-03,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        1.0000000000000000e+04,
+        -2.2293285733309560e+01,
+        9.9999861297524473e-01,
+        1.3870247552853079e-06,
+    ),
+];
+
+const FISHER_F_QUANTILE_TABLE: [(f64, f64, f64, f64); 36] = [
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9999999999999998e-13,
+        2.4674011002723395e-24,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e-03,
+        2.4674051589901410e-06,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.0000000000000003e-02,
+        6.1939586571081945e-03,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        2.5961618368249972e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        6.9999999999999996e-01,
+        3.8518399963191814e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.4999999999999996e-01,
+        1.6144763879758821e+02,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9900000000000000e-01,
+        4.0528406790284818e+05,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9999999989999999e-01,
+        4.0528466750254080e+19,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        9.9999999999999998e-13,
+        6.3396732468941680e-06,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.0000000000000000e-03,
+        2.6937964429416376e-02,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        5.0000000000000003e-02,
+        1.7282693758579051e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        6.4657480424495717e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.2519251840506678e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        6.9999999999999996e-01,
+        2.6084273054201277e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        9.4999999999999996e-01,
+        1.9296409652017232e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        9.9900000000000000e-01,
+        9.9929992996497708e+02,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        9.9999999989999999e-01,
+        9.9999991718963585e+09,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        9.9999999999999998e-13,
+        1.8933472268966532e-03,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        1.0000000000000000e-03,
+        1.3703992984488345e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        5.0000000000000003e-02,
+        3.7581902137799805e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        2.9999999999999999e-01,
+        7.2094799688553723e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        5.0000000000000000e-01,
+        9.5002981878678927e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        6.9999999999999996e-01,
+        1.2338227060362221e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        9.4999999999999996e-01,
+        2.0772480464172101e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        9.9900000000000000e-01,
+        3.8743860839486728e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        9.9999999989999999e-01,
+        1.5241237369024390e+01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        9.9999999999999998e-13,
+        4.3907447273374166e-02,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        1.0000000000000000e-03,
+        1.7748951434569302e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        5.0000000000000003e-02,
+        3.7739385971948364e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        2.9999999999999999e-01,
+        8.1314468417297758e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.2636517900019784e+00,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        6.9999999999999996e-01,
+        2.1050191399975362e+00,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        9.4999999999999996e-01,
+        8.5402208011683509e+00,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.2376958488277253e+02,
+    ),
+    (
+        2.0000000000000000e+02,
+        3.0000000000000000e+00,
+        9.9999999989999999e-01,
+        5.7731900360962441e+06,
+    ),
+];
+
+const POISSON_TABLE: [(f64, u64, f64, f64, f64); 15] = [
+    (
+        1.0000000000000000e-02,
+        0,
+        -1.0000000000000000e-02,
+        9.9004983374916811e-01,
+        9.9501662508319471e-03,
+    ),
+    (
+        1.0000000000000000e-02,
+        1,
+        -4.6151701859880916e+00,
+        9.9995033208665973e-01,
+        4.9667913340265889e-05,
+    ),
+    (
+        1.0000000000000000e-02,
+        2,
+        -9.9134875525361288e+00,
+        9.9999983457834718e-01,
+        1.6542165280748770e-07,
+    ),
+    (
+        1.0000000000000000e-02,
+        20,
+        -1.3444902018051530e+02,
+        1.0000000000000000e+00,
+        1.9386999143909362e-62,
+    ),
+    (
+        3.5000000000000000e+00,
+        0,
+        -3.5000000000000000e+00,
+        3.0197383422318501e-02,
+        9.6980261657768152e-01,
+    ),
+    (
+        3.5000000000000000e+00,
+        1,
+        -2.2472370315046319e+00,
+        1.3588822540043324e-01,
+        8.6411177459956678e-01,
+    ),
+    (
+        3.5000000000000000e+00,
+        3,
+        -1.5334705637419510e+00,
+        5.3663266790078501e-01,
+        4.6336733209921499e-01,
+    ),
+    (
+        3.5000000000000000e+00,
+        11,
+        -7.2219151924248379e+00,
+        9.9971100778006972e-01,
+        2.8899221993030022e-04,
+    ),
+    (
+        3.5000000000000000e+00,
+        30,
+        -4.0575347293969124e+01,
+        1.0000000000000000e+00,
+        3.0280745798206685e-19,
+    ),
+    (
+        2.5000000000000000e+02,
+        0,
+        -2.5000000000000000e+02,
+        2.6691902155412764e-109,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+02,
+        1,
+        -2.4447853908213776e+02,
+        6.6996674410086034e-107,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+02,
+        25,
+        -1.6996708227642435e+02,
+        1.6973805589085579e-74,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+02,
+        250,
+        -3.6800023252913525e+00,
+        5.1681228921553923e-01,
+        4.8318771078446082e-01,
+    ),
+    (
+        2.5000000000000000e+02,
+        299,
+        -8.2852530296001046e+00,
+        9.9883760636894536e-01,
+        1.1623936310546183e-03,
+    ),
+    (
+        2.5000000000000000e+02,
+        770,
+        -3.5043803169455265e+02,
+        1.0000000000000000e+00,
+        3.0718539142779099e-153,
+    ),
+];
+
+const POISSON_QUANTILE_TABLE: [(f64, f64, f64); 27] = [
+    (
+        1.0000000000000000e-02,
+        9.9999999999999998e-13,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        1.0000000000000000e-03,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        5.0000000000000003e-02,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        2.9999999999999999e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        6.9999999999999996e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        9.4999999999999996e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        9.9900000000000000e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        9.9999999989999999e-01,
+        4.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        9.9999999999999998e-13,
+        0.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        1.0000000000000000e-03,
+        0.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        5.0000000000000003e-02,
+        1.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        2.9999999999999999e-01,
+        2.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        5.0000000000000000e-01,
+        3.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        6.9999999999999996e-01,
+        4.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        9.4999999999999996e-01,
+        7.0000000000000000e+00,
+    ),
+    (
+        3.5000000000000000e+00,
+        9.9900000000000000e-01,
+        1.1000000000000000e+01,
+    ),
+    (
+        3.5000000000000000e+00,
+        9.9999999989999999e-01,
+        2.1000000000000000e+01,
+    ),
+    (
+        2.5000000000000000e+02,
+        9.9999999999999998e-13,
+        1.4700000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        1.0000000000000000e-03,
+        2.0300000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        5.0000000000000003e-02,
+        2.2400000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        2.9999999999999999e-01,
+        2.4200000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        5.0000000000000000e-01,
+        2.5000000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        6.9999999999999996e-01,
+        2.5800000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        9.4999999999999996e-01,
+        2.7600000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        9.9900000000000000e-01,
+        3.0000000000000000e+02,
+    ),
+    (
+        2.5000000000000000e+02,
+        9.9999999989999999e-01,
+        3.5700000000000000e+02,
+    ),
+];
+
+const BINOMIAL_TABLE: [(u64, f64, u64, f64, f64, f64); 16] = [
+    (
+        1,
+        2.9999999999999999e-01,
+        0,
+        -3.5667494393873234e-01,
+        6.9999999999999996e-01,
+        2.9999999999999999e-01,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        1,
+        -1.2039728043259361e+00,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        0,
+        -1.0258658877510107e+00,
+        3.5848592240854221e-01,
+        6.4151407759145773e-01,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        1,
+        -9.7457259336346014e-01,
+        7.3583952494384985e-01,
+        2.6416047505615015e-01,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        19,
+        -5.3974474218359390e+01,
+        1.0000000000000000e+00,
+        9.5367431640625110e-27,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        20,
+        -5.9914645471079815e+01,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        0,
+        -6.9314718055994533e+01,
+        7.8886090522101181e-31,
+        1.0000000000000000e+00,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        1,
+        -6.4709547870006446e+01,
+        7.9674951427322192e-29,
+        1.0000000000000000e+00,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        50,
+        -2.5308764039771048e+00,
+        5.3979461869358936e-01,
+        4.6020538130641064e-01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        99,
+        -6.4709547870006446e+01,
+        1.0000000000000000e+00,
+        7.8886090522101181e-31,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        100,
+        -6.9314718055994533e+01,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        0,
+        -2.3025850929940457e+03,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        1,
+        -2.2934801131377276e+03,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        900,
+        -3.1696859581836669e+00,
+        5.1541770956591937e-01,
+        4.8458229043408063e-01,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        999,
+        -1.0064998495618036e+02,
+        1.0000000000000000e+00,
+        1.7478712517226947e-46,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        1000,
+        -1.0536051565782628e+02,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+];
+
+const BINOMIAL_QUANTILE_TABLE: [(u64, f64, f64, f64); 36] = [
+    (
+        1,
+        2.9999999999999999e-01,
+        9.9999999999999998e-13,
+        0.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        1.0000000000000000e-03,
+        0.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        5.0000000000000003e-02,
+        0.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        6.9999999999999996e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        9.4999999999999996e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        9.9900000000000000e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        1,
+        2.9999999999999999e-01,
+        9.9999999989999999e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        9.9999999999999998e-13,
+        0.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        1.0000000000000000e-03,
+        0.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        5.0000000000000003e-02,
+        0.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        2.9999999999999999e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        6.9999999999999996e-01,
+        1.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        9.4999999999999996e-01,
+        3.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        9.9900000000000000e-01,
+        5.0000000000000000e+00,
+    ),
+    (
+        20,
+        5.0000000000000003e-02,
+        9.9999999989999999e-01,
+        1.1000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        9.9999999999999998e-13,
+        1.6000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        1.0000000000000000e-03,
+        3.5000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        5.0000000000000003e-02,
+        4.2000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        2.9999999999999999e-01,
+        4.7000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        6.9999999999999996e-01,
+        5.3000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        9.4999999999999996e-01,
+        5.8000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        9.9900000000000000e-01,
+        6.5000000000000000e+01,
+    ),
+    (
+        100,
+        5.0000000000000000e-01,
+        9.9999999989999999e-01,
+        8.1000000000000000e+01,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        9.9999999999999998e-13,
+        8.2700000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        1.0000000000000000e-03,
+        8.7000000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        5.0000000000000003e-02,
+        8.8400000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        2.9999999999999999e-01,
+        8.9500000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        5.0000000000000000e-01,
+        9.0000000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        6.9999999999999996e-01,
+        9.0500000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        9.4999999999999996e-01,
+        9.1500000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        9.9900000000000000e-01,
+        9.2800000000000000e+02,
+    ),
+    (
+        1000,
+        9.0000000000000002e-01,
+        9.9999999989999999e-01,
+        9.5500000000000000e+02,
+    ),
+];
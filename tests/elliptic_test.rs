@@ -0,0 +1,494 @@
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
+
+use approx::assert_relative_eq;
+use core::f64::consts::{FRAC_PI_2, PI};
+use puruspe::{
+    ellipe, ellipeinc, ellipk, ellipkinc, ellipkm1, ellippi, ellippiinc, elliprc, elliprd, elliprf,
+    elliprj,
+};
+
+#[test]
+fn test_complete_elliptic_integrals() {
+    for (m, k, e) in COMPLETE_TABLE {
+        assert_relative_eq!(ellipk(m), k, max_relative = 1e-14);
+        assert_relative_eq!(ellipe(m), e, max_relative = 1e-14);
+    }
+    for (p, k) in ELLIPKM1_TABLE {
+        assert_relative_eq!(ellipkm1(p), k, max_relative = 1e-14);
+    }
+    for (n, m, pi) in PI_TABLE {
+        assert_relative_eq!(ellippi(n, m), pi, max_relative = 1e-13);
+    }
+}
+
+#[test]
+fn test_incomplete_elliptic_integrals() {
+    for (phi, m, f, e) in INCOMPLETE_TABLE {
+        assert_relative_eq!(ellipkinc(phi, m), f, max_relative = 1e-14);
+        assert_relative_eq!(ellipeinc(phi, m), e, max_relative = 1e-14);
+    }
+    for (n, phi, m, pi) in PI_INCOMPLETE_TABLE {
+        assert_relative_eq!(ellippiinc(n, phi, m), pi, max_relative = 1e-13);
+    }
+}
+
+#[test]
+fn test_carlson_integrals() {
+    for (x, y, z, p, rf, rd, rj) in CARLSON_TABLE {
+        assert_relative_eq!(elliprf(x, y, z), rf, max_relative = 1e-14);
+        assert_relative_eq!(elliprd(x, y, z), rd, max_relative = 1e-14);
+        assert_relative_eq!(elliprj(x, y, z, p), rj, max_relative = 1e-13);
+    }
+    for (x, y, rc) in RC_TABLE {
+        assert_relative_eq!(elliprc(x, y), rc, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_legendre_relation() {
+    // E(m) K(1-m) + E(1-m) K(m) - K(m) K(1-m) = π/2
+    for &m in [1e-6, 0.1, 0.3, 0.5, 0.8, 0.99].iter() {
+        let (k, e) = (ellipk(m), ellipe(m));
+        let (kc, ec) = (ellipkm1(m), ellipe(1.0 - m));
+        assert_relative_eq!(e * kc + ec * k - k * kc, FRAC_PI_2, max_relative = 1e-14);
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_elliptic_edge_cases() {
+    assert_relative_eq!(ellipk(0.0), FRAC_PI_2, max_relative = 1e-15);
+    assert_eq!(ellipk(1.0), f64::INFINITY);
+    assert_eq!(ellipe(1.0), 1.0);
+    assert_eq!(ellippi(1.0, 0.5), f64::INFINITY);
+    assert_eq!(ellippi(0.5, 1.0), f64::INFINITY);
+    assert!(ellipk(1.5).is_nan());
+    assert!(ellipe(f64::NAN).is_nan());
+    assert!(ellipkm1(-1.0).is_nan());
+
+    // F(φ, 0) = E(φ, 0) = φ, F(φ, 1) = artanh(sin φ) and E(φ, 1) = sin φ for |φ| < π/2
+    for &phi in [-1.2, 0.3, 1.0, 1.5].iter() {
+        assert_relative_eq!(ellipkinc(phi, 0.0), phi, max_relative = 1e-15);
+        assert_relative_eq!(ellipeinc(phi, 0.0), phi, max_relative = 1e-15);
+        assert_relative_eq!(
+            ellipkinc(phi, 1.0),
+            f64::atanh(phi.sin()),
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(ellipeinc(phi, 1.0), phi.sin(), max_relative = 1e-15);
+    }
+    // The amplitude is reduced by the quasi-periodicity, and the integrals are complex beyond m sin² φ = 1.
+    assert_relative_eq!(ellipkinc(PI, 0.7), 2.0 * ellipk(0.7), max_relative = 1e-15);
+    assert_relative_eq!(
+        ellipeinc(-PI, 0.7),
+        -2.0 * ellipe(0.7),
+        max_relative = 1e-15
+    );
+    assert!(ellipkinc(1.0, 2.0).is_nan());
+    assert!(ellipeinc(f64::NAN, 0.5).is_nan());
+
+    // R_C(x, y) = arccos(√(x/y)) / √(y - x) and degenerate arguments
+    assert_relative_eq!(
+        elliprc(0.5, 2.0),
+        f64::acos(0.5) / 1.5f64.sqrt(),
+        max_relative = 1e-15
+    );
+    assert_eq!(elliprf(0.0, 0.0, 1.0), f64::INFINITY);
+    assert_eq!(elliprd(1.0, 1.0, 0.0), f64::INFINITY);
+    assert_eq!(elliprj(1.0, 2.0, 3.0, 0.0), f64::INFINITY);
+    assert_eq!(elliprc(1.0, 0.0), f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "Bad args in elliprf")]
+fn test_elliprf_negative_argument() {
+    elliprf(-1.0, 1.0, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Bad args in elliprj")]
+fn test_elliprj_negative_argument() {
+    elliprj(1.0, -1.0, 1.0, 1.0);
+}
+
+const COMPLETE_TABLE: [(f64, f64, f64); 13] = [
+    (
+        -1.0000000000000000e+02,
+        3.6821924860914101e-01,
+        1.0209260919814572e+01,
+    ),
+    (
+        -3.0000000000000000e+00,
+        1.0782578237498217e+00,
+        2.4221120551369189e+00,
+    ),
+    (
+        -5.0000000000000000e-01,
+        1.4157372084259563e+00,
+        1.7517712756948178e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.5707963267948966e+00,
+        1.5707963267948966e+00,
+    ),
+    (
+        1.0000000000000000e-10,
+        1.5707963268341665e+00,
+        1.5707963267556266e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.6124413487202194e+00,
+        1.5307576368977631e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.7138894481787910e+00,
+        1.4453630644126654e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.8540746773013719e+00,
+        1.3506438810476755e+00,
+    ),
+    (
+        6.9999999999999996e-01,
+        2.0753631352924691e+00,
+        1.2416705679458229e+00,
+    ),
+    (
+        9.0000000000000002e-01,
+        2.5780921133481733e+00,
+        1.1047747327040733e+00,
+    ),
+    (
+        9.8999999999999999e-01,
+        3.6956373629898742e+00,
+        1.0159935450252240e+00,
+    ),
+    (
+        9.9999899999999997e-01,
+        8.2940514636010629e+00,
+        1.0000038970261722e+00,
+    ),
+    (
+        9.9999999999909051e-01,
+        1.5249237972322037e+01,
+        1.0000000000067071e+00,
+    ),
+];
+const ELLIPKM1_TABLE: [(f64, f64); 8] = [
+    (1.0000000000000000e-300, 3.4677405831022674e+02),
+    (1.0000000000000000e-100, 1.1651554901082217e+02),
+    (1.0000000000000001e-30, 3.5925070756030578e+01),
+    (1.0000000000000001e-17, 2.0958267651569280e+01),
+    (1.0000000000000000e-08, 1.0596634757087660e+01),
+    (2.5000000000000000e-01, 2.1565156474996434e+00),
+    (1.0000000000000000e+00, 1.5707963267948966e+00),
+    (4.0000000000000000e+01, 5.1305752425415185e-01),
+];
+const INCOMPLETE_TABLE: [(f64, f64, f64, f64); 13] = [
+    (
+        1.0000000000000001e-01,
+        5.0000000000000000e-01,
+        1.0008335399012896e-01,
+        9.9916770860605464e-02,
+    ),
+    (
+        6.9999999999999996e-01,
+        -2.0000000000000000e+00,
+        6.2335629952743343e-01,
+        7.9322782652840207e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.0457364440164778e+00,
+        9.5757966420960050e-01,
+    ),
+    (
+        1.2000000000000000e+00,
+        9.8999999999999999e-01,
+        1.6604884633802131e+00,
+        9.3572322322312984e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        9.9999899999999997e-01,
+        3.3406285443016617e+00,
+        9.9749615818337556e-01,
+    ),
+    (
+        1.5707963267948966e+00,
+        8.0000000000000004e-01,
+        2.2572053268208534e+00,
+        1.1784899243278384e+00,
+    ),
+    (
+        -9.0000000000000002e-01,
+        5.9999999999999998e-01,
+        -9.7610985159818220e-01,
+        -8.3382446422911904e-01,
+    ),
+    (
+        2.3999999999999999e+00,
+        4.0000000000000002e-01,
+        2.7867619220539224e+00,
+        2.0822832446024351e+00,
+    ),
+    (
+        7.0000000000000000e+00,
+        9.4999999999999996e-01,
+        1.2416311475654915e+01,
+        4.9021276039662425e+00,
+    ),
+    (
+        4.0000000000000000e+01,
+        -5.0000000000000000e-01,
+        3.6006615591961705e+01,
+        4.4664083483926184e+01,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.0000000000000000e+00,
+        5.9378468715439803e-01,
+        4.3185196403948561e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+01,
+        3.8888760405353179e-01,
+        2.4640002160680055e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.2261911708835171e+00,
+        8.4147098480789650e-01,
+    ),
+];
+const PI_TABLE: [(f64, f64, f64); 8] = [
+    (
+        -5.0000000000000000e+00,
+        2.9999999999999999e-01,
+        6.7423061297697762e-01,
+    ),
+    (
+        -5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        1.2825498301618641e+00,
+    ),
+    (
+        2.0000000000000001e-01,
+        -1.0000000000000000e+00,
+        1.4518076933278732e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        2.7012877620953510e+00,
+    ),
+    (
+        9.0000000000000002e-01,
+        9.8999999999999999e-01,
+        2.0326005496444310e+01,
+    ),
+    (
+        9.9900000000000000e-01,
+        1.0000000000000001e-01,
+        5.2274293287654025e+01,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.9999999999999999e-01,
+        -2.1183889209293819e-01,
+    ),
+    (
+        4.0000000000000000e+00,
+        9.0000000000000002e-01,
+        -4.5363928484743915e-01,
+    ),
+];
+const PI_INCOMPLETE_TABLE: [(f64, f64, f64, f64); 8] = [
+    (
+        -2.0000000000000000e+00,
+        5.9999999999999998e-01,
+        5.0000000000000000e-01,
+        5.1536808507911414e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.2391080217058432e+00,
+    ),
+    (
+        9.0000000000000002e-01,
+        1.3999999999999999e+00,
+        9.0000000000000002e-01,
+        6.2382371538333086e+00,
+    ),
+    (
+        -2.9999999999999999e-01,
+        2.5000000000000000e+00,
+        2.0000000000000001e-01,
+        2.2739367796630234e+00,
+    ),
+    (
+        4.0000000000000002e-01,
+        -7.0000000000000000e+00,
+        6.9999999999999996e-01,
+        -1.1948718329061583e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000002e-01,
+        5.0000000000000000e-01,
+        4.9543764853055267e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.2000000000000000e+00,
+        5.0000000000000000e-01,
+        8.2896757008337152e-02,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000000e+00,
+        1.0000000000000001e-01,
+        3.1564646080103474e-02,
+    ),
+];
+const CARLSON_TABLE: [(f64, f64, f64, f64, f64, f64, f64); 10] = [
+    (
+        1.0000000000000000e+00,
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        7.2694593546890818e-01,
+        2.9046028102899063e-01,
+        2.3984809974956775e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.3110287771460598e+00,
+        1.0679379896673957e+00,
+        7.7688623778582333e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        5.0000000000000000e+00,
+        5.8408284167715174e-01,
+        1.6510527294261054e-01,
+        1.4297579667156754e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.4142135623730951e+00,
+        2.8284271247461903e+00,
+        2.8284271247461903e+00,
+    ),
+    (
+        1.0000000000000001e-05,
+        1.0000000000000000e+05,
+        3.0000000000000000e+00,
+        2.0000000000000001e-01,
+        2.0844687064651035e-02,
+        3.1562730673908961e-03,
+        1.6516319710593842e-02,
+    ),
+    (
+        0.0000000000000000e+00,
+        9.9999999999999998e-13,
+        1.0000000000000000e+00,
+        1.0000000000000000e-10,
+        1.5201804919087715e+01,
+        4.2605414757283697e+01,
+        9.0249064478104294e+10,
+    ),
+    (
+        3.0000000000000001e-80,
+        1.9999999999999999e-80,
+        9.9999999999999996e-81,
+        3.9999999999999998e-80,
+        7.2694593546890819e+39,
+        5.5911221740544418e+119,
+        2.3984809974956776e+119,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        -5.0000000000000000e-01,
+        5.8408284167715174e-01,
+        1.6510527294261054e-01,
+        2.4723819703051564e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -3.0000000000000000e+00,
+        1.3110287771460598e+00,
+        1.0679379896673957e+00,
+        -8.8796627542723949e-01,
+    ),
+    (
+        1.0000000000000000e+30,
+        2.9999999999999998e+30,
+        2.0000000000000000e+30,
+        1.0000000000000000e+30,
+        7.2694593546890824e-16,
+        3.7517237295715429e-46,
+        5.5911221740544412e-46,
+    ),
+];
+const RC_TABLE: [(f64, f64, f64); 7] = [
+    (
+        0.0000000000000000e+00,
+        2.5000000000000000e-01,
+        3.1415926535897931e+00,
+    ),
+    (
+        2.2500000000000000e+00,
+        2.0000000000000000e+00,
+        6.9314718055994529e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000010000001e+00,
+        9.9999999966666664e-01,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e+00,
+        7.0247947311109737e-01,
+    ),
+    (
+        2.5000000000000000e-01,
+        -2.0000000000000000e+00,
+        2.3104906018664845e-01,
+    ),
+    (
+        1.0000000000000000e+06,
+        9.9999999999999995e-07,
+        1.4508657738531223e-02,
+    ),
+];
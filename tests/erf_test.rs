@@ -1,5 +1,7 @@
-use approx::assert_abs_diff_eq;
-use puruspe::{erf, erfc, inverf, inverfc};
+#![allow(clippy::excessive_precision)]
+
+use approx::{assert_abs_diff_eq, assert_relative_eq};
+use puruspe::{erf, erfc, erfi, inverf, inverfc};
 
 #[test]
 fn test_erf() {
@@ -19,6 +21,26 @@ fn test_erfc() {
     }
 }
 
+#[test]
+fn test_erf_small() {
+    // Regression test for the power series below |x| = 0.5.
+    // 1 - erfc(x) lost all relative accuracy for tiny x.
+    for &(x, expected) in ERF_SMALL_TABLE.iter() {
+        assert_relative_eq!(erf(x), expected, epsilon = 0.0, max_relative = 1e-15);
+    }
+}
+
+#[test]
+fn test_erfi() {
+    for &(x, expected) in ERFI_TABLE.iter() {
+        assert_relative_eq!(erfi(x), expected, max_relative = 1e-14);
+    }
+    assert_eq!(erfi(0.0), 0.0);
+    assert_eq!(erfi(27.0), f64::INFINITY);
+    assert_eq!(erfi(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert!(erfi(f64::NAN).is_nan());
+}
+
 #[test]
 fn test_inverf() {
     for &(p, expected) in INVERF_TABLE.iter() {
@@ -83,3 +105,28 @@ const INVERFC_TABLE: [(f64, f64); 5] = [
     (1.00000000000000e+00, -0.00000000000000e+00),
     (1.99000000000000e+00, -1.82138636771845e+00),
 ];
+
+const ERF_SMALL_TABLE: [(f64, f64); 11] = [
+    (1.0000000000000000e-300, 1.1283791670955126e-300),
+    (-9.9999999999999998e-13, -1.1283791670955126e-12),
+    (1.0000000000000001e-05, 1.1283791670579000e-05),
+    (1.0000000000000000e-02, 1.1283415555849618e-02),
+    (-2.9999999999999999e-01, -3.2862675945912739e-01),
+    (4.8999999999999999e-01, 5.1166826118852304e-01),
+    (4.9999990000000000e-01, 5.2049978993478430e-01),
+    (5.0000000000000000e-01, 5.2049987781304652e-01),
+    (-5.0000000000000000e-01, -5.2049987781304652e-01),
+    (5.0000009999999995e-01, 5.2049996569129997e-01),
+    (6.9999999999999996e-01, 6.7780119383741844e-01),
+];
+
+const ERFI_TABLE: [(f64, f64); 8] = [
+    (1.0000000000000000e-10, 1.1283791670955126e-10),
+    (-2.0000000000000001e-01, -2.2872129924397130e-01),
+    (5.0000000000000000e-01, 6.1495209469651102e-01),
+    (1.0000000000000000e+00, 1.6504257587975428e+00),
+    (2.5000000000000000e+00, 1.3039575501324694e+02),
+    (-4.0000000000000000e+00, -1.2969597307176392e+06),
+    (1.0000000000000000e+01, 1.5243074227086696e+42),
+    (2.6000000000000000e+01, 8.3146371647309880e+291),
+];
@@ -20,17 +20,22 @@
 //! gcc -o myprogram myprogram.c -lcerf -lm
 //! ```
 
-use puruspe::faddeeva::faddeeva;
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use num_complex::Complex64;
+use puruspe::faddeeva::{
+    complex_dawson, complex_erf, complex_erfc, complex_erfcx, complex_erfi, faddeeva,
+};
 
 const MAX_ERR: f64 = 1e-15;
 
 #[test]
 fn test_faddeeva() {
     let mut z = (0., 0.);
-    for i in 0..1000 {
+    for libcerf_val in W_OF_Z_TABLE {
         z = (z.0 + 0.01, z.1 + 0.01);
         let w = faddeeva(z.0, z.1);
-        let libcerf_val = W_OF_Z_TABLE[i];
 
         println!("RE(Z): {}, IM(Z): {}", z.0, z.1);
         println!("REAL DIFFERENCE: {}", (libcerf_val.0 - w.0).abs());
@@ -41,6 +46,91 @@ fn test_faddeeva() {
     }
 }
 
+#[test]
+fn test_faddeeva_near_axes() {
+    // The small component is 2y (x Im w(x) - 1/√π) near the real axis and 2x (1/√π - y erfcx(y))
+    // near the imaginary axis, whose differences cancel for large |z|.
+    for &(re, im, w_re, w_im) in W_NEAR_AXIS_TABLE.iter() {
+        let w = faddeeva(re, im);
+        assert_relative_eq!(w.0, w_re, epsilon = 0.0, max_relative = 1e-13);
+        assert_relative_eq!(w.1, w_im, epsilon = 0.0, max_relative = 1e-13);
+    }
+}
+
+#[test]
+fn test_complex_error_functions() {
+    check_complex(complex_erf, &COMPLEX_ERF_TABLE);
+    check_complex(complex_erfc, &COMPLEX_ERFC_TABLE);
+    check_complex(complex_erfcx, &COMPLEX_ERFCX_TABLE);
+    check_complex(complex_erfi, &COMPLEX_ERFI_TABLE);
+    check_complex(complex_dawson, &COMPLEX_DAWSON_TABLE);
+    for &(re, im, erfcx_re, erfcx_im, dawson_re, dawson_im) in COMPLEX_LARGE_TABLE.iter() {
+        let z = Complex64::new(re, im);
+        assert_complex_eq(complex_erfcx(z), Complex64::new(erfcx_re, erfcx_im), 1e-14);
+        assert_complex_eq(
+            complex_dawson(z),
+            Complex64::new(dawson_re, dawson_im),
+            1e-14,
+        );
+    }
+}
+
+#[test]
+fn test_complex_error_functions_edge_cases() {
+    let zero = Complex64::new(0.0, 0.0);
+    assert_eq!(complex_erf(zero), zero);
+    assert_eq!(complex_erfc(zero), Complex64::new(1.0, 0.0));
+    assert_eq!(complex_erfcx(zero), Complex64::new(1.0, 0.0));
+    assert_eq!(complex_dawson(zero), zero);
+    // The sign of zero is preserved on the real axis.
+    let negative_zero = complex_erf(Complex64::new(1.0, -0.0));
+    assert!(negative_zero.im == 0.0 && negative_zero.im.is_sign_negative());
+    assert_eq!(
+        complex_erf(Complex64::new(1.0, 40.0)).im.abs(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        complex_erf(Complex64::new(40.0, 1.0)),
+        Complex64::new(1.0, 0.0)
+    );
+    assert_eq!(
+        complex_erfc(Complex64::new(-40.0, 1.0)),
+        Complex64::new(2.0, 0.0)
+    );
+    // e^{-z²} overflows against a finite w, which gives infinities rather than NaN.
+    // erfc(0.001 + 30i) ≈ -8.26e387 - 1.38e389i
+    assert_eq!(
+        complex_erfc(Complex64::new(0.001, 30.0)),
+        Complex64::new(f64::NEG_INFINITY, f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        complex_erfc(Complex64::new(-0.001, 30.0)),
+        Complex64::new(f64::INFINITY, f64::NEG_INFINITY)
+    );
+    assert_eq!(complex_erfi(Complex64::new(0.0, 0.5)).re, 0.0);
+    let nan = complex_erf(Complex64::new(f64::NAN, 1.0));
+    assert!(nan.re.is_nan() && nan.im.is_nan());
+    assert!(complex_dawson(Complex64::new(1.0, f64::NAN)).im.is_nan());
+}
+
+fn check_complex(f: fn(Complex64) -> Complex64, table: &[(f64, f64, f64, f64)]) {
+    for &(re, im, f_re, f_im) in table.iter() {
+        // e^{-z²} amplifies the rounding of z² by about |z|².
+        let z = Complex64::new(re, im);
+        let tolerance = 4.0 * f64::EPSILON * (1.0 + z.norm_sqr());
+        assert_complex_eq(f(z), Complex64::new(f_re, f_im), tolerance);
+    }
+}
+
+fn assert_complex_eq(actual: Complex64, expected: Complex64, tolerance: f64) {
+    assert!(
+        (actual - expected).norm() <= tolerance * expected.norm(),
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
 const W_OF_Z_TABLE: [(f64, f64); 1000] = [
     (0.98871769295495482, 0.01108529605747728),
     (0.97744413655320983, 0.02177961561927827),
@@ -1043,3 +1133,693 @@ const W_OF_Z_TABLE: [(f64, f64); 1000] = [
     (0.02830791446808592, 0.02816645639318190),
     (0.02827946745423294, 0.02813843327633737),
 ];
+
+const COMPLEX_ERF_TABLE: [(f64, f64, f64, f64); 19] = [
+    (
+        1.0000000000000000e-10,
+        2.0000000000000001e-10,
+        1.1283791670955126e-10,
+        2.2567583341910252e-10,
+    ),
+    (
+        1.0000000000000001e-01,
+        -2.9999999999999999e-01,
+        1.2298040809618913e-01,
+        -3.4526497009870288e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        3.5768247791161106e-01,
+        3.1715474113943010e-01,
+    ),
+    (
+        -4.5000000000000001e-01,
+        1.0000000000000001e-01,
+        -4.7964660048047947e-01,
+        9.2336197170095183e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.3161512816979477e+00,
+        1.9045346923783468e-01,
+    ),
+    (
+        -2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -1.0035022433130363e+00,
+        4.7409030312943364e-03,
+    ),
+    (
+        5.0000000000000000e-01,
+        -3.0000000000000000e+00,
+        4.0481268348510667e+02,
+        1.1726091303384733e+03,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        9.9997790950300136e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        0.0000000000000000e+00,
+        -9.9959304798255499e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.5000000000000000e+00,
+        0.0000000000000000e+00,
+        4.5847332572844266e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -1.2969597307176392e+06,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        6.1607230237346114e-02,
+        1.8564679199971327e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e-03,
+        9.9999999999846290e-01,
+        3.1339685438965445e-14,
+    ),
+    (
+        -4.0000000000000000e+00,
+        -6.0000000000000000e+00,
+        1.0493233450621281e+07,
+        3.6619139187801234e+07,
+    ),
+    (
+        7.0000000000000000e+00,
+        7.5000000000000000e+00,
+        -4.0914068936867679e+01,
+        -6.5138051056669980e+01,
+    ),
+    (
+        2.0000000000000000e+01,
+        -2.0000000000000001e-01,
+        1.0000000000000000e+00,
+        -2.6623489527207864e-57,
+    ),
+    (
+        1.5000000000000000e+01,
+        1.0000000000000000e-04,
+        1.0000000000000000e+00,
+        -5.1715205886612011e-60,
+    ),
+    (
+        2.0000000000000001e-01,
+        2.0000000000000000e+01,
+        1.4038164674637482e+172,
+        -1.9209566440551557e+171,
+    ),
+    (
+        -1.2000000000000000e+01,
+        1.0000000000000001e-05,
+        -1.0000000000000000e+00,
+        -9.7457227317449329e-61,
+    ),
+];
+const COMPLEX_ERFC_TABLE: [(f64, f64, f64, f64); 19] = [
+    (
+        1.0000000000000000e-10,
+        2.0000000000000001e-10,
+        9.9999999988716204e-01,
+        -2.2567583341910252e-10,
+    ),
+    (
+        1.0000000000000001e-01,
+        -2.9999999999999999e-01,
+        8.7701959190381085e-01,
+        3.4526497009870288e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        6.4231752208838899e-01,
+        -3.1715474113943010e-01,
+    ),
+    (
+        -4.5000000000000001e-01,
+        1.0000000000000001e-01,
+        1.4796466004804796e+00,
+        -9.2336197170095183e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -3.1615128169794765e-01,
+        -1.9045346923783468e-01,
+    ),
+    (
+        -2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        2.0035022433130365e+00,
+        -4.7409030312943364e-03,
+    ),
+    (
+        5.0000000000000000e-01,
+        -3.0000000000000000e+00,
+        -4.0381268348510667e+02,
+        -1.1726091303384733e+03,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        2.2090496998585441e-05,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        0.0000000000000000e+00,
+        1.9995930479825550e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.5000000000000000e+00,
+        1.0000000000000000e+00,
+        -4.5847332572844266e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.2969597307176392e+06,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        9.3839276976265384e-01,
+        -1.8564679199971327e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e-03,
+        1.5371463869177120e-12,
+        -3.1339685438965445e-14,
+    ),
+    (
+        -4.0000000000000000e+00,
+        -6.0000000000000000e+00,
+        -1.0493232450621281e+07,
+        -3.6619139187801234e+07,
+    ),
+    (
+        7.0000000000000000e+00,
+        7.5000000000000000e+00,
+        4.1914068936867679e+01,
+        6.5138051056669980e+01,
+    ),
+    (
+        2.0000000000000000e+01,
+        -2.0000000000000001e-01,
+        -8.7247747215778460e-177,
+        5.5476077622387969e-176,
+    ),
+    (
+        1.5000000000000000e+01,
+        1.0000000000000000e-04,
+        7.2129616421887971e-100,
+        -2.1686825305247202e-102,
+    ),
+    (
+        2.0000000000000001e-01,
+        2.0000000000000000e+01,
+        -1.4038164674637482e+172,
+        1.9209566440551557e+171,
+    ),
+    (
+        -1.2000000000000000e+01,
+        1.0000000000000001e-05,
+        2.0000000000000000e+00,
+        -1.4178646555887489e-63,
+    ),
+];
+const COMPLEX_ERFCX_TABLE: [(f64, f64, f64, f64); 19] = [
+    (
+        1.0000000000000000e-10,
+        2.0000000000000001e-10,
+        9.9999999988716204e-01,
+        -2.2567583337910252e-10,
+    ),
+    (
+        1.0000000000000001e-01,
+        -2.9999999999999999e-01,
+        8.2724600691453054e-01,
+        2.6959988704429777e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        6.8872012089012613e-01,
+        -1.9703685972446097e-01,
+    ),
+    (
+        -4.5000000000000001e-01,
+        1.0000000000000001e-01,
+        1.7764203242319401e+00,
+        -2.7270278475711607e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        3.0474420525691259e-01,
+        -2.0821893820283163e-01,
+    ),
+    (
+        -2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -3.5635303512001890e+01,
+        -7.7380142375345429e+01,
+    ),
+    (
+        5.0000000000000000e-01,
+        -3.0000000000000000e+00,
+        3.7126366054692342e-02,
+        1.9298375530036208e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.7900115118138996e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        0.0000000000000000e+00,
+        1.0358148429726230e+03,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.5000000000000000e+00,
+        1.0539922456186433e-01,
+        -4.8322733014076907e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        1.1253517471925912e-07,
+        1.4595358990015278e-01,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        1.8547236370405552e-02,
+        -3.3995283120737863e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e-03,
+        1.1070462157031520e-01,
+        -4.2665573506478912e-05,
+    ),
+    (
+        -4.0000000000000000e+00,
+        -6.0000000000000000e+00,
+        -4.4140926062514026e-02,
+        6.4932541962824733e-02,
+    ),
+    (
+        7.0000000000000000e+00,
+        7.5000000000000000e+00,
+        3.7723897278275832e-02,
+        -4.0035857914588850e-02,
+    ),
+    (
+        2.0000000000000000e+01,
+        -2.0000000000000001e-01,
+        2.8171549031046236e-02,
+        2.8101563201966335e-04,
+    ),
+    (
+        1.5000000000000000e+01,
+        1.0000000000000000e-04,
+        3.7529606386856020e-02,
+        -2.4909754402305768e-07,
+    ),
+    (
+        2.0000000000000001e-01,
+        2.0000000000000000e+01,
+        2.8313075345109387e-04,
+        -2.8242032073783715e-02,
+    ),
+    (
+        -1.2000000000000000e+01,
+        1.0000000000000001e-05,
+        6.9093211137557074e+62,
+        -1.6582370991395224e+59,
+    ),
+];
+const COMPLEX_ERFI_TABLE: [(f64, f64, f64, f64); 19] = [
+    (
+        1.0000000000000000e-10,
+        2.0000000000000001e-10,
+        1.1283791670955126e-10,
+        2.2567583341910252e-10,
+    ),
+    (
+        1.0000000000000001e-01,
+        -2.9999999999999999e-01,
+        1.0340864129817522e-01,
+        -3.3173512856039805e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        3.1715474113943010e-01,
+        3.5768247791161106e-01,
+    ),
+    (
+        -4.5000000000000001e-01,
+        1.0000000000000001e-01,
+        -5.3804950071732383e-01,
+        1.3752101840614078e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.9045346923783468e-01,
+        1.3161512816979477e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0429925008314203e+00,
+        1.3839985667741278e+01,
+    ),
+    (
+        5.0000000000000000e-01,
+        -3.0000000000000000e+00,
+        -2.6284897222588233e-07,
+        -1.0000280653614764e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.6299946226015657e+03,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        0.0000000000000000e+00,
+        -1.3039575501324694e+02,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.5000000000000000e+00,
+        0.0000000000000000e+00,
+        9.6610514647531076e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -9.9999998458274209e-01,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        2.0666937131139316e-05,
+        9.9532230635288899e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e-03,
+        8.2966489615249491e+09,
+        1.6248660708449745e+08,
+    ),
+    (
+        -4.0000000000000000e+00,
+        -6.0000000000000000e+00,
+        1.5957451210396659e-10,
+        -1.0000000000184956e+00,
+    ),
+    (
+        7.0000000000000000e+00,
+        7.5000000000000000e+00,
+        -3.4207670342759538e-05,
+        9.9998118570641692e-01,
+    ),
+    (
+        2.0000000000000000e+01,
+        -2.0000000000000001e-01,
+        -1.9209566440551557e+171,
+        -1.4038164674637482e+172,
+    ),
+    (
+        1.5000000000000000e+01,
+        1.0000000000000000e-04,
+        1.9613757573454953e+96,
+        5.8710101967439577e+93,
+    ),
+    (
+        2.0000000000000001e-01,
+        2.0000000000000000e+01,
+        2.6623489527207864e-57,
+        1.0000000000000000e+00,
+    ),
+    (
+        -1.2000000000000000e+01,
+        1.0000000000000001e-05,
+        -1.6299357527463442e+61,
+        3.8981670768722385e+57,
+    ),
+];
+const COMPLEX_DAWSON_TABLE: [(f64, f64, f64, f64); 19] = [
+    (
+        1.0000000000000000e-10,
+        2.0000000000000001e-10,
+        1.0000000000000000e-10,
+        2.0000000000000001e-10,
+    ),
+    (
+        1.0000000000000001e-01,
+        -2.9999999999999999e-01,
+        1.1819483878241459e-01,
+        -3.1195229449229073e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.9999999999999999e-01,
+        3.3328019881676235e-01,
+        2.6154645575179630e-01,
+    ),
+    (
+        -4.5000000000000001e-01,
+        1.0000000000000001e-01,
+        -4.0078151460994749e-01,
+        6.4774352263106724e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        9.9037309232236137e-01,
+        -6.3887305156444330e-01,
+    ),
+    (
+        -2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        -2.7133655022167485e-01,
+        -1.0027272975386935e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        -3.0000000000000000e+00,
+        7.8926571742518956e+02,
+        5.5368879210064106e+03,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.7827103061055830e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        0.0000000000000000e+00,
+        -2.2308372216743549e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.5000000000000000e+00,
+        0.0000000000000000e+00,
+        8.1232893048692141e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -7.8751102844104907e+06,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        1.9363933877801304e-01,
+        4.8159576675109598e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e-03,
+        1.0213405614569332e-01,
+        -4.2681480445593323e-05,
+    ),
+    (
+        -4.0000000000000000e+00,
+        -6.0000000000000000e+00,
+        3.3032373665878123e+08,
+        2.7524059524574602e+08,
+    ),
+    (
+        7.0000000000000000e+00,
+        7.5000000000000000e+00,
+        -1.2110983254008343e+03,
+        -3.0072867389515125e+02,
+    ),
+    (
+        2.0000000000000000e+01,
+        -2.0000000000000001e-01,
+        2.5028849253287522e-02,
+        2.5091809713208578e-04,
+    ),
+    (
+        1.5000000000000000e+01,
+        1.0000000000000000e-04,
+        3.3407906807137656e-02,
+        -2.2372042590757635e-07,
+    ),
+    (
+        2.0000000000000001e-01,
+        2.0000000000000000e+01,
+        4.3986509542770920e+173,
+        -6.4688788388220596e+172,
+    ),
+    (
+        -1.2000000000000000e+01,
+        1.0000000000000001e-05,
+        -4.1812876453958703e-02,
+        -3.5090348957157498e-08,
+    ),
+];
+const COMPLEX_LARGE_TABLE: [(f64, f64, f64, f64, f64, f64); 4] = [
+    (
+        1.0000000000000000e+08,
+        5.0000000000000000e-01,
+        5.6418958354775623e-09,
+        -2.8209479177387812e-17,
+        5.0000000000000001e-09,
+        -2.5000000000000003e-17,
+    ),
+    (
+        1.0000000000000000e+08,
+        -5.0000000000000000e-01,
+        5.6418958354775623e-09,
+        2.8209479177387812e-17,
+        5.0000000000000001e-09,
+        2.5000000000000003e-17,
+    ),
+    (
+        3.0000000000000000e+05,
+        2.0000000000000001e-04,
+        1.8806319451487396e-06,
+        -1.2537546300852292e-15,
+        1.6666666666759260e-06,
+        -1.1111111111296297e-15,
+    ),
+    (
+        3.0000000000000000e+05,
+        -9.0000000000000002e-01,
+        1.8806319451318140e-06,
+        5.6418958353327541e-12,
+        1.6666666666609259e-06,
+        5.0000000000383335e-12,
+    ),
+];
+
+const W_NEAR_AXIS_TABLE: [(f64, f64, f64, f64); 12] = [
+    (
+        9.9000000000000004e+00,
+        1.0000000000000000e-08,
+        5.8468804737407683e-11,
+        5.7284143816628955e-02,
+    ),
+    (
+        1.0100000000000000e+01,
+        1.0000000000000000e-08,
+        5.6141191334490458e-11,
+        5.6138281496483249e-02,
+    ),
+    (
+        -5.0000000000000000e+01,
+        9.9999999999999995e-08,
+        2.2581137451456368e-11,
+        -1.1286049784700271e-02,
+    ),
+    (
+        2.0000000000000000e+03,
+        9.9999999999999995e-07,
+        1.4104744877974558e-13,
+        2.8209482703574033e-04,
+    ),
+    (
+        -3.0000000000000000e+04,
+        2.0000000000000002e-05,
+        1.2537546321957162e-14,
+        -1.8806319462039832e-05,
+    ),
+    (
+        1.0000000000000000e+05,
+        1.0000000000000000e-04,
+        5.6418958363238478e-15,
+        5.6418958357596576e-06,
+    ),
+    (
+        1.0000000000000000e-08,
+        9.9000000000000004e+00,
+        5.6702456938832267e-02,
+        5.6705197066336224e-11,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.0100000000000000e+01,
+        5.5590487009239874e-02,
+        5.4513295088671180e-11,
+    ),
+    (
+        9.9999999999999995e-08,
+        5.0000000000000000e+01,
+        1.1281536265323772e-02,
+        2.2554056313532385e-11,
+    ),
+    (
+        9.9999999999999995e-07,
+        2.0000000000000000e+03,
+        2.8209475651204240e-04,
+        1.4104734299419867e-13,
+    ),
+    (
+        -2.0000000000000002e-05,
+        3.0000000000000000e+04,
+        1.8806319441143922e-05,
+        -1.2537546280165342e-14,
+    ),
+    (
+        1.0000000000000000e-04,
+        1.0000000000000000e+05,
+        5.6418958351954685e-06,
+        5.6418958346312789e-15,
+    ),
+];
@@ -0,0 +1,355 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use num_complex::Complex64;
+use puruspe::{complex_fresnel, fresnel, fresnel_aux};
+
+#[test]
+fn test_fresnel() {
+    for (x, s, c) in FRESNEL_TABLE {
+        let actual = fresnel(x);
+        assert_relative_eq!(actual.0, s, max_relative = 1e-15);
+        assert_relative_eq!(actual.1, c, max_relative = 1e-15);
+    }
+}
+
+#[test]
+fn test_fresnel_aux() {
+    // g(x) = (w_re - w_im) / 2 cancels below the asymptotic range, by about πx².
+    for (x, f, g) in FRESNEL_AUX_TABLE {
+        let actual = fresnel_aux(x);
+        assert_relative_eq!(actual.0, f, max_relative = 1e-15);
+        assert_relative_eq!(actual.1, g, max_relative = 1e-14);
+    }
+}
+
+#[test]
+fn test_complex_fresnel() {
+    for (re, im, s_re, s_im, c_re, c_im) in COMPLEX_FRESNEL_TABLE {
+        let (s, c) = complex_fresnel(Complex64::new(re, im));
+        assert_complex_eq(s, Complex64::new(s_re, s_im));
+        assert_complex_eq(c, Complex64::new(c_re, c_im));
+    }
+}
+
+#[test]
+fn test_fresnel_identities() {
+    for &x in [-9.0, -3.1, -0.4, 0.05, 0.8, 1.7, 4.2, 6.5, 30.0].iter() {
+        let (s, c) = fresnel(x);
+        let (f, g) = fresnel_aux(x);
+        // The real path agrees with the complex one on the real axis.
+        let (s_complex, c_complex) = complex_fresnel(Complex64::new(x, 0.0));
+        assert_relative_eq!(s_complex.re, s, max_relative = 1e-14);
+        assert_relative_eq!(c_complex.re, c, max_relative = 1e-14);
+        // S(ix) = -i S(x) and C(ix) = i C(x)
+        let (s_imag, c_imag) = complex_fresnel(Complex64::new(0.0, x));
+        assert_complex_eq(s_imag, Complex64::new(0.0, -s));
+        assert_complex_eq(c_imag, Complex64::new(0.0, c));
+        // f² + g² = (1/2 - C)² + (1/2 - S)²
+        assert_relative_eq!(
+            f * f + g * g,
+            (0.5 - c).powi(2) + (0.5 - s).powi(2),
+            max_relative = 1e-13
+        );
+    }
+}
+
+fn assert_complex_eq(actual: Complex64, expected: Complex64) {
+    assert!(
+        (actual - expected).norm() <= 1e-14 * expected.norm(),
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_fresnel_edge_cases() {
+    assert_eq!(fresnel(0.0), (0.0, 0.0));
+    assert_eq!(fresnel(f64::INFINITY), (0.5, 0.5));
+    assert_eq!(fresnel(f64::NEG_INFINITY), (-0.5, -0.5));
+    assert!(fresnel(f64::NAN).0.is_nan());
+    assert_eq!(fresnel_aux(0.0), (0.5, 0.5));
+    assert_eq!(fresnel_aux(f64::INFINITY), (0.0, 0.0));
+    assert!(fresnel_aux(f64::NAN).1.is_nan());
+    let zero = Complex64::new(0.0, 0.0);
+    assert_eq!(complex_fresnel(zero), (zero, zero));
+    assert!(complex_fresnel(Complex64::new(f64::NAN, 1.0)).0.re.is_nan());
+}
+
+const FRESNEL_TABLE: [(f64, f64, f64); 17] = [
+    (
+        1.0000000000000000e-10,
+        5.2359877559829890e-31,
+        1.0000000000000000e-10,
+    ),
+    (
+        1.0000000000000001e-01,
+        5.2358954761221065e-04,
+        9.9997532627085078e-02,
+    ),
+    (
+        5.0000000000000000e-01,
+        6.4732432859999273e-02,
+        4.9234422587144638e-01,
+    ),
+    (
+        9.0000000000000002e-01,
+        3.3977634439314025e-01,
+        7.6482302127332646e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        4.3825914739035476e-01,
+        7.7989340037682287e-01,
+    ),
+    (
+        1.2000000000000000e+00,
+        6.2340091854624968e-01,
+        7.1543772292307339e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.4341567836369824e-01,
+        4.8825340607534073e-01,
+    ),
+    (
+        3.2999999999999998e+00,
+        5.1928608498206308e-01,
+        4.0569440370625848e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        4.9919138191711687e-01,
+        5.6363118870401219e-01,
+    ),
+    (
+        5.5999999999999996e+00,
+        4.7003880651486130e-01,
+        4.5171362460101600e-01,
+    ),
+    (
+        8.0000000000000000e+00,
+        4.6021421439301446e-01,
+        4.9980218037719715e-01,
+    ),
+    (
+        1.5500000000000000e+01,
+        4.8101678542100390e-01,
+        5.0783365540525172e-01,
+    ),
+    (
+        1.0030000000000000e+02,
+        4.9685806843508340e-01,
+        5.0044706165349895e-01,
+    ),
+    (
+        1.0000000000000000e+04,
+        4.9996816901138164e-01,
+        4.9999999999989869e-01,
+    ),
+    (
+        -6.9999999999999996e-01,
+        -1.7213645786347742e-01,
+        -6.5965235190451033e-01,
+    ),
+    (
+        -2.5000000000000000e+00,
+        -6.1918175581959289e-01,
+        -4.5741300964177706e-01,
+    ),
+    (
+        -7.0000000000000000e+00,
+        -4.9970478945344676e-01,
+        -5.4546709254696979e-01,
+    ),
+];
+const FRESNEL_AUX_TABLE: [(f64, f64, f64); 17] = [
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e-01,
+        4.9999999989999999e-01,
+    ),
+    (
+        1.0000000000000001e-01,
+        4.9313182560662361e-01,
+        4.0779855452993036e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.9920505852570226e-01,
+        1.7364269961323775e-01,
+    ),
+    (
+        9.0000000000000002e-01,
+        3.0022820969538477e-01,
+        7.5272003530279602e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.7989340037682281e-01,
+        6.1740852609645236e-02,
+    ),
+    (
+        1.2000000000000000e+00,
+        2.4465632434409282e-01,
+        4.2243130977416325e-02,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.5658432163630176e-01,
+        1.1746593924659246e-02,
+    ),
+    (
+        3.2999999999999998e+00,
+        9.6217161656430197e-02,
+        2.7850258587474920e-03,
+    ),
+    (
+        5.0000000000000000e+00,
+        6.3631188704012229e-02,
+        8.0861808288311323e-04,
+    ),
+    (
+        5.5999999999999996e+00,
+        5.6823545449523151e-02,
+        5.7606143031828014e-04,
+    ),
+    (
+        8.0000000000000000e+00,
+        3.9785785606985515e-02,
+        1.9781962280286444e-04,
+    ),
+    (
+        1.5500000000000000e+01,
+        2.0536013549251442e-02,
+        2.7207818754760817e-05,
+    ),
+    (
+        1.0030000000000000e+02,
+        3.1735781179239148e-03,
+        1.0041473559140012e-07,
+    ),
+    (
+        1.0000000000000000e+04,
+        3.1830988618379067e-05,
+        1.0132118364233775e-13,
+    ),
+    (
+        -6.9999999999999996e-01,
+        -3.2433804521155968e-01,
+        1.3005252122489854e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        -6.6760302064106058e-01,
+        -1.3128265995254986e+00,
+    ),
+    (
+        -7.0000000000000000e+00,
+        -1.0454670925469698e+00,
+        9.9970478945344676e-01,
+    ),
+];
+const COMPLEX_FRESNEL_TABLE: [(f64, f64, f64, f64, f64, f64); 12] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        -1.3678165772913883e-01,
+        1.3678165772913883e-01,
+        5.3173595500717019e-01,
+        5.3173595500717019e-01,
+    ),
+    (
+        1.2000000000000000e+00,
+        2.9999999999999999e-01,
+        7.3254702157784946e-01,
+        2.9571264415680482e-01,
+        8.6895340252875242e-01,
+        -2.1873268217541067e-01,
+    ),
+    (
+        -8.0000000000000004e-01,
+        9.0000000000000002e-01,
+        1.1258289568217608e+00,
+        6.5404117707080911e-01,
+        -1.1330512453427755e+00,
+        1.6150764705770428e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.5587751104404587e+01,
+        -3.6725464883991435e+01,
+        -3.6225687992881653e+01,
+        1.6087871374125481e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        -5.0000000000000000e-01,
+        -1.0192710374417915e+00,
+        -5.6746344797139958e+00,
+        6.1754063868112556e+00,
+        -1.5187559724006190e+00,
+    ),
+    (
+        -4.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        2.6646835650606132e+09,
+        -1.2985165080280459e+09,
+        -1.2985165085280459e+09,
+        -2.6646835655606132e+09,
+    ),
+    (
+        1.0000000000000001e-01,
+        3.0000000000000000e+00,
+        -1.1547270043812415e-01,
+        -4.9590513138043990e-01,
+        6.7066378214119919e-04,
+        6.5643566057249647e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0000000000000001e-01,
+        4.9838903151412767e-01,
+        1.4650022466696347e-01,
+        6.5970414546098288e-01,
+        9.7185658804708639e-04,
+    ),
+    (
+        1.5000000000000000e+00,
+        -1.5000000000000000e+00,
+        -6.8098465066989206e+01,
+        -6.8098465066989206e+01,
+        6.8598380090751618e+01,
+        -6.8598380090751618e+01,
+    ),
+    (
+        -2.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.1933373167808477e+04,
+        1.1933373167808477e+04,
+        -1.1933873167540894e+04,
+        1.1933873167540894e+04,
+    ),
+    (
+        0.0000000000000000e+00,
+        2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -3.4341567836369824e-01,
+        0.0000000000000000e+00,
+        4.8825340607534073e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        0.0000000000000000e+00,
+        4.9631299896737502e-01,
+        0.0000000000000000e+00,
+        6.0572078929768558e-01,
+        0.0000000000000000e+00,
+    ),
+];
@@ -1,6 +1,14 @@
+#![allow(clippy::excessive_precision, clippy::approx_constant)]
+
 use approx::assert_relative_eq;
+use num_complex::Complex64;
 use proptest::prelude::*;
-use puruspe::{gamma, gammp, gammq, invgammp, ln_gamma, utils::factorial};
+use puruspe::{
+    complex_gamma, complex_ln_gamma, digamma, erfc, gamma, gammp, gammp_grad, gammq, invgammp,
+    invgammq, ln_gamma, ln_gamma_sign, ln_lower_incomplete_gamma, ln_upper_incomplete_gamma,
+    lower_incomplete_gamma, polygamma, trigamma, try_gammp, try_gammq, try_invgammp,
+    upper_incomplete_gamma, utils::factorial, SpecialFunctionError,
+};
 
 unsafe extern "C" {
     fn tgamma(x: f64) -> f64;
@@ -11,9 +19,21 @@ unsafe extern "C" {
 fn test_ln_gamma() {
     for (x, y) in LN_GAMMA_TABLE {
         let result = ln_gamma(x);
-        let abs_eps = 1e-10;
-        let rel_eps = 1e-8;
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, y, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_ln_gamma_sign() {
+    for (x, y, sign) in LN_GAMMA_SIGN_TABLE {
+        let (result, result_sign) = ln_gamma_sign(x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
         assert_relative_eq!(result, y, epsilon = abs_eps, max_relative = rel_eps);
+        assert_eq!(result_sign, sign);
+        assert_eq!(ln_gamma(x), result);
     }
 }
 
@@ -49,6 +69,43 @@ fn test_gammq() {
     }
 }
 
+#[test]
+fn test_gammp_gammq_quadrature_below_mean() {
+    // For a ≥ 100 and a - 5√a ≤ x < a - 1 the quadrature integrates downwards from x,
+    // where the integral gives P rather than Q.
+    for &(a, x, p, q) in [
+        (200.0, 180.0, 0.074858034984159582, 0.92514196501584042),
+        (150.0, 140.5, 0.2220426946345632, 0.7779573053654368),
+        (500.0, 420.0, 8.0496549766092322e-5, 0.99991950345023391),
+        (1000.0, 950.0, 0.055054686230738034, 0.94494531376926197),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(gammp(a, x), p, max_relative = 1e-10);
+        assert_relative_eq!(gammq(a, x), q, max_relative = 1e-10);
+    }
+}
+
+#[test]
+fn test_gammp_far_below_mean() {
+    // Further below the mean the quadrature cannot resolve the narrow integrand, and the series is used.
+    for &(a, x, p) in [
+        (500.0, 100.0, 3.8087542289264636e-178),
+        (500.0, 200.0, 6.1742614345288565e-71),
+        (771.0, 250.0, 3.0718539142779097e-153),
+        (1e4, 9400.0, 4.6485246081212703e-10),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(gammp(a, x), p, max_relative = 1e-11);
+    }
+    assert_relative_eq!(
+        gammq(1e4, 9400.0),
+        0.99999999953514754,
+        max_relative = 1e-15
+    );
+}
+
 #[test]
 fn test_invgammp() {
     for &(a, p, expected) in INVGAMMP_TABLE.iter() {
@@ -59,6 +116,123 @@ fn test_invgammp() {
     }
 }
 
+#[test]
+fn test_invgammq() {
+    for &(a, q, expected) in INVGAMMQ_TABLE.iter() {
+        let result = invgammq(q, a);
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_digamma() {
+    for (x, y) in DIGAMMA_TABLE {
+        let result = digamma(x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, y, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_polygamma() {
+    for (n, x, y) in POLYGAMMA_TABLE {
+        let result = polygamma(n, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, y, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_polygamma_large_x() {
+    // n!/x^(n+1) underflows here, while the sum grows like x/n and brings the result back into range.
+    for (n, x, y) in [
+        (1, 1e300, 1e-300),
+        (1, 1e160, 1e-160),
+        (3, 1e100, 2e-300),
+        (4, 1e70, -6e-280),
+    ] {
+        assert_relative_eq!(polygamma(n, x), y, epsilon = 0.0, max_relative = 1e-12);
+    }
+    // The result itself underflows.
+    assert_eq!(polygamma(2, 1e200), 0.0);
+}
+
+#[test]
+fn test_complex_ln_gamma() {
+    for (re, im, w_re, w_im) in COMPLEX_LN_GAMMA_TABLE {
+        let result = complex_ln_gamma(Complex64::new(re, im));
+        let expected = Complex64::new(w_re, w_im);
+        let rel_eps = 1e-14;
+        assert!((result - expected).norm() <= rel_eps * expected.norm());
+    }
+}
+
+#[test]
+fn test_complex_gamma() {
+    for (re, im, w_re, w_im) in COMPLEX_GAMMA_TABLE {
+        let result = complex_gamma(Complex64::new(re, im));
+        let expected = Complex64::new(w_re, w_im);
+        let rel_eps = 1e-13;
+        assert!((result - expected).norm() <= rel_eps * expected.norm());
+    }
+}
+
+#[test]
+fn test_upper_incomplete_gamma() {
+    for (a, x, y) in UPPER_INCOMPLETE_GAMMA_TABLE {
+        let result = upper_incomplete_gamma(a, x);
+        // Values close to the limits of f64 are computed through their logarithms.
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, y, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_lower_incomplete_gamma() {
+    for (a, x, y) in LOWER_INCOMPLETE_GAMMA_TABLE {
+        let result = lower_incomplete_gamma(a, x);
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, y, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_ln_incomplete_gamma() {
+    for (a, x, y) in LN_UPPER_INCOMPLETE_GAMMA_TABLE {
+        let result = ln_upper_incomplete_gamma(a, x);
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, y, max_relative = rel_eps);
+    }
+    for (a, x, y) in LN_LOWER_INCOMPLETE_GAMMA_TABLE {
+        let result = ln_lower_incomplete_gamma(a, x);
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, y, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_gammp_grad() {
+    for (a, x, y, dy) in GAMMP_GRAD_TABLE {
+        let (result, dresult) = gammp_grad(a, x);
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, y, max_relative = rel_eps);
+        assert_relative_eq!(dresult, dy, max_relative = rel_eps);
+    }
+    // The value agrees with gammp, and the derivative with a central difference
+    for &(a, x, _) in GAMMP_TABLE.iter() {
+        let (result, dresult) = gammp_grad(a, x);
+        assert_relative_eq!(result, gammp(a, x), epsilon = 1e-14, max_relative = 1e-12);
+        let h = 1e-6 * a;
+        let diff = (gammp(a + h, x) - gammp(a - h, x)) / (2.0 * h);
+        assert_relative_eq!(dresult, diff, epsilon = 1e-8, max_relative = 1e-5);
+    }
+    assert_eq!(gammp_grad(2.0, 0.0), (0.0, 0.0));
+    assert_eq!(gammp_grad(2.0, f64::INFINITY), (1.0, 0.0));
+}
+
 // ┌─────────────────────────────────────────────────────────┐
 //  Edge Case Tests
 // └─────────────────────────────────────────────────────────┘
@@ -162,6 +336,153 @@ fn test_ln_gamma_edge_cases() {
     // Test extreme large value
     let result_extreme = ln_gamma(1e10);
     assert!(result_extreme > 2e11 && result_extreme < 2.3e11);
+
+    // Special values follow the C function lgamma.
+    assert!(ln_gamma(f64::NAN).is_nan());
+    assert_eq!(ln_gamma(f64::INFINITY), f64::INFINITY);
+    assert_eq!(ln_gamma(f64::NEG_INFINITY), f64::INFINITY);
+    assert_eq!(ln_gamma_sign(0.0), (f64::INFINITY, 1));
+    assert_eq!(ln_gamma_sign(-0.0), (f64::INFINITY, -1));
+    for n in 1..10 {
+        assert_eq!(ln_gamma_sign(-n as f64), (f64::INFINITY, 1));
+    }
+
+    // Subnormal inputs, where 1/z overflows.
+    assert_relative_eq!(ln_gamma(5e-324), -(5e-324_f64).ln());
+
+    // Agrees with the gamma function where that is finite.
+    for x in [-170.5, -30.2, -3.7, -0.9, 0.01, 0.7, 1.3, 2.9, 33.3, 170.1] {
+        let (result, sign) = ln_gamma_sign(x);
+        let g = gamma(x);
+        assert_relative_eq!(result, g.abs().ln(), max_relative = 1e-13);
+        assert_eq!(sign as f64, g.signum());
+    }
+}
+
+#[test]
+fn test_polygamma_edge_cases() {
+    // NaN and -∞ have no meaningful output.
+    assert!(digamma(f64::NAN).is_nan());
+    assert!(digamma(f64::NEG_INFINITY).is_nan());
+    assert!(polygamma(3, f64::NAN).is_nan());
+    assert!(polygamma(3, f64::NEG_INFINITY).is_nan());
+
+    // ψ(+∞) = +∞ while the higher derivatives vanish.
+    assert_eq!(digamma(f64::INFINITY), f64::INFINITY);
+    assert_eq!(trigamma(f64::INFINITY), 0.0);
+
+    // ψ(±0) = ∓∞, like the signed infinities of Γ(±0).
+    assert_eq!(digamma(0.0), -f64::INFINITY);
+    assert_eq!(digamma(-0.0), f64::INFINITY);
+    assert_eq!(polygamma(2, 0.0), -f64::INFINITY);
+    assert_eq!(polygamma(2, -0.0), f64::INFINITY);
+
+    // The odd order polygamma functions diverge to +∞ from both sides of a pole.
+    assert_eq!(trigamma(0.0), f64::INFINITY);
+    assert_eq!(trigamma(-0.0), f64::INFINITY);
+    assert_eq!(trigamma(-3.0), f64::INFINITY);
+
+    // The even order ones do not have a limit at the negative integers.
+    assert!(digamma(-1.0).is_nan());
+    assert!(polygamma(2, -4.0).is_nan());
+
+    // ψ(1) = -γ
+    let euler_gamma = 0.577_215_664_901_532_9;
+    assert_relative_eq!(digamma(1.0), -euler_gamma, max_relative = 1e-15);
+
+    // ψ(1/2) = -γ - 2ln(2)
+    assert_relative_eq!(
+        digamma(0.5),
+        -euler_gamma - 2.0 * 2_f64.ln(),
+        max_relative = 1e-15
+    );
+
+    // ψ₁(1) = π²/6
+    assert_relative_eq!(
+        trigamma(1.0),
+        core::f64::consts::PI.powi(2) / 6.0,
+        max_relative = 1e-15
+    );
+
+    // polygamma(0, x) is the digamma function.
+    for x in [-2.5, 0.3, 1.7, 42.0] {
+        assert_eq!(polygamma(0, x), digamma(x));
+    }
+
+    // The recurrence ψ⁽ⁿ⁾(x + 1) = ψ⁽ⁿ⁾(x) + (-1)ⁿ n!/x^(n+1).
+    for n in 0..6 {
+        for x in [2.0, 3.5, 7.5, 31.0] {
+            let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+            let expected = polygamma(n, x) + sign * factorial(n as usize) / x.powi(n as i32 + 1);
+            assert_relative_eq!(polygamma(n, x + 1.0), expected, max_relative = 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_complex_gamma_edge_cases() {
+    // The poles of Γ give NaN, as do NaN inputs.
+    for re in [0.0, -1.0, -7.0] {
+        let z = Complex64::new(re, 0.0);
+        assert!(complex_gamma(z).is_nan());
+        assert!(complex_ln_gamma(z).is_nan());
+    }
+    assert!(complex_gamma(Complex64::new(f64::NAN, 1.0)).is_nan());
+    assert!(complex_ln_gamma(Complex64::new(1.0, f64::NAN)).is_nan());
+
+    // On the real axis the results agree with the real functions.
+    for x in [0.3, 1.0, 2.5, 17.25, 150.0] {
+        let z = Complex64::new(x, 0.0);
+        assert_relative_eq!(complex_gamma(z).re, gamma(x), max_relative = 1e-15);
+        assert_eq!(complex_gamma(z).im, 0.0);
+        assert_relative_eq!(
+            complex_ln_gamma(z).re,
+            ln_gamma(x),
+            epsilon = 1e-15,
+            max_relative = 1e-14
+        );
+        assert_eq!(complex_ln_gamma(z).im, 0.0);
+    }
+
+    // For negative real arguments the imaginary part of log Γ is an odd multiple of π where Γ is negative.
+    let z = Complex64::new(-2.5, 0.0);
+    let (ln_abs, sign) = ln_gamma_sign(-2.5);
+    assert_eq!(sign, -1);
+    assert_relative_eq!(complex_ln_gamma(z).re, ln_abs, epsilon = 1e-14);
+    assert_relative_eq!(
+        complex_ln_gamma(z).im,
+        -3.0 * core::f64::consts::PI,
+        max_relative = 1e-15
+    );
+
+    // Both functions are symmetric under complex conjugation.
+    for z in [
+        Complex64::new(0.7, 2.0),
+        Complex64::new(-3.3, 0.4),
+        Complex64::new(12.0, -30.0),
+    ] {
+        assert_eq!(complex_gamma(z.conj()), complex_gamma(z).conj());
+        assert_eq!(complex_ln_gamma(z.conj()), complex_ln_gamma(z).conj());
+    }
+
+    // The recurrence Γ(z + 1) = zΓ(z).
+    for z in [
+        Complex64::new(0.5, 1.5),
+        Complex64::new(-4.2, 0.9),
+        Complex64::new(3.0, -8.0),
+    ] {
+        let lhs = complex_gamma(z + 1.0);
+        let rhs = z * complex_gamma(z);
+        assert!((lhs - rhs).norm() <= 1e-13 * rhs.norm());
+    }
+
+    // Γ(i) and log Γ far away from the real axis, where Γ itself underflows.
+    let z = Complex64::new(0.0, 1.0);
+    let expected = Complex64::new(-0.154_949_828_301_810_68, -0.498_015_668_118_356_04);
+    assert!((complex_gamma(z) - expected).norm() <= 1e-14 * expected.norm());
+    let z = Complex64::new(1.0, 1000.0);
+    assert_eq!(complex_gamma(z), Complex64::new(0.0, 0.0));
+    assert!(complex_ln_gamma(z).re.is_finite());
 }
 
 #[test]
@@ -192,6 +513,18 @@ fn test_gammp_edge_cases() {
     let result_large_a = gammp(150.0, 150.0);
     assert!(result_large_a > 0.45 && result_large_a < 0.55);
 
+    // Large a below the mean, where the quadrature runs downwards from x
+    assert_relative_eq!(
+        gammp(200.0, 150.0),
+        5.709688574208240e-5,
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        gammq(1000.0, 990.0),
+        0.6204786214620360,
+        max_relative = 1e-10
+    );
+
     // Test complement property: P(a,x) + Q(a,x) = 1
     let a = 3.5;
     let x = 2.7;
@@ -209,6 +542,84 @@ fn test_gammp_edge_cases() {
     }
 }
 
+#[test]
+fn test_incomplete_gamma_edge_cases() {
+    // At x = 0 the upper function is Γ(a), or diverges for a ≤ 0.
+    assert_relative_eq!(
+        upper_incomplete_gamma(4.5, 0.0),
+        gamma(4.5),
+        max_relative = 1e-14
+    );
+    assert_eq!(upper_incomplete_gamma(0.0, 0.0), f64::INFINITY);
+    assert_eq!(upper_incomplete_gamma(-2.5, 0.0), f64::INFINITY);
+    assert_eq!(lower_incomplete_gamma(4.5, 0.0), 0.0);
+
+    // At x = ∞ the lower function is Γ(a).
+    assert_eq!(upper_incomplete_gamma(-2.5, f64::INFINITY), 0.0);
+    assert_relative_eq!(
+        lower_incomplete_gamma(4.5, f64::INFINITY),
+        gamma(4.5),
+        max_relative = 1e-14
+    );
+
+    assert!(upper_incomplete_gamma(f64::NAN, 1.0).is_nan());
+
+    // Γ(1,x) = e⁻ˣ and Γ(1/2,x) = √π erfc(√x).
+    for x in [1e-3, 0.7, 1.5, 12.0, 600.0] {
+        assert_relative_eq!(
+            upper_incomplete_gamma(1.0, x),
+            (-x).exp(),
+            max_relative = 1e-13
+        );
+        assert_relative_eq!(
+            upper_incomplete_gamma(0.5, x),
+            core::f64::consts::PI.sqrt() * erfc(x.sqrt()),
+            max_relative = 1e-13
+        );
+    }
+
+    // The two functions add up to Γ(a) and agree with the regularized ones.
+    for a in [0.1, 1.0, 3.7, 25.0, 140.0] {
+        for x in [0.05, 1.0, 4.0, 30.0, 150.0] {
+            let upper = upper_incomplete_gamma(a, x);
+            let lower = lower_incomplete_gamma(a, x);
+            assert_relative_eq!(upper + lower, gamma(a), max_relative = 1e-13);
+            assert_relative_eq!(
+                upper / gamma(a),
+                gammq(a, x),
+                epsilon = 1e-14,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                lower / gamma(a),
+                gammp(a, x),
+                epsilon = 1e-14,
+                max_relative = 1e-12
+            );
+        }
+    }
+
+    // The recurrence relation Γ(a,x) = (Γ(a+1,x) - xᵃe⁻ˣ)/a, also for negative a.
+    for a in [-12.3, -4.0, -1.5, -0.2, 2.6] {
+        for x in [0.02, 0.9, 3.0, 17.0] {
+            let expected = (upper_incomplete_gamma(a + 1.0, x) - x.powf(a) * (-x).exp()) / a;
+            assert_relative_eq!(upper_incomplete_gamma(a, x), expected, max_relative = 1e-12);
+        }
+    }
+
+    // The logarithms stay finite where the functions themselves overflow or underflow.
+    assert_eq!(upper_incomplete_gamma(-400.0, 1e-3), f64::INFINITY);
+    assert!(ln_upper_incomplete_gamma(-400.0, 1e-3).is_finite());
+    assert_eq!(upper_incomplete_gamma(2.0, 1e3), 0.0);
+    assert_relative_eq!(
+        ln_upper_incomplete_gamma(2.0, 1e3),
+        -1e3 + 1001_f64.ln(),
+        max_relative = 1e-15
+    );
+    assert_eq!(lower_incomplete_gamma(400.0, 0.1), 0.0);
+    assert!(ln_lower_incomplete_gamma(400.0, 0.1).is_finite());
+}
+
 #[test]
 fn test_gammq_edge_cases() {
     // Test when x = 0 (should be 1)
@@ -290,6 +701,71 @@ fn test_invgammp_edge_cases() {
     assert_relative_eq!(p_check_large, 0.5, epsilon = 1e-9);
 }
 
+#[test]
+fn test_invgammq_edge_cases() {
+    // Test q = 0 and q = 1
+    assert_eq!(invgammq(0.0, 2.0), f64::INFINITY);
+    assert_eq!(invgammq(1.0, 2.0), 0.0);
+
+    // Q(1,x) = e⁻ˣ, also far out in the tail where 1 - q rounds to 1
+    for q in [1e-300, 1e-30, 1e-3, 0.5, 0.75] {
+        assert_relative_eq!(invgammq(q, 1.0), -q.ln(), max_relative = 1e-14);
+    }
+
+    // Solutions too small to be represented
+    assert_eq!(invgammq(0.9, 1e-3), 0.0);
+
+    // Test round-trip: gammq(a, invgammq(q, a)) ≈ q
+    for a in [0.3, 1.0, 4.5, 30.0, 500.0].iter() {
+        for q in [1e-12, 0.01, 0.4, 0.6, 0.99].iter() {
+            let x = invgammq(*q, *a);
+            assert_relative_eq!(gammq(*a, x), *q, max_relative = 1e-10);
+        }
+    }
+
+    // Agreement with invgammp in the bulk of the distribution
+    for a in [0.5, 2.0, 10.0].iter() {
+        for q in [0.1, 0.5, 0.9].iter() {
+            let x = invgammq(*q, *a);
+            assert_relative_eq!(x, invgammp(1.0 - q, *a), max_relative = 1e-8);
+        }
+    }
+}
+
+#[test]
+fn test_try_gammp_gammq_invgammp() {
+    for &(a, x, _) in GAMMP_TABLE.iter() {
+        assert_eq!(try_gammp(a, x), Ok(gammp(a, x)));
+        assert_eq!(try_gammq(a, x), Ok(gammq(a, x)));
+    }
+    for &(a, p, _) in INVGAMMP_TABLE.iter() {
+        assert_eq!(try_invgammp(p, a), Ok(invgammp(p, a)));
+    }
+
+    assert_eq!(
+        try_gammp(1.0, -1.0),
+        Err(SpecialFunctionError::Domain("Bad args in gammp"))
+    );
+    assert_eq!(
+        try_gammq(0.0, 1.0),
+        Err(SpecialFunctionError::Domain("Bad args in gammq"))
+    );
+    assert_eq!(
+        try_invgammp(0.5, -2.0),
+        Err(SpecialFunctionError::Domain(
+            "a must be positive in invgammp"
+        ))
+    );
+    assert!(try_gammp(f64::NAN, 1.0).is_err());
+    assert!(try_invgammp(0.5, f64::NAN).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Bad args in gammp_grad")]
+fn test_gammp_grad_negative_a() {
+    gammp_grad(-1.0, 1.0);
+}
+
 #[test]
 #[should_panic(expected = "Bad args in gammp")]
 fn test_gammp_negative_x() {
@@ -338,6 +814,30 @@ fn test_invgammp_zero_a() {
     invgammp(0.5, 0.0);
 }
 
+#[test]
+#[should_panic(expected = "a must be positive in invgammq")]
+fn test_invgammq_zero_a() {
+    invgammq(0.5, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Bad x in ln_upper_incomplete_gamma")]
+fn test_upper_incomplete_gamma_negative_x() {
+    upper_incomplete_gamma(1.0, -1.0);
+}
+
+#[test]
+#[should_panic(expected = "Bad args in ln_lower_incomplete_gamma")]
+fn test_lower_incomplete_gamma_negative_x() {
+    lower_incomplete_gamma(1.0, -1.0);
+}
+
+#[test]
+#[should_panic(expected = "Bad args in ln_lower_incomplete_gamma")]
+fn test_lower_incomplete_gamma_zero_a() {
+    lower_incomplete_gamma(0.0, 1.0);
+}
+
 proptest! {
     #[test]
     fn test_gamma_proptest(x in 0.00001f64..100000.0) {
@@ -352,8 +852,18 @@ proptest! {
     fn test_ln_gamma_proptest(x in 0.00001f64..100000.0) {
         let result = ln_gamma(x);
         let expected = unsafe { lgamma(x) };
-        let abs_eps = 1e-10;
-        let rel_eps = 1e-8;
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+
+    #[test]
+    fn test_ln_gamma_negative_proptest(x in -1000.0f64..0.0) {
+        let result = ln_gamma(x);
+        let expected = unsafe { lgamma(x) };
+        // The absolute tolerance accounts for the roots of ln|Γ| on the negative axis.
+        let abs_eps = 1e-13;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
@@ -1047,3 +1557,1558 @@ const INVGAMMP_TABLE: [(f64, f64, f64); 42] = [
         1.87831173933125e+01,
     ),
 ];
+
+const INVGAMMQ_TABLE: [(f64, f64, f64); 63] = [
+    (
+        1.0000000000000001e-01,
+        1.0000000000000000e-300,
+        6.8264811806652438e+02,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000000e-100,
+        2.2313479066961921e+02,
+    ),
+    (
+        1.0000000000000001e-01,
+        9.9999999999999995e-21,
+        4.0447481384592756e+01,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000001e-05,
+        7.3594818139790039e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000000e-02,
+        1.5884778179295052e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        2.9999999999999999e-01,
+        1.7427776389282008e-02,
+    ),
+    (
+        1.0000000000000001e-01,
+        5.0000000000000000e-01,
+        5.9339110446022614e-04,
+    ),
+    (
+        1.0000000000000001e-01,
+        9.0000000000000002e-01,
+        6.0730483627431676e-11,
+    ),
+    (
+        1.0000000000000001e-01,
+        9.9900000000000000e-01,
+        6.0730483624079598e-31,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e-300,
+        6.8693631561119707e+02,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e-100,
+        2.2697154111939949e+02,
+    ),
+    (
+        5.0000000000000000e-01,
+        9.9999999999999995e-21,
+        4.3580866713454910e+01,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-05,
+        9.7557104823287855e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e-02,
+        3.3174483005106077e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        2.9999999999999999e-01,
+        5.3709708542879264e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        2.2746821155978639e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        7.8953870467156091e-03,
+    ),
+    (
+        5.0000000000000000e-01,
+        9.9900000000000000e-01,
+        7.8539857463124637e-07,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-300,
+        6.9077552789821368e+02,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-100,
+        2.3025850929940458e+02,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9999999999999995e-21,
+        4.6051701859880914e+01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000001e-05,
+        1.1512925464970229e+01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-02,
+        4.6051701859880909e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.2039728043259361e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        6.9314718055994529e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.0000000000000002e-01,
+        1.0536051565782628e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.0005003335835344e-03,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e-300,
+        6.9732421137935262e+02,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e-100,
+        2.3572541016192412e+02,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9999999999999995e-21,
+        4.9983197987090747e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000001e-05,
+        1.4236627712003017e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e-02,
+        6.6383520679938126e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        2.4392164832802044e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.6783469900166605e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.0000000000000002e-01,
+        5.3181160838961195e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9900000000000000e-01,
+        4.5402017769489579e-02,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0000000000000000e-300,
+        7.1388597806494431e+02,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0000000000000000e-100,
+        2.4916910020808962e+02,
+    ),
+    (
+        5.0000000000000000e+00,
+        9.9999999999999995e-21,
+        5.9270721295523288e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0000000000000001e-05,
+        2.0648078984380110e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0000000000000000e-02,
+        1.1604625579477180e+01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.9999999999999999e-01,
+        5.8903613136970066e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        4.6709088827959837e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        9.0000000000000002e-01,
+        2.4325910259626644e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        7.3937173191783268e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e-300,
+        7.3741431245569436e+02,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e-100,
+        2.6780299764163215e+02,
+    ),
+    (
+        1.0000000000000000e+01,
+        9.9999999999999995e-21,
+        7.1853116267699676e+01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000001e-05,
+        2.9522275193400823e+01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e-02,
+        1.8783117393312526e+01,
+    ),
+    (
+        1.0000000000000000e+01,
+        2.9999999999999999e-01,
+        1.1387272536823215e+01,
+    ),
+    (
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        9.6687146147141316e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        9.0000000000000002e-01,
+        6.2213046052250327e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        9.9900000000000000e-01,
+        2.9605203727437601e+00,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e-300,
+        1.1396373659465191e+03,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e-100,
+        5.7816538755665817e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        9.9999999999999995e-21,
+        2.9318450346561139e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000001e-05,
+        2.0806336722310783e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e-02,
+        1.7995321297516745e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.9999999999999999e-01,
+        1.5617301345523109e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        5.0000000000000000e-01,
+        1.4966679867460903e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        9.0000000000000002e-01,
+        1.3453393038998226e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        9.9900000000000000e-01,
+        1.1498171758902240e+02,
+    ),
+];
+
+const DIGAMMA_TABLE: [(f64, f64); 20] = [
+    (-1.0030000000000000e+02, 6.8956431248604346e+00),
+    (-2.5000000000000000e+00, 1.1031566406452431e+00),
+    (-1.2000000000000000e+00, 4.8683247666271958e+00),
+    (-5.0000000000000000e-01, 3.6489973978576520e-02),
+    (-1.0000000000000001e-05, 9.9999422767885626e+04),
+    (1.0000000000000000e-10, -1.0000000000577215e+10),
+    (1.0000000000000000e-03, -1.0005755719318103e+03),
+    (1.0000000000000001e-01, -1.0423754940411076e+01),
+    (5.0000000000000000e-01, -1.9635100260214235e+00),
+    (1.0000000000000000e+00, -5.7721566490153287e-01),
+    (1.2500000000000000e+00, -2.2745353337626542e-01),
+    (1.4616321449683622e+00, -9.2412655217294273e-17),
+    (1.5000000000000000e+00, 3.6489973978576520e-02),
+    (2.0000000000000000e+00, 4.2278433509846713e-01),
+    (3.2999999999999998e+00, 1.0348224890596216e+00),
+    (1.0000000000000000e+01, 2.2517525890667209e+00),
+    (2.5000000000000000e+01, 3.1987425128519740e+00),
+    (1.0000000000000000e+03, 6.9072551956488120e+00),
+    (1.0000000000000000e+08, 1.8420680738952367e+01),
+    (1.0000000000000000e+15, 3.4538776394910684e+01),
+];
+
+const POLYGAMMA_TABLE: [(u32, f64, f64); 80] = [
+    (1, -1.0030000000000000e+02, 1.5069493149244011e+01),
+    (1, -2.5000000000000000e+00, 9.5392466449891238e+00),
+    (1, -1.2000000000000000e+00, 2.7993918581946154e+01),
+    (1, -5.0000000000000000e-01, 8.9348022005446790e+00),
+    (1, -1.0000000000000001e-05, 1.0000000001644957e+10),
+    (1, 1.0000000000000000e-10, 1.0000000000000000e+20),
+    (1, 1.0000000000000000e-03, 1.0000016425331958e+06),
+    (1, 1.0000000000000001e-01, 1.0143329915079275e+02),
+    (1, 5.0000000000000000e-01, 4.9348022005446790e+00),
+    (1, 1.0000000000000000e+00, 1.6449340668482264e+00),
+    (1, 1.2500000000000000e+00, 1.1973291545071107e+00),
+    (1, 1.4616321449683622e+00, 9.6767224544762120e-01),
+    (1, 1.5000000000000000e+00, 9.3480220054467933e-01),
+    (1, 2.0000000000000000e+00, 6.4493406684822641e-01),
+    (1, 3.2999999999999998e+00, 3.5350154184106181e-01),
+    (1, 1.0000000000000000e+01, 1.0516633568168575e-01),
+    (1, 2.5000000000000000e+01, 4.0810663257225578e-02),
+    (1, 1.0000000000000000e+03, 1.0005001666666333e-03),
+    (1, 1.0000000000000000e+08, 1.0000000049999999e-08),
+    (1, 1.0000000000000000e+15, 1.0000000000000005e-15),
+    (2, -1.0030000000000000e+02, 6.8837445297961494e+01),
+    (2, -2.5000000000000000e+00, -1.0820405164172740e-01),
+    (2, -1.2000000000000000e+00, 2.4672729169898594e+02),
+    (2, -5.0000000000000000e-01, -8.2879664423431998e-01),
+    (2, -1.0000000000000001e-05, 1.9999999999999970e+15),
+    (2, 1.0000000000000000e-10, -1.9999999999999998e+30),
+    (2, 1.0000000000000000e-03, -2.0000000023976321e+09),
+    (2, 1.0000000000000001e-01, -2.0018614573783436e+03),
+    (2, 5.0000000000000000e-01, -1.6828796644234320e+01),
+    (2, 1.0000000000000000e+00, -2.4041138063191885e+00),
+    (2, 1.2500000000000000e+00, -1.3277399375369203e+00),
+    (2, 1.4616321449683622e+00, -8.8552633796718438e-01),
+    (2, 1.5000000000000000e+00, -8.2879664423431998e-01),
+    (2, 2.0000000000000000e+00, -4.0411380631918858e-01),
+    (2, 3.2999999999999998e+00, -1.2375118526494272e-01),
+    (2, 1.0000000000000000e+01, -1.1049834970802067e-02),
+    (2, 2.5000000000000000e+01, -1.6652793184224681e-03),
+    (2, 1.0000000000000000e+03, -1.0010004999998333e-06),
+    (2, 1.0000000000000000e+08, -1.0000000100000001e-16),
+    (2, 1.0000000000000000e+15, -1.0000000000000010e-30),
+    (3, -1.0030000000000000e+02, 7.6902091238084324e+02),
+    (3, -2.5000000000000000e+00, 1.9474787621918762e+02),
+    (3, -1.2000000000000000e+00, 3.7682644105890045e+03),
+    (3, -5.0000000000000000e-01, 1.9340909103400244e+02),
+    (3, -1.0000000000000001e-05, 5.9999999999999987e+20),
+    (3, 1.0000000000000000e-10, 5.9999999999999995e+40),
+    (3, 1.0000000000000000e-03, 6.0000000000064688e+12),
+    (3, 1.0000000000000001e-01, 6.0004512876790257e+04),
+    (3, 5.0000000000000000e-01, 9.7409091034002444e+01),
+    (3, 1.0000000000000000e+00, 6.4939394022668289e+00),
+    (3, 1.2500000000000000e+00, 2.7821440091883960e+00),
+    (3, 1.4616321449683622e+00, 1.5509985657339065e+00),
+    (3, 1.5000000000000000e+00, 1.4090910340024372e+00),
+    (3, 2.0000000000000000e+00, 4.9393940226682914e-01),
+    (3, 3.2999999999999998e+00, 8.5849667336884941e-02),
+    (3, 1.0000000000000000e+01, 2.3199013042898686e-03),
+    (3, 2.5000000000000000e+01, 1.3588463650827370e-04),
+    (3, 1.0000000000000000e+03, 2.0030019999990000e-09),
+    (3, 1.0000000000000000e+08, 2.0000000300000002e-24),
+    (3, 1.0000000000000000e+15, 2.0000000000000031e-45),
+    (10, -1.0030000000000000e+02, 2.0482848930187612e+12),
+    (10, -2.5000000000000000e+00, -4.0266930412329947e+00),
+    (10, -1.2000000000000000e+00, 1.7718745823790959e+14),
+    (10, -5.0000000000000000e-01, -4.2108858768975493e+04),
+    (10, -1.0000000000000001e-05, 3.6287999999999970e+61),
+    (10, 1.0000000000000000e-10, -3.6287999999999984e+116),
+    (10, 1.0000000000000000e-03, -3.6287999999999990e+39),
+    (10, 1.0000000000000001e-01, -3.6288000000127270e+17),
+    (10, 5.0000000000000000e-01, -7.4318245088587694e+09),
+    (10, 1.0000000000000000e+00, -3.6305933116066288e+06),
+    (10, 1.2500000000000000e+00, -3.1220554998136853e+05),
+    (10, 1.4616321449683622e+00, -5.5973390515878826e+04),
+    (10, 1.5000000000000000e+00, -4.2108858768975493e+04),
+    (10, 2.0000000000000000e+00, -1.7933116066287130e+03),
+    (10, 3.2999999999999998e+00, -7.6168055358183766e+00),
+    (10, 1.0000000000000000e+01, -5.7675966863222595e-05),
+    (10, 2.5000000000000000e+01, -4.6216645150488321e-09),
+    (10, 1.0000000000000000e+03, -3.6469772639135140e-25),
+    (10, 1.0000000000000000e+08, -3.6288001814400031e-75),
+    (10, 1.0000000000000000e+15, -3.6288000000000180e-145),
+];
+
+const LN_GAMMA_SIGN_TABLE: [(f64, f64, i8); 19] = [
+    (-1.0000000000500000e+10, -2.2025850932220462e+11, -1),
+    (-2.0025000000000000e+02, -8.6306604333236805e+02, -1),
+    (-1.0050000000000000e+02, -3.6490096830942736e+02, -1),
+    (-1.0100000000000000e+01, -1.3020973271011499e+01, -1),
+    (-2.5000000000000000e+00, -5.6243716497674054e-02, -1),
+    (-1.5000000000000000e+00, 8.6004701537648098e-01, 1),
+    (-5.0000000000000000e-01, 1.2655121234846454e+00, -1),
+    (-2.5000000000000000e-01, 1.5895753125511860e+00, -1),
+    (-1.0000000000000000e-10, 2.3025850929998178e+01, -1),
+    (9.9990000000000001e-01, 5.7729791561193866e-05, 1),
+    (1.0001000000000000e+00, -5.7713342220471268e-05, 1),
+    (1.9999000000000000e+00, -4.2275208772153459e-05, 1),
+    (2.0001000000000002e+00, 4.2281658112919945e-05, 1),
+    (1.0000000000000000e-300, 6.9077552789821368e+02, 1),
+    (3.0000000000000000e+00, 6.9314718055994529e-01, 1),
+    (7.5000000000000000e+00, 7.5343642367587327e+00, 1),
+    (1.7169999999999999e+02, 7.1017161294037498e+02, 1),
+    (1.0000000000000000e+15, 3.3538776394910668e+16, 1),
+    (1.0000000000000001e+300, 6.8977552789821374e+302, 1),
+];
+
+const COMPLEX_LN_GAMMA_TABLE: [(f64, f64, f64, f64); 14] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.1238724280962312e-01,
+        -7.5072920212205074e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -6.5092319930185638e-01,
+        -3.0164032046753320e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        -3.0000000000000000e+00,
+        -2.0928517530927335e+00,
+        -2.3023965434668678e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000000e+01,
+        -1.5709998695368144e+01,
+        1.2393696956719154e+01,
+    ),
+    (
+        1.2000000000000000e+00,
+        1.0000000000000001e-01,
+        -9.1697512412654753e-02,
+        -2.8658497320683367e-02,
+    ),
+    (
+        2.2999999999999998e+00,
+        -2.0000000000000001e-01,
+        1.4335864056483308e-01,
+        -1.2039092506034982e-01,
+    ),
+    (
+        -5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        4.5896083308959579e-01,
+        -3.1069236923143957e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        -1.0000000000000000e+00,
+        -2.3441906524655924e+00,
+        8.3041279866579263e+00,
+    ),
+    (
+        -1.0300000000000001e+01,
+        6.9999999999999996e-01,
+        -1.6156626550287555e+01,
+        -3.2274432544084384e+01,
+    ),
+    (
+        -1.0020000000000000e+02,
+        4.0000000000000000e+00,
+        -3.7531067798293242e+02,
+        -2.9790872921687429e+02,
+    ),
+    (
+        5.0000000000000000e+00,
+        -2.5000000000000000e+01,
+        -2.3842251704654426e+01,
+        -6.2139252720049306e+01,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+01,
+        4.9232808494070298e+01,
+        1.4383479582266483e+02,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e+00,
+        6.0000612611113081e+02,
+        5.0073057139303216e+00,
+    ),
+    (
+        -3.0000000000000000e+00,
+        5.0000000000000000e+01,
+        -9.1315754413986241e+01,
+        1.3998179226251608e+02,
+    ),
+];
+
+const COMPLEX_GAMMA_TABLE: [(f64, f64, f64, f64); 14] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        8.1816399954174734e-01,
+        -7.6331382871398257e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        4.9801566811835607e-01,
+        -1.5494982830181067e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        -3.0000000000000000e+00,
+        -8.2395272665611891e-02,
+        -9.1774287435259311e-02,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000000e+01,
+        1.4815875493685428e-07,
+        -2.5840447322347109e-08,
+    ),
+    (
+        1.2000000000000000e+00,
+        1.0000000000000001e-01,
+        9.1200644379165086e-01,
+        -2.6143892026465974e-02,
+    ),
+    (
+        2.2999999999999998e+00,
+        -2.0000000000000001e-01,
+        1.1457896831676617e+00,
+        -1.3861301237284548e-01,
+    ),
+    (
+        -5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        -1.5814778282557300e+00,
+        -5.4850170827764777e-02,
+    ),
+    (
+        -2.5000000000000000e+00,
+        -1.0000000000000000e+00,
+        -4.1736625807893613e-02,
+        8.6369107369763484e-02,
+    ),
+    (
+        -1.0300000000000001e+01,
+        6.9999999999999996e-01,
+        6.2886533330256385e-08,
+        -7.2825890258471341e-08,
+    ),
+    (
+        -1.0020000000000000e+02,
+        4.0000000000000000e+00,
+        -8.6558876102528610e-164,
+        -5.2189149327964086e-164,
+    ),
+    (
+        5.0000000000000000e+00,
+        -2.5000000000000000e+01,
+        3.4017315837069521e-11,
+        2.8224751237408894e-11,
+    ),
+    (
+        3.0000000000000000e+01,
+        4.0000000000000000e+01,
+        1.8741997673037803e+21,
+        -1.5108445033328678e+21,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e+00,
+        1.1034056813346646e+260,
+        -3.6323091445725759e+260,
+    ),
+    (
+        -3.0000000000000000e+00,
+        5.0000000000000000e+01,
+        -3.9553467205070509e-41,
+        2.1623451787263668e-40,
+    ),
+];
+
+const UPPER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); 104] = [
+    (
+        -3.0500000000000000e+01,
+        9.9999999999999995e-07,
+        3.2786851347614143e+181,
+    ),
+    (
+        -3.0500000000000000e+01,
+        1.0000000000000000e-02,
+        3.2449650536103661e+59,
+    ),
+    (
+        -3.0500000000000000e+01,
+        5.0000000000000000e-01,
+        2.9694278094295900e+07,
+    ),
+    (
+        -3.0500000000000000e+01,
+        1.0000000000000000e+00,
+        1.1666596038313125e-02,
+    ),
+    (
+        -3.0500000000000000e+01,
+        1.8999999999999999e+00,
+        1.4504525046706325e-11,
+    ),
+    (
+        -3.0500000000000000e+01,
+        3.0000000000000000e+00,
+        4.1560937541730027e-18,
+    ),
+    (
+        -3.0500000000000000e+01,
+        2.0000000000000000e+01,
+        8.4333159385505489e-51,
+    ),
+    (
+        -3.0500000000000000e+01,
+        1.6000000000000000e+02,
+        1.0125689988301370e-139,
+    ),
+    (
+        -5.0000000000000000e+00,
+        9.9999999999999995e-07,
+        1.9999975000016671e+29,
+    ),
+    (
+        -5.0000000000000000e+00,
+        1.0000000000000000e-02,
+        1.9751658374473917e+09,
+    ),
+    (
+        -5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        3.4626688247340862e+00,
+    ),
+    (
+        -5.0000000000000000e+00,
+        1.0000000000000000e+00,
+        5.9485040741944384e-02,
+    ),
+    (
+        -5.0000000000000000e+00,
+        1.8999999999999999e+00,
+        8.4005662077727924e-04,
+    ),
+    (
+        -5.0000000000000000e+00,
+        3.0000000000000000e+00,
+        2.4438776639352108e-05,
+    ),
+    (
+        -5.0000000000000000e+00,
+        2.0000000000000000e+01,
+        2.4982968909688721e-17,
+    ),
+    (
+        -5.0000000000000000e+00,
+        1.6000000000000000e+02,
+        1.8718385488282991e-83,
+    ),
+    (
+        -2.5000000000000000e+00,
+        9.9999999999999995e-07,
+        3.9999933333433244e+14,
+    ),
+    (
+        -2.5000000000000000e+00,
+        1.0000000000000000e-02,
+        3.9342421330201694e+04,
+    ),
+    (
+        -2.5000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0724658257534472e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        1.0000000000000000e+00,
+        9.6556648631275166e-02,
+    ),
+    (
+        -2.5000000000000000e+00,
+        1.8999999999999999e+00,
+        6.2155162448184640e-03,
+    ),
+    (
+        -2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        5.2943283050101001e-04,
+    ),
+    (
+        -2.5000000000000000e+00,
+        2.0000000000000000e+01,
+        4.9322535536729906e-14,
+    ),
+    (
+        -2.5000000000000000e+00,
+        1.6000000000000000e+02,
+        6.1534882817400808e-78,
+    ),
+    (
+        -1.0000000000000000e+00,
+        9.9999999999999995e-07,
+        9.9998576170460694e+05,
+    ),
+    (
+        -1.0000000000000000e+00,
+        1.0000000000000000e-02,
+        9.4967053798378686e+01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        6.5328772464910601e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.4849550677592205e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        1.8999999999999999e+00,
+        2.2515947732115182e-02,
+    ),
+    (
+        -1.0000000000000000e+00,
+        3.0000000000000000e+00,
+        3.5473083617576103e-03,
+    ),
+    (
+        -1.0000000000000000e+00,
+        2.0000000000000000e+01,
+        4.7024282154290744e-12,
+    ),
+    (
+        -1.0000000000000000e+00,
+        1.6000000000000000e+02,
+        1.2568417529732802e-74,
+    ),
+    (
+        -2.9999999999999999e-01,
+        9.9999999999999995e-07,
+        2.0599235385465082e+02,
+    ),
+    (
+        -2.9999999999999999e-01,
+        1.0000000000000000e-02,
+        9.0001435175142017e+00,
+    ),
+    (
+        -2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        5.7452399843952340e-01,
+    ),
+    (
+        -2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        1.9295920811540390e-01,
+    ),
+    (
+        -2.9999999999999999e-01,
+        1.8999999999999999e+00,
+        4.2465850614286343e-02,
+    ),
+    (
+        -2.9999999999999999e-01,
+        3.0000000000000000e+00,
+        8.7976822708421953e-03,
+    ),
+    (
+        -2.9999999999999999e-01,
+        2.0000000000000000e+01,
+        3.9497532408927964e-11,
+    ),
+    (
+        -2.9999999999999999e-01,
+        1.6000000000000000e+02,
+        4.4058932622752457e-73,
+    ),
+    (
+        -1.0000000000000000e-08,
+        9.9999999999999995e-07,
+        1.3238296837513783e+01,
+    ),
+    (
+        -1.0000000000000000e-08,
+        1.0000000000000000e-02,
+        4.0379296732447614e+00,
+    ),
+    (
+        -1.0000000000000000e-08,
+        5.0000000000000000e-01,
+        5.5977359507346380e-01,
+    ),
+    (
+        -1.0000000000000000e-08,
+        1.0000000000000000e+00,
+        2.1938393341708831e-01,
+    ),
+    (
+        -1.0000000000000000e-08,
+        1.8999999999999999e+00,
+        5.6204377644211843e-02,
+    ),
+    (
+        -1.0000000000000000e-08,
+        3.0000000000000000e+00,
+        1.3048380922056972e-02,
+    ),
+    (
+        -1.0000000000000000e-08,
+        2.0000000000000000e+01,
+        9.8355249915076980e-11,
+    ),
+    (
+        -1.0000000000000000e-08,
+        1.6000000000000000e+02,
+        2.0233618122859237e-72,
+    ),
+    (
+        0.0000000000000000e+00,
+        9.9999999999999995e-07,
+        1.3238295893062491e+01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e-02,
+        4.0379295765381142e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        5.5977359477616084e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        2.1938393439552029e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.8999999999999999e+00,
+        5.6204378174534858e-02,
+    ),
+    (
+        0.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.3048381094197037e-02,
+    ),
+    (
+        0.0000000000000000e+00,
+        2.0000000000000000e+01,
+        9.8355252906498815e-11,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.6000000000000000e+02,
+        2.0233619150999679e-72,
+    ),
+    (
+        1.0000000000000000e-08,
+        9.9999999999999995e-07,
+        1.3238294948611287e+01,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.0000000000000000e-02,
+        4.0379294798314698e+00,
+    ),
+    (
+        1.0000000000000000e-08,
+        5.0000000000000000e-01,
+        5.5977359447885788e-01,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.0000000000000000e+00,
+        2.1938393537395226e-01,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.8999999999999999e+00,
+        5.6204378704857873e-02,
+    ),
+    (
+        1.0000000000000000e-08,
+        3.0000000000000000e+00,
+        1.3048381266337105e-02,
+    ),
+    (
+        1.0000000000000000e-08,
+        2.0000000000000000e+01,
+        9.8355255897920741e-11,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.6000000000000000e+02,
+        2.0233620179140177e-72,
+    ),
+    (
+        2.9999999999999999e-01,
+        9.9999999999999995e-07,
+        2.9387392267970363e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e-02,
+        2.1562002828889786e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        5.5699483100960656e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        2.5226657904968819e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.8999999999999999e+00,
+        7.4814381214619932e-02,
+    ),
+    (
+        2.9999999999999999e-01,
+        3.0000000000000000e+00,
+        1.9416397685157079e-02,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.0000000000000000e+01,
+        2.4496298984577986e-10,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.6000000000000000e+02,
+        9.2921164210990954e-72,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9999999999999995e-07,
+        9.9999900000050002e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-02,
+        9.9004983374916811e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        6.0653065971263342e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        3.6787944117144233e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.8999999999999999e+00,
+        1.4956861922263506e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.9787068367863944e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.0000000000000000e+01,
+        2.0611536224385579e-09,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.6000000000000000e+02,
+        3.2574885322075211e-70,
+    ),
+    (
+        2.5000000000000000e+00,
+        9.9999999999999995e-07,
+        1.3293403881791366e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.0000000000000000e-02,
+        1.3293364166397568e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        5.0000000000000000e-01,
+        1.2795775586565121e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.0000000000000000e+00,
+        1.1288027918891024e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.8999999999999999e+00,
+        7.6909691570098992e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0706917587130298e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        2.0000000000000000e+01,
+        1.9851942639472550e-07,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.6000000000000000e+02,
+        6.6546923033240080e-67,
+    ),
+    (
+        1.0000000000000000e+01,
+        9.9999999999999995e-07,
+        3.6288000000000000e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e-02,
+        3.6288000000000000e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        3.6287999993795948e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+00,
+        3.6287995956592244e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.8999999999999999e+00,
+        3.6286895278131147e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+00,
+        3.6247992910734372e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        2.0000000000000000e+01,
+        1.8127352184386573e+03,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.6000000000000000e+02,
+        2.3710283016716025e-50,
+    ),
+    (
+        1.5000000000000000e+02,
+        9.9999999999999995e-07,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e-02,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        5.0000000000000000e-01,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e+00,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.8999999999999999e+00,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        3.0000000000000000e+00,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.0000000000000000e+01,
+        3.8089226376305698e+260,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.6000000000000000e+02,
+        7.7844403817313807e+259,
+    ),
+];
+
+const LOWER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); 46] = [
+    (
+        1.0000000000000000e-08,
+        9.9999999999999995e-07,
+        9.9999986184489399e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.0000000000000000e-02,
+        9.9999995384854868e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        5.0000000000000000e-01,
+        9.9999998863010749e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.0000000000000000e+00,
+        9.9999999203400403e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.8999999999999999e+00,
+        9.9999999366579965e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        3.0000000000000000e+00,
+        9.9999999409735963e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        2.0000000000000000e+01,
+        9.9999999422784343e+07,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.6000000000000000e+02,
+        9.9999999422784343e+07,
+    ),
+    (
+        2.9999999999999999e-01,
+        9.9999999999999995e-07,
+        5.2829760890554474e-02,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e-02,
+        8.3536870479861225e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        2.4345741566779844e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e+00,
+        2.7393024086379025e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.8999999999999999e+00,
+        2.9167546064729710e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        3.0000000000000000e+00,
+        2.9721525900024335e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        2.0000000000000000e+01,
+        2.9915689874426277e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.6000000000000000e+02,
+        2.9915689876875908e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        9.9999999999999995e-07,
+        9.9999950000016670e-07,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-02,
+        9.9501662508319471e-03,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e-01,
+        3.9346934028736658e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        6.3212055882855767e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.8999999999999999e+00,
+        8.5043138077736491e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        3.0000000000000000e+00,
+        9.5021293163213605e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        2.0000000000000000e+01,
+        9.9999999793884642e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.6000000000000000e+02,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        9.9999999999999995e-07,
+        3.9999971428582533e-16,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.0000000000000000e-02,
+        3.9715393801492956e-06,
+    ),
+    (
+        2.5000000000000000e+00,
+        5.0000000000000000e-01,
+        4.9762829522624882e-02,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.0000000000000000e+00,
+        2.0053759629003473e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.8999999999999999e+00,
+        5.6024347247814710e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        9.2227121230783404e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        2.0000000000000000e+01,
+        1.3293401896597106e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.6000000000000000e+02,
+        1.3293403881791370e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        9.9999999999999995e-07,
+        9.9999909090950713e-62,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e-02,
+        9.9095062966769397e-22,
+    ),
+    (
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        6.2040506025013011e-05,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+00,
+        4.0434077579554958e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.8999999999999999e+00,
+        1.1047218688529890e+01,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+00,
+        4.0007089265630526e+02,
+    ),
+    (
+        1.0000000000000000e+01,
+        2.0000000000000000e+01,
+        3.6106726478156133e+05,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.6000000000000000e+02,
+        3.6288000000000000e+05,
+    ),
+    (
+        1.5000000000000000e+02,
+        5.0000000000000000e-01,
+        2.8425136980444512e-48,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.0000000000000000e+00,
+        2.4688790834267453e-03,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.8999999999999999e+00,
+        6.5658084461490603e+38,
+    ),
+    (
+        1.5000000000000000e+02,
+        3.0000000000000000e+00,
+        1.2529321743496341e+68,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.0000000000000000e+01,
+        2.2602568577717463e+184,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.6000000000000000e+02,
+        3.0304785994574317e+260,
+    ),
+];
+
+const LN_UPPER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); 9] = [
+    (
+        -3.0000000000000000e+02,
+        1.0000000000000000e-03,
+        2.0666217978755089e+03,
+    ),
+    (
+        -5.0500000000000000e+01,
+        1.0000000000000000e-10,
+        1.1588834986256097e+03,
+    ),
+    (
+        5.0000000000000000e-01,
+        8.0000000000000000e+02,
+        -8.0334292989026915e+02,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+04,
+        -9.9171060363371162e+03,
+    ),
+    (
+        1.0000000000000000e-300,
+        1.0000000000000001e-01,
+        6.0044178249488622e-01,
+    ),
+    (
+        2.0000000000000000e+02,
+        1.0000000000000000e-03,
+        8.5793366982585746e+02,
+    ),
+    (
+        1.0000000000000000e+04,
+        9.0000000000000000e+03,
+        8.2099717496442376e+04,
+    ),
+    (
+        1.0000000000000000e+04,
+        1.1000000000000000e+04,
+        8.2049587039756167e+04,
+    ),
+    (
+        3.0000000000000000e+02,
+        1.0000000000000000e+03,
+        1.0657734699852874e+03,
+    ),
+];
+
+const LN_LOWER_INCOMPLETE_GAMMA_TABLE: [(f64, f64, f64); 7] = [
+    (
+        5.0000000000000000e-01,
+        8.0000000000000000e+02,
+        5.7236494292470008e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+04,
+        1.2801827480081469e+01,
+    ),
+    (
+        1.0000000000000000e-300,
+        1.0000000000000001e-01,
+        6.9077552789821368e+02,
+    ),
+    (
+        2.0000000000000000e+02,
+        1.0000000000000000e-03,
+        -1.3868503681878387e+03,
+    ),
+    (
+        1.0000000000000000e+04,
+        9.0000000000000000e+03,
+        8.2042882010273621e+04,
+    ),
+    (
+        1.0000000000000000e+04,
+        1.1000000000000000e+04,
+        8.2099717496442376e+04,
+    ),
+    (
+        3.0000000000000000e+02,
+        1.0000000000000000e+03,
+        1.4092020674704117e+03,
+    ),
+];
+
+const GAMMP_GRAD_TABLE: [(f64, f64, f64, f64); 15] = [
+    (
+        1.0000000000000000e-03,
+        1.0000000000000000e-04,
+        9.9140311966744332e-01,
+        -8.5606353090487151e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        5.0000000000000000e-01,
+        9.9437324380603287e-01,
+        -5.6554591171525781e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        3.4527915398142300e-01,
+        -8.2293609697959880e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        2.0000000000000000e+00,
+        9.5449973610364158e-01,
+        -1.3561933520399724e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        6.3212055882855767e-01,
+        -4.3172971063489868e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        1.0000000000000000e+00,
+        1.5085496391539036e-01,
+        -1.8009960695404151e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        5.0000000000000000e+00,
+        9.2476475385348778e-01,
+        -8.3942784207594143e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        9.0000000000000000e+00,
+        4.1259175566805861e-01,
+        -1.2703651192426837e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.2000000000000000e+01,
+        7.5760783832948764e-01,
+        -9.7177972037179650e-02,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+01,
+        9.9999999999999645e-01,
+        -9.9743303000892821e-15,
+    ),
+    (
+        5.0000000000000000e+01,
+        4.5000000000000000e+01,
+        2.4680203440017026e-01,
+        -4.5543292140123877e-02,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.7000000000000000e+02,
+        9.4436556868980670e-01,
+        -8.9826187114896688e-03,
+    ),
+    (
+        1.0000000000000000e-10,
+        1.0000000000000001e-05,
+        9.9999999890642799e-01,
+        -1.0935719788249191e+01,
+    ),
+    (
+        9.9999999999999995e-07,
+        2.9999999999999999e-01,
+        9.9999909432318634e-01,
+        -9.0567697569471073e-01,
+    ),
+    (
+        1.0000000000000000e-08,
+        3.0000000000000000e+00,
+        9.9999999986951615e-01,
+        -1.3048381589111773e-02,
+    ),
+];
@@ -27,7 +27,7 @@ fn invgammp_test() {
     // Invert the second test.
     let expected_2 = 3_f64;
     let a = 0.8;
-    let p = 0.96739453751512363;
+    let p = 0.967_394_537_515_123_6;
     let res_2 = invgammp(p, a);
     assert!((res_2 - expected_2).abs() < 1e-10);
 }
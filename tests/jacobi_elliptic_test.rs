@@ -0,0 +1,229 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use core::f64::consts::PI;
+use puruspe::{ellipj, ellipk};
+
+#[test]
+fn test_ellipj() {
+    // The functions oscillate, so values near their zeros are compared with an absolute epsilon.
+    for (u, m, sn, cn, dn, am) in ELLIPJ_TABLE {
+        let actual = ellipj(u, m);
+        assert_relative_eq!(actual.0, sn, epsilon = 1e-14, max_relative = 1e-13);
+        assert_relative_eq!(actual.1, cn, epsilon = 1e-14, max_relative = 1e-13);
+        assert_relative_eq!(actual.2, dn, epsilon = 1e-14, max_relative = 1e-13);
+        assert_relative_eq!(actual.3, am, max_relative = 1e-13);
+    }
+    for (u, m, sn, cn, dn) in ELLIPJ_TRANSFORMED_TABLE {
+        let actual = ellipj(u, m);
+        assert_relative_eq!(actual.0, sn, epsilon = 1e-14, max_relative = 1e-13);
+        assert_relative_eq!(actual.1, cn, epsilon = 1e-14, max_relative = 1e-13);
+        assert_relative_eq!(actual.2, dn, epsilon = 1e-14, max_relative = 1e-13);
+        assert_relative_eq!(actual.3.sin(), actual.0, epsilon = 1e-14);
+        assert_relative_eq!(actual.3.cos(), actual.1, epsilon = 1e-14);
+    }
+}
+
+#[test]
+fn test_ellipj_identities() {
+    // sn² + cn² = 1, dn² + m sn² = 1 and am(u + 2K) = am(u) + π for m < 1
+    for &m in [-7.0, -0.3, 0.2, 0.6, 0.95, 1.0, 2.5].iter() {
+        for &u in [-3.0, 0.1, 1.0, 2.2, 9.0].iter() {
+            let (sn, cn, dn, am) = ellipj(u, m);
+            assert_relative_eq!(sn * sn + cn * cn, 1.0, max_relative = 1e-14);
+            assert_relative_eq!(dn * dn + m * sn * sn, 1.0, max_relative = 1e-13);
+            if m < 1.0 {
+                let am_shifted = ellipj(u + 2.0 * ellipk(m), m).3;
+                assert_relative_eq!(am_shifted, am + PI, max_relative = 1e-13);
+            }
+        }
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_ellipj_edge_cases() {
+    assert_eq!(ellipj(0.0, 0.5), (0.0, 1.0, 1.0, 0.0));
+    assert_eq!(ellipj(0.7, 0.0), (0.7f64.sin(), 0.7f64.cos(), 1.0, 0.7));
+    // am(u|1) is the Gudermannian function, which tends to π/2.
+    assert_eq!(ellipj(1e3, 1.0), (1.0, 0.0, 0.0, PI / 2.0));
+    // For tiny |m| the AGM takes no steps, and the functions reduce to sin, cos and 1.
+    for &m in [1e-40, -1e-40, 1e-33, -1e-33].iter() {
+        let (sn, cn, dn, am) = ellipj(1.0, m);
+        assert_relative_eq!(sn, 1f64.sin(), max_relative = 1e-15);
+        assert_relative_eq!(cn, 1f64.cos(), max_relative = 1e-15);
+        assert_relative_eq!(dn, 1.0, max_relative = 1e-15);
+        assert_relative_eq!(am, 1.0, max_relative = 1e-15);
+    }
+    assert!(ellipj(f64::NAN, 0.5).0.is_nan());
+    assert!(ellipj(1.0, f64::NAN).3.is_nan());
+    assert!(ellipj(f64::INFINITY, 0.5).1.is_nan());
+}
+
+const ELLIPJ_TABLE: [(f64, f64, f64, f64, f64, f64); 13] = [
+    (
+        2.0000000000000001e-01,
+        0.0000000000000000e+00,
+        1.9866933079506122e-01,
+        9.8006657784124163e-01,
+        1.0000000000000000e+00,
+        2.0000000000000001e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e-10,
+        8.4147098480053018e-01,
+        5.4030230587961214e-01,
+        9.9999999996459632e-01,
+        9.9999999998636624e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        2.9999999999999999e-01,
+        4.7421562271182061e-01,
+        8.8040873642646245e-01,
+        9.6567896474595116e-01,
+        4.9407289371104723e-01,
+    ),
+    (
+        -1.3000000000000000e+00,
+        5.0000000000000000e-01,
+        -9.2044647421001780e-01,
+        3.9086863280947348e-01,
+        7.5920296631215389e-01,
+        -1.1692212148542587e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.9466232535801768e-01,
+        -1.0318361552776183e-01,
+        7.1086104778408732e-01,
+        1.6741639220482392e+00,
+    ),
+    (
+        7.0000000000000000e+00,
+        6.9999999999999996e-01,
+        -8.9915043310408682e-01,
+        4.3763969044035883e-01,
+        6.5883985083942309e-01,
+        5.1653609275097070e+00,
+    ),
+    (
+        3.0000000000000000e+01,
+        1.0000000000000001e-01,
+        -8.2106512204046600e-01,
+        -5.7083453414161500e-01,
+        9.6570451305607319e-01,
+        2.9237608308252849e+01,
+    ),
+    (
+        1.5000000000000000e+00,
+        9.0000000000000002e-01,
+        9.2037272516982827e-01,
+        3.9104225700487133e-01,
+        4.8746552912705265e-01,
+        1.1690325768881384e+00,
+    ),
+    (
+        4.0000000000000000e+00,
+        9.8999999999999999e-01,
+        9.9952252382899875e-01,
+        -3.0898614184272505e-02,
+        1.0461920050795384e-01,
+        1.6016998597026080e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        9.9999899999999997e-01,
+        9.9505499504790063e-01,
+        9.9325509463694936e-02,
+        9.9330493629427571e-02,
+        1.4713067710894492e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        9.9999999999909051e-01,
+        9.6402758007600398e-01,
+        2.6580222883340121e-01,
+        2.6580222883499122e-01,
+        1.3017603360467189e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        7.6159415595576485e-01,
+        6.4805427366388535e-01,
+        6.4805427366388535e-01,
+        8.6576948323965863e-01,
+    ),
+    (
+        -5.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -9.9990920426259511e-01,
+        1.3475282221304558e-02,
+        1.3475282221304558e-02,
+        -1.5573206367260510e+00,
+    ),
+];
+const ELLIPJ_TRANSFORMED_TABLE: [(f64, f64, f64, f64, f64); 8] = [
+    (
+        6.9999999999999996e-01,
+        -5.0000000000000000e-01,
+        6.6395932651367950e-01,
+        7.4776868932544993e-01,
+        1.1047266601436978e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        -9.9799228149981489e-01,
+        -6.3335661888025377e-02,
+        2.2324771836981498e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        -3.0000000000000000e+01,
+        3.1246221580360556e-01,
+        9.4993018885342362e-01,
+        1.9821652527342339e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        -1.0000000000000000e-03,
+        -5.4602066984571074e-01,
+        -8.3777170404665813e-01,
+        1.0001490581767793e+00,
+    ),
+    (
+        4.0000000000000002e-01,
+        1.5000000000000000e+00,
+        3.7524892760210216e-01,
+        9.2692407581930480e-01,
+        8.8813420354145156e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        4.0000000000000000e+00,
+        -2.9010005990683707e-01,
+        9.5699631934613494e-01,
+        -8.1447395352349861e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        9.0000000000000000e+00,
+        -7.7464076960844772e-02,
+        9.9699514380994070e-01,
+        -9.7262215223869897e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0001000000000000e+00,
+        9.9988250991346928e-01,
+        1.5328612759833358e-02,
+        1.1618513922952933e-02,
+    ),
+];
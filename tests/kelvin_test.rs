@@ -0,0 +1,421 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use core::f64::consts::FRAC_PI_4;
+use puruspe::{berbei, kerkei};
+
+#[test]
+fn test_kelvin() {
+    for (nu, x, expected) in KELVIN_TABLE {
+        let (ber, bei, berp, beip) = berbei(nu, x);
+        let (ker, kei, kerp, keip) = kerkei(nu, x);
+        let actual = [ber, bei, berp, beip, ker, kei, kerp, keip];
+        // The functions oscillate, so each part is compared relative to the modulus of its pair.
+        let rel_eps = 1e-12;
+        for (i, (&a, &e)) in actual.iter().zip(expected.iter()).enumerate() {
+            let scale = expected[i - i % 2].hypot(expected[i - i % 2 + 1]);
+            assert_relative_eq!(a, e, epsilon = rel_eps * scale, max_relative = rel_eps);
+        }
+    }
+}
+
+#[test]
+fn test_kelvin_wronskian() {
+    // (ber + i bei)(ker' + i kei') - (ber' + i bei')(ker + i kei) = -1/x
+    for &nu in [0.0, 0.6, 1.0, 3.5, 12.0].iter() {
+        for &x in [0.2, 2.0, 9.0, 28.0, 33.0, 60.0].iter() {
+            let (ber, bei, berp, beip) = berbei(nu, x);
+            let (ker, kei, kerp, keip) = kerkei(nu, x);
+            let re = ber * kerp - bei * keip - berp * ker + beip * kei;
+            let im = ber * keip + bei * kerp - berp * kei - beip * ker;
+            let scale = ber.hypot(bei) * kerp.hypot(keip) + berp.hypot(beip) * ker.hypot(kei);
+            assert_relative_eq!(re, -1.0 / x, epsilon = 1e-13 * scale);
+            assert_relative_eq!(im, 0.0, epsilon = 1e-13 * scale);
+        }
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_kelvin_edge_cases() {
+    // Integer orders have the parity (-1)^n.
+    for &(n, x) in [(1.0, 3.0), (2.0, 0.4), (3.0, 40.0)].iter() {
+        let sign = if n % 2.0 == 0.0 { 1.0 } else { -1.0 };
+        let (b, bn) = (berbei(n, x), berbei(-n, x));
+        let (k, kn) = (kerkei(n, x), kerkei(-n, x));
+        assert_eq!(
+            [bn.0, bn.1, bn.2, bn.3],
+            [b.0, b.1, b.2, b.3].map(|v| sign * v)
+        );
+        assert_eq!(
+            [kn.0, kn.1, kn.2, kn.3],
+            [k.0, k.1, k.2, k.3].map(|v| sign * v)
+        );
+    }
+    assert!(berbei(f64::NAN, 1.0).0.is_nan());
+    assert!(kerkei(1.0, f64::NAN).3.is_nan());
+
+    // Values at x = 0
+    assert_eq!(berbei(0.0, 0.0), (1.0, 0.0, 0.0, 0.0));
+    assert_eq!(berbei(2.5, 0.0), (0.0, 0.0, 0.0, 0.0));
+    assert_eq!(
+        kerkei(0.0, 0.0),
+        (f64::INFINITY, -FRAC_PI_4, f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq!(kerkei(2.0, 0.0).0, 0.5);
+    assert_eq!(kerkei(0.3, 0.0).1, f64::NEG_INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "Bad args in berbei")]
+fn test_berbei_negative_x() {
+    berbei(0.0, -1.0);
+}
+
+#[test]
+#[should_panic(expected = "Bad args in kerkei")]
+fn test_kerkei_negative_x() {
+    kerkei(0.0, -1.0);
+}
+
+const KELVIN_TABLE: [(f64, f64, [f64; 8]); 24] = [
+    (
+        0.00,
+        1.0000000000000000e-03,
+        [
+            9.9999999999998435e-01,
+            2.4999999999999957e-07,
+            -6.2499999999999952e-11,
+            4.9999999999999741e-04,
+            7.0236869909899573e+00,
+            -7.8539615747573743e-01,
+            -9.9999960730143539e+02,
+            3.7618434464076374e-03,
+        ],
+    ),
+    (
+        0.00,
+        5.0000000000000000e-01,
+        [
+            9.9902346399083830e-01,
+            6.2493218382199456e-02,
+            -7.8120761475077337e-03,
+            2.4991862111621022e-01,
+            8.5590587211863423e-01,
+            -6.7158169509436760e-01,
+            -1.8197997533173527e+00,
+            3.3320379160332986e-01,
+        ],
+    ),
+    (
+        0.00,
+        4.0000000000000000e+00,
+        [
+            -2.5634165572585799e+00,
+            2.2926903226992996e+00,
+            -3.1346539628386436e+00,
+            -4.9113744063072262e-01,
+            -3.6178847899547610e-02,
+            2.1983992949725197e-03,
+            3.1478489822108151e-02,
+            2.3910613791704644e-02,
+        ],
+    ),
+    (
+        0.00,
+        1.2000000000000000e+01,
+        [
+            -1.2851162615653871e+02,
+            5.4694855245424662e+02,
+            -4.7256881636119590e+02,
+            2.7267002155955771e+02,
+            -6.3077137052054553e-05,
+            -3.8999594971788217e-05,
+            1.9593852092745619e-05,
+            7.3814962959769997e-05,
+        ],
+    ),
+    (
+        0.00,
+        3.5000000000000000e+01,
+        [
+            2.6936308311167269e+09,
+            -2.6608730553930860e+09,
+            3.7477314751316042e+09,
+            6.1569898415699162e+07,
+            3.7729507247581208e-12,
+            -2.3467159619969635e-14,
+            -2.7381018403131925e-12,
+            -2.6512132795643033e-12,
+        ],
+    ),
+    (
+        0.00,
+        1.2000000000000000e+02,
+        [
+            -2.4175738721410021e+35,
+            9.2000884237739585e+34,
+            -2.3499450223805138e+35,
+            -1.0627924202028654e+35,
+            -1.4696296427449719e-38,
+            6.5943447656500014e-39,
+            1.5115941138393906e-38,
+            5.7015981504315743e-39,
+        ],
+    ),
+    (
+        1.00,
+        2.0000000000000000e+00,
+        [
+            -9.9707765192642850e-01,
+            2.9977543700203352e-01,
+            -7.2053151524289016e-01,
+            -3.0584538321379934e-01,
+            -2.3080592951812295e-01,
+            8.0049397807066741e-02,
+            2.8798268556633883e-01,
+            7.3632501147267604e-02,
+        ],
+    ),
+    (
+        1.00,
+        2.9000000000000000e+01,
+        [
+            -5.6481536292715810e+07,
+            1.7322341524952184e+07,
+            -5.1225444616828576e+07,
+            -2.7964236238090210e+07,
+            -2.5786085018632097e-10,
+            1.3666966147946062e-10,
+            2.8346120574414767e-10,
+            8.3218243823385416e-11,
+        ],
+    ),
+    (
+        1.00,
+        3.1000000000000000e+01,
+        [
+            -1.0330383604110874e+08,
+            -2.1126825035611999e+08,
+            7.7919065206780583e+07,
+            -2.1905686797922426e+08,
+            2.2238636164707570e-11,
+            6.4878367801617003e-11,
+            2.9768917909950986e-11,
+            -6.2659463915045702e-11,
+        ],
+    ),
+    (
+        2.00,
+        1.0000000000000001e-01,
+        [
+            1.0416666124131951e-06,
+            -1.2499996744791725e-03,
+            4.1666662326388942e-05,
+            -2.4999980468750568e-02,
+            4.9902224753375224e-01,
+            1.9999603853748661e+02,
+            -1.9485556127545382e-02,
+            -4.0000667455611606e+03,
+        ],
+    ),
+    (
+        2.00,
+        8.0000000000000000e+00,
+        [
+            -2.2889035214142712e+01,
+            2.5438893739657008e+01,
+            -3.2589066897362343e+01,
+            1.3005949787355746e+00,
+            -1.8199122972336851e-03,
+            -1.4965293134059149e-04,
+            1.3347021734498937e-03,
+            1.3737261476940378e-03,
+        ],
+    ),
+    (
+        0.30,
+        6.0000000000000000e+00,
+        [
+            -4.4744805200076581e+00,
+            -1.0530345760800030e+01,
+            4.6795124154320709e+00,
+            -9.7224493232148728e+00,
+            2.7406407892373772e-03,
+            6.7488551962675671e-03,
+            2.6185611103171583e-03,
+            -7.2656399410941626e-03,
+        ],
+    ),
+    (
+        2.50,
+        4.5000000000000000e+01,
+        [
+            -2.4845828031122417e+12,
+            -2.7968727211387271e+12,
+            2.4280391134545197e+11,
+            -3.7037221361286831e+12,
+            1.7091753713312751e-16,
+            2.9651056688907079e-15,
+            1.9707027202862588e-15,
+            -2.2533814775889650e-15,
+        ],
+    ),
+    (
+        7.25,
+        1.0000000000000000e+01,
+        [
+            9.4533075630149881e+00,
+            1.9035839573297121e+01,
+            -1.7884853693065366e+00,
+            2.1553408716746830e+01,
+            -1.7859663682049382e-04,
+            -2.2083984420619147e-03,
+            -1.0856928647699369e-03,
+            2.1968392602905039e-03,
+        ],
+    ),
+    (
+        7.25,
+        8.0000000000000000e+01,
+        [
+            3.2713581944562973e+22,
+            -1.2654802479497086e+23,
+            1.1213371355803651e+23,
+            -6.6022503013082598e+22,
+            4.1097396298791489e-26,
+            2.4440752782560208e-26,
+            -1.2223103608681587e-26,
+            -4.6449521193441260e-26,
+        ],
+    ),
+    (
+        20.00,
+        1.5000000000000000e+01,
+        [
+            1.3490687412109217e-01,
+            -7.1459441458819301e-02,
+            2.1024557970551394e-01,
+            -5.1279391778509723e-02,
+            1.4875282011086424e-01,
+            3.4982420217856629e-02,
+            -1.9364326746262517e-01,
+            -1.0459581655484987e-01,
+        ],
+    ),
+    (
+        35.50,
+        6.0000000000000000e+01,
+        [
+            -1.1377033819469779e+13,
+            -6.5681575776700203e+13,
+            2.9478641487881785e+13,
+            -6.1397139076391195e+13,
+            5.2336922748800341e-17,
+            1.0960291913203924e-16,
+            2.1232883343797330e-17,
+            -1.2412875737144667e-16,
+        ],
+    ),
+    (
+        3.00,
+        6.0000000000000000e+02,
+        [
+            6.8938290087781131e+181,
+            2.8357691880494682e+182,
+            -1.5182684343229135e+182,
+            2.4903142364660664e+182,
+            -1.4849940206473129e-186,
+            -2.4389705744380442e-186,
+            -6.7329212482738888e-187,
+            2.7767026777588543e-186,
+        ],
+    ),
+    (
+        -0.40,
+        3.0000000000000000e+00,
+        [
+            8.7656044354468765e-01,
+            1.7086584206753881e+00,
+            -7.2147055533099436e-01,
+            1.5926193960903081e+00,
+            -2.5885762653145478e-02,
+            -8.1857827855263535e-02,
+            -3.5541379142625763e-02,
+            8.9622937208659714e-02,
+        ],
+    ),
+    (
+        -1.00,
+        7.0000000000000000e+00,
+        [
+            -2.0368926034213573e+01,
+            2.3171651121915025e+00,
+            -1.4677548626105359e+01,
+            -1.2780679569767463e+01,
+            -2.7435871155434585e-03,
+            2.1488969236968087e-03,
+            3.6604619805567207e-03,
+            2.4338671965654532e-04,
+        ],
+    ),
+    (
+        -2.50,
+        5.0000000000000000e+01,
+        [
+            1.1546415368595959e+14,
+            -4.0487141013875273e+13,
+            1.0918222830769189e+14,
+            5.3286523937697672e+13,
+            7.3700058339777152e-17,
+            -3.5324103310135468e-17,
+            -7.7862133381965888e-17,
+            -2.6691916624605533e-17,
+        ],
+    ),
+    (
+        -3.70,
+        9.0000000000000002e-01,
+        [
+            1.6600784325392269e+01,
+            1.2157006196105394e+01,
+            -6.6375963808002084e+01,
+            -5.2820875206953865e+01,
+            -3.8046996205197743e+01,
+            1.2206303464690125e+01,
+            1.5870080380190910e+02,
+            -4.3965162084414615e+01,
+        ],
+    ),
+    (
+        -3.70,
+        2.5000000000000000e+01,
+        [
+            1.9550976849686988e+06,
+            -2.4540780587562737e+06,
+            3.0739207808073754e+06,
+            -3.3814414385395515e+05,
+            6.3251080785590485e-09,
+            7.8476705477255509e-10,
+            -4.0979147312566976e-09,
+            -5.0038876328767826e-09,
+        ],
+    ),
+    (
+        -12.30,
+        4.0000000000000000e+01,
+        [
+            -2.7777652282452477e+10,
+            -1.4967598299845268e+10,
+            -1.0166402458766174e+10,
+            -2.9612602289074341e+10,
+            -1.3116775185039894e-13,
+            3.7289392772135382e-13,
+            3.5080755112961317e-13,
+            -1.9238397747130175e-13,
+        ],
+    ),
+];
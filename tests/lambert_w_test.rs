@@ -45,6 +45,7 @@ const LAMBERT_WM1_TABLE: [(f64, f64); 13] = [
 const BRANCH_POINT: (f64, f64) = (-0.36787944117144232, -1.0);
 /// Formatted as (branch, (re(z), im(z)), (re(w), im(w)))
 #[rustfmt::skip]
+#[allow(clippy::type_complexity)]
 const COMPLEX_LAMBERT_W_TABLE: [(i32, (f64, f64), (f64, f64)); 153] = [
     (0, (1.00000000000000e-01, 2.00000000000000e-01), (1.16456459794844e-01, 1.61397090591358e-01)),
     (0, (-1.50000000000000e+00, -5.00000000000000e-01), (1.47043313306281e-01, -1.35698492904899e+00)),
@@ -0,0 +1,231 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use core::f64::consts::{FRAC_2_PI, PI};
+use puruspe::{besseljy, struve_h, struve_l};
+
+#[test]
+fn test_struve() {
+    for (nu, x, h, l) in STRUVE_TABLE {
+        // H_ν oscillates like Y_ν for large x, so it is compared relative to the envelope of Y_ν.
+        let (j, y, _, _) = besseljy(nu, x);
+        let scale = if x > nu.abs() { j.hypot(y) } else { 0.0 };
+        let rel_eps = 1e-12;
+        assert_relative_eq!(
+            struve_h(nu, x),
+            h,
+            epsilon = rel_eps * scale,
+            max_relative = rel_eps
+        );
+        assert_relative_eq!(struve_l(nu, x), l, max_relative = rel_eps);
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_struve_half_integer_orders() {
+    // H_{1/2}(x) = √(2/(πx)) (1 - cos x) and L_{1/2}(x) = √(2/(πx)) (cosh x - 1), written without cancellation
+    for &x in [0.01, 0.7, 5.0, 19.0, 60.0, 300.0].iter() {
+        let c = (2.0 / (PI * x)).sqrt();
+        assert_relative_eq!(
+            struve_h(0.5, x),
+            2.0 * c * (0.5 * x).sin().powi(2),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            struve_l(0.5, x),
+            2.0 * c * (0.5 * x).sinh().powi(2),
+            max_relative = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_struve_recurrence() {
+    // H_{ν-1}(x) + H_{ν+1}(x) = 2ν/x H_ν(x) + (x/2)^ν / (√π Γ(ν+3/2)) and
+    // L_{ν-1}(x) - L_{ν+1}(x) = 2ν/x L_ν(x) + (x/2)^ν / (√π Γ(ν+3/2)) at ν = 1
+    for &x in [0.3, 4.0, 11.0, 17.0, 35.0, 90.0].iter() {
+        let source = x / (2.0 * PI.sqrt() * (0.75 * PI.sqrt()));
+        let (h0, h1, h2) = (struve_h(0.0, x), struve_h(1.0, x), struve_h(2.0, x));
+        assert_relative_eq!(h0 + h2, 2.0 / x * h1 + source, max_relative = 1e-12);
+        let (l0, l1, l2) = (struve_l(0.0, x), struve_l(1.0, x), struve_l(2.0, x));
+        assert_relative_eq!(l0 - l2, 2.0 / x * l1 + source, max_relative = 1e-12);
+    }
+}
+
+#[test]
+fn test_struve_edge_cases() {
+    // Integer orders have the parity (-1)^(n+1), other orders are complex for x < 0.
+    for &(nu, x) in [(0.0, 2.0), (1.0, 7.0), (-3.0, 25.0), (4.0, 0.5)].iter() {
+        let sign = if nu % 2.0 == 0.0 { -1.0 } else { 1.0 };
+        assert_eq!(struve_h(nu, -x), sign * struve_h(nu, x));
+        assert_eq!(struve_l(nu, -x), sign * struve_l(nu, x));
+    }
+    assert!(struve_h(0.5, -1.0).is_nan());
+    assert!(struve_l(0.5, -1.0).is_nan());
+    assert!(struve_h(f64::NAN, 1.0).is_nan());
+    assert!(struve_l(1.0, f64::NAN).is_nan());
+
+    // Values at x = 0, where only the leading term (x/2)^(ν+1) of the series matters
+    assert_eq!(struve_h(0.0, 0.0), 0.0);
+    assert_eq!(struve_l(2.5, 0.0), 0.0);
+    assert_eq!(struve_h(-1.0, 0.0), FRAC_2_PI);
+    assert_eq!(struve_l(-1.0, 0.0), FRAC_2_PI);
+    assert_eq!(struve_h(-1.7, 0.0), f64::NEG_INFINITY);
+    assert_eq!(struve_h(-2.5, 0.0), 0.0);
+
+    assert_eq!(struve_h(0.0, f64::INFINITY), 0.0);
+    assert_eq!(struve_h(1.0, f64::INFINITY), FRAC_2_PI);
+    assert_eq!(struve_l(0.0, f64::INFINITY), f64::INFINITY);
+    assert_eq!(struve_l(0.0, 1e3), f64::INFINITY);
+}
+
+const STRUVE_TABLE: [(f64, f64, f64, f64); 24] = [
+    (
+        0.00,
+        5.0000000000000000e-01,
+        3.0955591458375470e-01,
+        3.2724069939418077e-01,
+    ),
+    (
+        0.00,
+        5.0000000000000000e+00,
+        -1.8521681577668489e-01,
+        2.7105917126558147e+01,
+    ),
+    (
+        0.00,
+        1.2000000000000000e+01,
+        -1.7253413511998872e-01,
+        1.8948871899874866e+04,
+    ),
+    (
+        0.00,
+        3.0000000000000000e+01,
+        -9.6098421554162108e-02,
+        7.8167229782395630e+11,
+    ),
+    (
+        0.00,
+        4.0000000000000000e+02,
+        -7.5819803764649064e-03,
+        1.0418584503521463e+172,
+    ),
+    (
+        1.00,
+        3.0000000000000000e+00,
+        1.0201095691864503e+00,
+        3.3966129474747762e+00,
+    ),
+    (
+        1.00,
+        2.0000000000000000e+01,
+        4.7268818429104287e-01,
+        4.2454972750111982e+07,
+    ),
+    (
+        1.00,
+        1.0000000000000000e+02,
+        6.1631110327201344e-01,
+        1.0683693903381625e+42,
+    ),
+    (
+        0.30,
+        8.0000000000000000e+00,
+        3.0527450160561170e-01,
+        4.2480585745418182e+02,
+    ),
+    (
+        2.50,
+        1.0000000000000000e+01,
+        3.1234631965773274e+00,
+        2.0254774442031007e+03,
+    ),
+    (
+        7.25,
+        4.0000000000000000e+01,
+        2.5187178760577965e+04,
+        7.6710827123906460e+15,
+    ),
+    (
+        40.10,
+        2.0000000000000000e+01,
+        4.9227126022095357e-09,
+        1.1039998211493149e-07,
+    ),
+    (
+        40.10,
+        4.5000000000000000e+01,
+        2.3509896456941628e+05,
+        8.2540715254178787e+10,
+    ),
+    (
+        120.00,
+        1.0000000000000000e+02,
+        1.4275282392657235e+04,
+        2.4845688323653059e+13,
+    ),
+    (
+        200.00,
+        1.9000000000000000e+02,
+        3.7779827158580781e+19,
+        2.7575226434910621e+38,
+    ),
+    (
+        -0.40,
+        1.5000000000000000e+01,
+        1.6068968266628603e-01,
+        3.3777792145353893e+05,
+    ),
+    (
+        -0.70,
+        2.5000000000000000e+01,
+        2.8228552098400053e-02,
+        5.7170778006868696e+09,
+    ),
+    (
+        -1.70,
+        1.2000000000000000e+01,
+        2.2993977687250799e-01,
+        1.6710732708603904e+04,
+    ),
+    (
+        -3.00,
+        1.0000000000000000e+01,
+        2.5046218700540568e-01,
+        1.7583817666408622e+03,
+    ),
+    (
+        -7.70,
+        2.0000000000000000e+01,
+        1.8249937353755816e-01,
+        9.7088993027902320e+06,
+    ),
+    (
+        -2.50,
+        3.0000000000000000e+00,
+        4.1271003220971597e-01,
+        1.5153394466819652e+00,
+    ),
+    (
+        -12.30,
+        8.0000000000000004e-01,
+        -1.6416863436011966e+11,
+        -1.6095760914280927e+11,
+    ),
+    (
+        0.50,
+        1.0000000000000000e-03,
+        1.2615661558795618e-05,
+        1.2615663661406054e-05,
+    ),
+    (
+        3.00,
+        2.5000000000000000e+02,
+        2.6527693498170215e+03,
+        9.2884920159328914e+106,
+    ),
+];
@@ -0,0 +1,270 @@
+#![allow(clippy::excessive_precision)]
+
+use approx::assert_relative_eq;
+use core::f64::consts::PI;
+use puruspe::{hurwitz_zeta, polygamma, zeta, zetac};
+
+#[test]
+fn test_zeta() {
+    for (s, y, yc) in ZETA_TABLE {
+        let rel_eps = 1e-14;
+        assert_relative_eq!(zeta(s), y, max_relative = rel_eps);
+        assert_relative_eq!(zetac(s), yc, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_hurwitz_zeta() {
+    for (s, a, y) in HURWITZ_ZETA_TABLE {
+        let result = hurwitz_zeta(s, a);
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, y, max_relative = rel_eps);
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Edge Case Tests
+// └─────────────────────────────────────────────────────────┘
+
+#[test]
+fn test_zeta_edge_cases() {
+    // Special values
+    assert_relative_eq!(zeta(2.0), PI * PI / 6.0, max_relative = 1e-15);
+    assert_relative_eq!(zeta(4.0), PI.powi(4) / 90.0, max_relative = 1e-15);
+    assert_relative_eq!(zeta(0.0), -0.5, max_relative = 1e-15);
+    assert_eq!(zeta(-2.0), 0.0);
+    assert_eq!(zeta(-100.0), 0.0);
+
+    // Pole, overflow and limits
+    assert_eq!(zeta(1.0), f64::INFINITY);
+    assert_eq!(zetac(1.0), f64::INFINITY);
+    assert_eq!(zeta(-301.0), f64::NEG_INFINITY);
+    assert_eq!(zeta(-303.0), f64::INFINITY);
+    assert_eq!(zeta(f64::INFINITY), 1.0);
+    assert_eq!(zetac(f64::INFINITY), 0.0);
+    assert!(zeta(f64::NAN).is_nan());
+    assert!(zeta(f64::NEG_INFINITY).is_nan());
+
+    // zetac keeps the digits lost in zeta(s) - 1
+    assert_relative_eq!(zetac(60.0), 2f64.powi(-60), max_relative = 1e-15);
+}
+
+#[test]
+fn test_hurwitz_zeta_edge_cases() {
+    // Relation to the Riemann zeta and polygamma functions
+    for &s in [1.5, 2.0, 3.5, 10.0].iter() {
+        assert_relative_eq!(hurwitz_zeta(s, 1.0), zeta(s), max_relative = 1e-15);
+        assert_relative_eq!(hurwitz_zeta(s, 2.0), zetac(s), max_relative = 1e-15);
+    }
+    for &a in [0.1, 1.7, 25.0].iter() {
+        assert_relative_eq!(hurwitz_zeta(2.0, a), polygamma(1, a), max_relative = 1e-14);
+        assert_relative_eq!(
+            hurwitz_zeta(4.0, a),
+            polygamma(3, a) / 6.0,
+            max_relative = 1e-14
+        );
+    }
+
+    // Recurrence ζ(s, a) = ζ(s, a + 1) + a^(-s)
+    for &(s, a) in [(1.3, 0.4), (2.5, 3.0), (8.0, 0.9)].iter() {
+        assert_relative_eq!(
+            hurwitz_zeta(s, a),
+            hurwitz_zeta(s, a + 1.0) + a.powf(-s),
+            max_relative = 1e-14
+        );
+    }
+
+    assert_eq!(hurwitz_zeta(1.0, 3.0), f64::INFINITY);
+    assert_eq!(hurwitz_zeta(f64::INFINITY, 0.5), f64::INFINITY);
+    assert_eq!(hurwitz_zeta(f64::INFINITY, 2.0), 0.0);
+}
+
+#[test]
+fn test_hurwitz_zeta_out_of_domain() {
+    assert!(hurwitz_zeta(2.0, -1.0).is_nan());
+    assert!(hurwitz_zeta(2.0, 0.0).is_nan());
+    assert!(hurwitz_zeta(0.5, 1.0).is_nan());
+    assert!(hurwitz_zeta(f64::NAN, 1.0).is_nan());
+    assert!(hurwitz_zeta(2.0, f64::NAN).is_nan());
+}
+
+const ZETA_TABLE: [(f64, f64, f64); 25] = [
+    (
+        -2.5550000000000000e+02,
+        1.4010117087958980e+301,
+        1.4010117087958980e+301,
+    ),
+    (
+        -1.7130000000000001e+02,
+        3.0809948816915279e+172,
+        3.0809948816915279e+172,
+    ),
+    (
+        -4.9000000000000000e+01,
+        -1.5001733492153928e+23,
+        -1.5001733492153928e+23,
+    ),
+    (
+        -2.0500000000000000e+01,
+        -1.0821747505877606e+02,
+        -1.0921747505877606e+02,
+    ),
+    (
+        -3.0000000000000000e+00,
+        8.3333333333333332e-03,
+        -9.9166666666666670e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        -8.3333333333333329e-02,
+        -1.0833333333333333e+00,
+    ),
+    (
+        -9.9900000000000000e-01,
+        -8.3498879643032251e-02,
+        -1.0834988796430323e+00,
+    ),
+    (
+        -5.0000000000000000e-01,
+        -2.0788622497735457e-01,
+        -1.2078862249773545e+00,
+    ),
+    (
+        -1.0000000000000000e-08,
+        -4.9999999081061475e-01,
+        -1.4999999908106147e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        -5.0000000000000000e-01,
+        -1.5000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-08,
+        -5.0000000918938547e-01,
+        -1.5000000091893855e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        -9.0455925725398401e-01,
+        -1.9045592572539840e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        -1.4603545088095868e+00,
+        -2.4603545088095866e+00,
+    ),
+    (
+        9.9900000000000000e-01,
+        -9.9942285715578794e+02,
+        -1.0004228571557879e+03,
+    ),
+    (
+        9.9999990000000005e-01,
+        -9.9999994280479010e+06,
+        -1.0000000428047901e+07,
+    ),
+    (
+        1.0000001000000001e+00,
+        1.0000000571377000e+07,
+        9.9999995713769998e+06,
+    ),
+    (
+        1.0009999999999999e+00,
+        1.0005772884760116e+03,
+        9.9957728847601163e+02,
+    ),
+    (
+        1.5000000000000000e+00,
+        2.6123753486854882e+00,
+        1.6123753486854884e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.6449340668482264e+00,
+        6.4493406684822641e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.2020569031595942e+00,
+        2.0205690315959429e-01,
+    ),
+    (
+        7.5000000000000000e+00,
+        1.0058267275365229e+00,
+        5.8267275365228074e-03,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000009539620338e+00,
+        9.5396203387279621e-07,
+    ),
+    (
+        5.0000000000000000e+01,
+        1.0000000000000009e+00,
+        8.8817842109308162e-16,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0000000000000000e+00,
+        7.8886090522101181e-31,
+    ),
+    (
+        1.0000000000000000e+03,
+        1.0000000000000000e+00,
+        9.3326361850321888e-302,
+    ),
+];
+
+const HURWITZ_ZETA_TABLE: [(f64, f64, f64); 10] = [
+    (
+        1.5000000000000000e+00,
+        1.0000000000000001e-01,
+        3.4052975515075602e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000001e-05,
+        1.0000000001644909e+10,
+    ),
+    (
+        2.0000000000000000e+00,
+        5.0000000000000000e-01,
+        4.9348022005446790e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.0500000000000000e+01,
+        4.9875721238575848e-03,
+    ),
+    (
+        1.0100000000000000e+00,
+        3.0000000000000000e+00,
+        9.9081397090778268e+01,
+    ),
+    (
+        1.2000000000000000e+00,
+        4.0000000000000000e+02,
+        1.5089214087251979e+00,
+    ),
+    (
+        3.0000000000000000e+01,
+        6.9999999999999996e-01,
+        4.4366870862485186e+04,
+    ),
+    (
+        2.0000000000000000e+02,
+        1.5000000000000000e+00,
+        6.0498998981937487e-36,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+08,
+        1.0000000049999999e-08,
+    ),
+    (
+        4.0000000000000000e+00,
+        1.0000000000000000e-70,
+        1.0000000000000000e+280,
+    ),
+];